@@ -35,6 +35,9 @@ android {
         viewBinding = true
     }
     sourceSets {
+        getByName("main") {
+            assets.srcDir("../../lib/assets")
+        }
         getByName("androidTest") {
             jniLibs.srcDir("$buildDir/rustJniLibs/android")
         }
@@ -0,0 +1,34 @@
+#version 450 core
+
+layout (location = 0) in vec3 in_Position;
+layout (location = 1) in vec3 in_Normal;
+
+layout (location = 0) out vec3 out_Normal;
+layout (location = 1) out vec3 out_WorldPosition;
+layout (location = 2) out vec3 out_Barycentric;
+
+layout (set = 0, binding = 0) uniform CameraUniformLayout {
+    mat4 m_ProjView;
+    vec3 m_Position;
+} u_Camera;
+
+layout (set = 1, binding = 0) uniform EntityUniformLayout {
+    mat4 m_World;
+    vec4 m_Color;
+} u_Entity;
+
+void main() {
+    vec4 a_WorldPosition = u_Entity.m_World * vec4(in_Position, 1.0);
+
+    // Barycentric coordinates are only valid when each triangle has its own vertices (unindexed draw).
+    int a_Corner = int(gl_VertexIndex) % 3;
+    out_Barycentric = vec3(
+        a_Corner == 0 ? 1.0 : 0.0,
+        a_Corner == 1 ? 1.0 : 0.0,
+        a_Corner == 2 ? 1.0 : 0.0
+    );
+
+    out_Normal = (u_Entity.m_World * vec4(in_Normal, 0.0)).xyz;
+    out_WorldPosition = a_WorldPosition.xyz;
+    gl_Position = u_Camera.m_ProjView * a_WorldPosition;
+}
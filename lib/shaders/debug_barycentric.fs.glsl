@@ -0,0 +1,20 @@
+#version 450 core
+
+const float LINE_WIDTH = 1.5;
+
+layout (location = 0) in vec3 in_Normal;
+layout (location = 1) in vec3 in_WorldPosition;
+layout (location = 2) in vec3 in_Barycentric;
+
+layout (location = 0) out vec4 out_FragColor;
+
+void main() {
+    // Discards fragments far from the edges of the triangle.
+    vec3 a_Delta = fwidth(in_Barycentric);
+    vec3 a_Edge = smoothstep(vec3(0.0), a_Delta * LINE_WIDTH, in_Barycentric);
+    float a_Factor = min(min(a_Edge.x, a_Edge.y), a_Edge.z);
+    if (a_Factor > 0.99)
+        discard;
+
+    out_FragColor = vec4(0.1, 0.1, 0.1, 1.0 - a_Factor);
+}
@@ -0,0 +1,21 @@
+#version 450 core
+
+const float MAX_DISTANCE = 15.0;
+
+layout (location = 0) in vec3 in_Normal;
+layout (location = 1) in vec3 in_WorldPosition;
+layout (location = 2) in vec3 in_Barycentric;
+
+layout (location = 0) out vec4 out_FragColor;
+
+layout (set = 0, binding = 0) uniform CameraUniformLayout {
+    mat4 m_ProjView;
+    vec3 m_Position;
+} u_Camera;
+
+void main() {
+    // The distance from the camera is used instead of the non-linear depth value.
+    float a_Distance = length(in_WorldPosition - u_Camera.m_Position);
+    float a_Value = 1.0 - clamp(a_Distance / MAX_DISTANCE, 0.0, 1.0);
+    out_FragColor = vec4(a_Value, a_Value, a_Value, 1.0);
+}
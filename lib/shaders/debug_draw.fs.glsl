@@ -0,0 +1,9 @@
+#version 450 core
+
+layout (location = 0) in vec4 in_Color;
+
+layout (location = 0) out vec4 out_FragColor;
+
+void main() {
+    out_FragColor = in_Color;
+}
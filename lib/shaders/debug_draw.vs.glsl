@@ -0,0 +1,16 @@
+#version 450 core
+
+layout (location = 0) in vec3 in_Position;
+layout (location = 1) in vec4 in_Color;
+
+layout (location = 0) out vec4 out_Color;
+
+layout (set = 0, binding = 0) uniform CameraUniformLayout {
+    mat4 m_ProjView;
+    vec3 m_Position;
+} u_Camera;
+
+void main() {
+    out_Color = in_Color;
+    gl_Position = u_Camera.m_ProjView * vec4(in_Position, 1.0);
+}
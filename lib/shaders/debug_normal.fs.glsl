@@ -0,0 +1,11 @@
+#version 450 core
+
+layout (location = 0) in vec3 in_Normal;
+layout (location = 1) in vec3 in_WorldPosition;
+layout (location = 2) in vec3 in_Barycentric;
+
+layout (location = 0) out vec4 out_FragColor;
+
+void main() {
+    out_FragColor = vec4(normalize(in_Normal) * 0.5 + 0.5, 1.0);
+}
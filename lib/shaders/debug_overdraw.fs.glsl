@@ -0,0 +1,12 @@
+#version 450 core
+
+layout (location = 0) in vec3 in_Normal;
+layout (location = 1) in vec3 in_WorldPosition;
+layout (location = 2) in vec3 in_Barycentric;
+
+layout (location = 0) out vec4 out_FragColor;
+
+void main() {
+    // Each layer is added to the render target, so the more layers overlap, the brighter the pixel.
+    out_FragColor = vec4(0.25, 0.08, 0.02, 1.0);
+}
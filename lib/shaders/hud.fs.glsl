@@ -0,0 +1,15 @@
+#version 450 core
+
+layout (location = 0) in vec2 in_TexCoord;
+layout (location = 1) in vec4 in_Color;
+
+layout (location = 0) out vec4 out_FragColor;
+
+layout (set = 0, binding = 1) uniform texture2D t_Font;
+layout (set = 0, binding = 2) uniform sampler s_Font;
+
+void main() {
+    // Negative texture coordinates mark solid quads that do not sample the font atlas.
+    float a_Coverage = in_TexCoord.x < 0.0 ? 1.0 : texture(sampler2D(t_Font, s_Font), in_TexCoord).r;
+    out_FragColor = vec4(in_Color.rgb, in_Color.a * a_Coverage);
+}
@@ -0,0 +1,19 @@
+#version 450 core
+
+layout (location = 0) in vec2 in_Position;
+layout (location = 1) in vec2 in_TexCoord;
+layout (location = 2) in vec4 in_Color;
+
+layout (location = 0) out vec2 out_TexCoord;
+layout (location = 1) out vec4 out_Color;
+
+layout (set = 0, binding = 0) uniform HudUniformLayout {
+    vec2 m_ScreenSize;
+} u_Hud;
+
+void main() {
+    vec2 a_Ndc = in_Position / u_Hud.m_ScreenSize * 2.0 - 1.0;
+    out_TexCoord = in_TexCoord;
+    out_Color = in_Color;
+    gl_Position = vec4(a_Ndc.x, -a_Ndc.y, 0.0, 1.0);
+}
@@ -0,0 +1,34 @@
+#version 450 core
+
+#define MAX_SKIN_JOINTS 64
+
+layout (location = 0) in vec3 in_Position;
+layout (location = 1) in vec3 in_Normal;
+layout (location = 5) in uvec4 in_Joints;
+layout (location = 6) in vec4 in_Weights;
+
+layout (location = 0) out vec4 out_Color;
+
+layout (set = 0, binding = 0) uniform CameraUniformLayout {
+    mat4 m_ProjView;
+    vec3 m_Position;
+} u_Camera;
+
+layout (set = 1, binding = 0) uniform EntityUniformLayout {
+    mat4 m_World;
+    vec4 m_Color;
+} u_Entity;
+
+layout (set = 2, binding = 0) uniform SkinUniformLayout {
+    mat4 m_Joints[MAX_SKIN_JOINTS];
+} u_Skin;
+
+void main() {
+    mat4 a_Skin = in_Weights.x * u_Skin.m_Joints[in_Joints.x]
+        + in_Weights.y * u_Skin.m_Joints[in_Joints.y]
+        + in_Weights.z * u_Skin.m_Joints[in_Joints.z]
+        + in_Weights.w * u_Skin.m_Joints[in_Joints.w];
+
+    out_Color = u_Entity.m_Color;
+    gl_Position = u_Camera.m_ProjView * u_Entity.m_World * a_Skin * vec4(in_Position, 1.0);
+}
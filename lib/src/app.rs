@@ -1,368 +1,759 @@
 use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
 use winit::event::Event;
 use winit::event::WindowEvent;
 use winit::event_loop::EventLoop;
-use winit::event_loop::ControlFlow;
+use winit::event_loop::EventLoopWindowTarget;
 use winit::window::Window;
 
+use crate::config::RendererConfig;
+use crate::error::AppError;
+use crate::pacer::FramePacer;
+use crate::profiler;
 use crate::scene::GameScene;
-use crate::scene::SampleScene;
 use crate::timer::GameTimer;
+use crate::vfs::Vfs;
+#[cfg(not(target_os = "android"))]
+use crate::render::shader::SHADER_MOUNT_POINT;
+#[cfg(not(target_os = "android"))]
+use crate::render::shader::ShaderWatcher;
+#[cfg(not(target_os = "android"))]
+use crate::vfs::DirectoryFs;
+#[cfg(feature = "egui")]
+use crate::gui::Gui;
+#[cfg(target_os = "android")]
+use winit::platform::android::activity::AndroidApp;
 
 
-#[cfg(target_os = "android")]
-pub const RENDER_TARGET_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Rgba8Unorm;
-#[cfg(not(target_os = "android"))]
-pub const RENDER_TARGET_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Bgra8Unorm;
+
+/// #### 한국어 </br>
+/// 선택된 어댑터와 그 어댑터에서 생성한 장치, 명령어 대기열입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The selected adapter, with the device and command queue created from it. </br>
+/// 
+type DeviceHandles = (Arc<wgpu::Adapter>, Arc<wgpu::Device>, Arc<wgpu::Queue>);
+
 
 
 /// #### 한국어 </br>
-/// 이벤트 루프를 돌며 Android 애플리케이션을 동작하는 함수입니다. </br>
+/// `surface`가 생성되기 전에 그래픽스 파이프라인을 생성할 때 사용하는 렌더 타겟의 형식입니다. </br>
+/// 첫 번째 `Resumed` 이벤트에서 `surface`가 지원하는 형식과 다르다면 파이프라인들이 다시 생성됩니다. </br>
 /// 
 /// #### English (Translation) </br>
-/// This is a function that runs an Android application while running an event loop. </br>
+/// The render target format used to create graphics pipelines before the `surface` is created. </br>
+/// If it differs from the format supported by the `surface` at the first `Resumed` event, the pipelines are recreated. </br>
+/// 
+const INITIAL_RENDER_TARGET_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Rgba8UnormSrgb;
+
+
+
+/// #### 한국어 </br>
+/// 사용자가 제공한 장면으로 애플리케이션을 실행하는 실행기입니다. </br>
+/// 장면은 장치와 `surface`의 형식이 정해진 후 `scene_factory`로 생성되며, 에셋을 읽을 가상 파일 시스템을 함께 전달받습니다. </br>
+/// 가상 파일 시스템을 설정하지 않으면 플랫폼의 기본 가상 파일 시스템을 사용합니다. </br>
 /// 
+/// #### English (Translation) </br>
+/// A runner that runs the application with a user-supplied scene. </br>
+/// The scene is created with `scene_factory` after the device and the format of the `surface` are decided, and receives the virtual file system to read assets from. </br>
+/// If no virtual file system is set, the default virtual file system of the platform is used. </br>
+/// 
+/// # Example
+/// ```no_run
+/// use wgpu_app::AppRunner;
+/// use wgpu_app::RendererConfig;
+/// use wgpu_app::scene::SampleScene;
+/// 
+/// # #[cfg(not(target_os = "android"))]
+/// AppRunner::new(SampleScene::new)
+///     .with_config(RendererConfig::default())
+///     .with_title("Sample")
+///     .run()
+///     .unwrap();
+/// ```
+/// 
+pub struct AppRunner<F> {
+    config: RendererConfig, 
+    title: Option<String>, 
+    vfs: Option<Vfs>, 
+    scene_factory: F, 
+}
+
 #[allow(dead_code)]
-#[cfg(target_os = "android")]
-pub fn run_android<'a>(event_loop: EventLoop<()>) {
-    let mut window: Option<Arc<Window>> = None;
-    let mut surface: Option<Arc<wgpu::Surface<'a>>> = None;
-    let mut depth_stencil_view: Option<Arc<wgpu::TextureView>> = None;
-
-    let (
-        instance, 
-        _adapter, 
-        device, 
-        queue
-    ) = init_wgpu_renderer_without_surface();
-
-    let mut sample_scene = SampleScene::new(&device, &queue);
-
-    let mut timer = GameTimer::<50>::new();
-    event_loop.set_control_flow(ControlFlow::Wait);
-    event_loop.run(|event, elwt| {
-        match event {
-            Event::AboutToWait => {
-                timer.tick();
-                sample_scene.on_update(timer.elapsed_time_sec(), &device, &queue);
-                if let Some(window) = window.as_ref() {
-                    window.request_redraw();
-                }
-            },
-            Event::WindowEvent { window_id, event } 
-            if window.as_ref().is_some_and(|window| window.id() == window_id) => match event {
-                WindowEvent::CloseRequested | WindowEvent::Destroyed => {
-                    elwt.exit();
-                }, 
-                WindowEvent::RedrawRequested => {
-                    if let Some((window, surface, depth_stencil_view)) = window.as_ref().and_then(|w| 
-                        surface.as_ref().and_then(|s| 
-                            depth_stencil_view.as_ref().and_then(|d| Some((w, s, d)))
-                        )
-                    ) {
-                        // (한국어) `winit`에게 다음 프레임을 그릴 준비가 되었음을 알립니다.
-                        // (English Translation) Notifies `winit` that the next frame is ready to be drawn.
-                        window.pre_present_notify();
-
-                        // (한국어) 이전 작업이 끝날 때 까지 기다립니다.
-                        // (English Translation) Wait until the previous operation is finished. 
-                        device.poll(wgpu::Maintain::Wait);
-
-                        // (한국어) 다음 프레임을 가져옵니다.
-                        // (English Translation) Get the next frame.
-                        let frame = surface.get_current_texture()
-                            .expect("Could not get the following frame buffer!");
-
-                        // (한국어) 렌더 타겟의 텍스처 뷰를 생성합니다.
-                        // (English Translation) Creates a texture view of render target.
-                        let render_target_view = frame.texture.create_view(
-                            &wgpu::TextureViewDescriptor { 
-                                ..Default::default() 
-                            }
-                        );
-
-                        sample_scene.on_draw(&render_target_view, &depth_stencil_view, &device, &queue);
-
-                        // (한국어) 프레임 버퍼를 화면에 출력합니다.
-                        // (English Translation) Prints the framebuffer to the screen. 
-                        frame.present();
-                    }
-                }, 
-                WindowEvent::Resized(size) if size.width > 0 && size.height > 0 => {
-                    if let Some((window, surface)) = window.as_ref().zip(surface.as_ref()) {
-                        // (한국어) 모든 작업이 끝날 때 까지 기다립니다.
-                        // (English Translation) Wait until all operations are completed.
-                        instance.poll_all(true);
-
-                        setup_swapchain(size.width, size.height, &device, surface);
-                        depth_stencil_view = Some(setup_depth_stencil_view(size.width, size.height, &device));
-
-                        sample_scene.on_resized(&window, &device, &queue);
-                    }
-                },
-                WindowEvent::Touch(touch) => {
-                    sample_scene.on_touch_event(touch, &device, &queue);
-                },
-                _ => { /* empty */ }
-            },
-            Event::Suspended => {
-                // (한국어) 기존의 윈도우 핸들과 `surface`를 제거합니다. 
-                // (English Translation) Removes the existing window handle and `surface`.
-                window = None;
-                surface = None;
-                depth_stencil_view = None;
-
-                sample_scene.on_paused(&device, &queue);
-            }, 
-            Event::Resumed => {
-                // (한국어) 새로운 윈도우 핸들과 `surface`를 생성합니다.
-                // (English Translation) Creates a new window handle and `surface`.
-                let a_window: Arc<Window> = Window::new(elwt)
-                    .expect("Can't create window!")
-                    .into();
-                let target_window = a_window.clone();
-                let a_surface: Arc<wgpu::Surface<'a>> = instance.create_surface(
-                    wgpu::SurfaceTarget::from(target_window)
-                    )
-                    .expect("Can't create wgpu surface!")
-                    .into();
-
-                setup_swapchain(a_window.inner_size().width, a_window.inner_size().height, &device, &a_surface);
-                depth_stencil_view = Some(setup_depth_stencil_view(
-                    a_window.inner_size().width, 
-                    a_window.inner_size().height, 
-                    &device
-                ));
-
-                sample_scene.on_resumed(&a_window, &device, &queue);
-
-                window = Some(a_window);
-                surface = Some(a_surface);
-            },
-            _ => { /* empty */ }
-        };
-    })
-    .expect("Failed to run event loop!");
+impl<S, F> AppRunner<F> 
+where S: GameScene, F: FnOnce(&Vfs, wgpu::TextureFormat, &wgpu::Device, &wgpu::Queue) -> Result<S, AppError> {
+    #[inline]
+    pub fn new(scene_factory: F) -> Self {
+        Self {
+            config: RendererConfig::default(), 
+            title: None, 
+            vfs: None, 
+            scene_factory, 
+        }
+    }
+
+    #[inline]
+    pub fn with_config(mut self, config: RendererConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 윈도우의 제목을 설정합니다. 데스크톱에서만 사용됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the title of the window. Used only on desktop. </br>
+    /// 
+    #[inline]
+    pub fn with_title<T: Into<String>>(mut self, title: T) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 장면이 에셋을 읽을 가상 파일 시스템을 설정합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the virtual file system from which the scene reads assets. </br>
+    /// 
+    #[inline]
+    pub fn with_vfs(mut self, vfs: Vfs) -> Self {
+        self.vfs = Some(vfs);
+        self
+    }
+
+    #[inline]
+    pub fn config(&self) -> &RendererConfig {
+        &self.config
+    }
+
+    /// #### 한국어 </br>
+    /// 이벤트 루프와 윈도우를 생성하고, 애플리케이션이 종료될 때 까지 실행합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates the event loop and the window, and runs until the application exits. </br>
+    /// 
+    #[cfg(not(target_os = "android"))]
+    pub fn run(self) -> Result<(), AppError> {
+        use winit::window::WindowBuilder;
+
+        let event_loop: EventLoop<()> = EventLoop::new()?;
+        let mut builder = WindowBuilder::new();
+        if let Some(title) = self.title {
+            builder = builder.with_title(title);
+        }
+        let window = Arc::new(builder.build(&event_loop)?);
+        let vfs = self.vfs.unwrap_or_else(Vfs::desktop);
+        Runtime::new(self.config, Some(window), vfs, self.scene_factory)?
+            .run(event_loop)
+    }
+
+    /// #### 한국어 </br>
+    /// 주어진 Android 애플리케이션으로 이벤트 루프를 생성하고, 애플리케이션이 종료될 때 까지 실행합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates the event loop with the given Android application, and runs until the application exits. </br>
+    /// 
+    #[cfg(target_os = "android")]
+    pub fn run(self, app: AndroidApp) -> Result<(), AppError> {
+        use winit::event_loop::EventLoopBuilder;
+        use winit::platform::android::EventLoopBuilderExtAndroid;
+
+        let vfs = self.vfs.unwrap_or_else(|| Vfs::android(app.clone()));
+        let event_loop: EventLoop<()> = EventLoopBuilder::new()
+            .with_android_app(app)
+            .build()?;
+        Runtime::new(self.config, None, vfs, self.scene_factory)?
+            .run(event_loop)
+    }
+}
+
+
+/// #### 한국어 </br>
+/// 이벤트 루프가 동작하는 동안 유지되는 애플리케이션의 상태입니다. </br>
+/// 윈도우와 `surface`는 플랫폼에 따라 이벤트 루프 전에 생성되거나(데스크톱), `Resumed` 이벤트에서 생성됩니다(Android). </br>
+/// 
+/// #### English (Translation) </br>
+/// The state of the application kept while the event loop runs. </br>
+/// Depending on the platform, the window and the `surface` are created before the event loop (desktop) or at the `Resumed` event (Android). </br>
+/// 
+struct Runtime<S: GameScene> {
+    config: RendererConfig, 
+    instance: wgpu::Instance, 
+    adapter: Arc<wgpu::Adapter>, 
+    device: Arc<wgpu::Device>, 
+    queue: Arc<wgpu::Queue>, 
+    device_lost: Arc<AtomicBool>, 
+
+    window: Option<Arc<Window>>, 
+    surface: Option<wgpu::Surface<'static>>, 
+    surface_format: wgpu::TextureFormat, 
+    depth_stencil_view: Option<Arc<wgpu::TextureView>>, 
+
+    scene: S, 
+    #[cfg(feature = "egui")]
+    gui: Gui, 
+    #[cfg(not(target_os = "android"))]
+    shader_watcher: Option<ShaderWatcher>, 
+    timer: GameTimer<50>, 
+    pacer: FramePacer, 
 }
 
 #[allow(dead_code)]
-#[cfg(not(target_os = "android"))]
-pub fn run_dev(window: Window, event_loop: EventLoop<()>) {
-    let (
-        instance, 
-        surface, 
-        _adapter, 
-        device, 
-        queue
-    ) = init_wgpu_renderer(&window);
-
-    let mut depth_stencil_view = setup_depth_stencil_view(
-        window.inner_size().width, 
-        window.inner_size().height, 
-        &device
-    );
+impl<S: GameScene> Runtime<S> {
+    /// #### 한국어 </br>
+    /// 장치를 생성한 후 `scene_factory`로 장면을 생성합니다. </br>
+    /// 윈도우가 주어지면 그 `surface`가 지원하는 형식으로 장면을 생성하고, 그렇지 않으면 `INITIAL_RENDER_TARGET_FORMAT`을 사용합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates the scene with `scene_factory` after creating the device. </br>
+    /// If a window is given, the scene is created with the format supported by its `surface`, otherwise `INITIAL_RENDER_TARGET_FORMAT` is used. </br>
+    /// 
+    fn new<F>(config: RendererConfig, window: Option<Arc<Window>>, vfs: Vfs, scene_factory: F) -> Result<Self, AppError>
+    where F: FnOnce(&Vfs, wgpu::TextureFormat, &wgpu::Device, &wgpu::Queue) -> Result<S, AppError> {
+        config.validate()?;
+
+        let instance = wgpu::Instance::new(
+            wgpu::InstanceDescriptor {
+                backends: config.wgpu_backends(), 
+                ..Default::default()
+            }, 
+        );
 
-    let mut sample_scene = SampleScene::new(&device, &queue);
+        let surface = match window.as_ref() {
+            Some(window) => Some(instance.create_surface(wgpu::SurfaceTarget::from(window.clone()))?), 
+            None => None, 
+        };
 
-    let mut timer = GameTimer::<50>::new();
-    event_loop.set_control_flow(ControlFlow::Wait);
-    event_loop.run(|event, elwt| {
-        match event {
-            Event::AboutToWait => {
-                timer.tick();
-                sample_scene.on_update(timer.elapsed_time_sec(), &device, &queue);
-                window.request_redraw();
-            }
-            Event::WindowEvent { window_id, event } 
-            if window.id() == window_id => match event {
-                WindowEvent::CloseRequested | WindowEvent::Destroyed => {
-                    elwt.exit();
+        let (adapter, device, queue) = request_device(&instance, &config)?;
+
+        // (한국어) 장치가 손실되었는지 감시합니다.
+        // (English Translation) Watches whether the device is lost.
+        let device_lost = Arc::new(AtomicBool::new(false));
+        watch_device_lost(&device, device_lost.clone());
+
+        let surface_format = match surface.as_ref() {
+            Some(surface) => select_surface_format(&config, &adapter, surface)?, 
+            None => INITIAL_RENDER_TARGET_FORMAT, 
+        };
+
+        // (한국어) 설정에 셰이더 디렉토리가 있다면 가상 파일 시스템에 마운트하고 셰이더 파일들의 변경을 감시합니다.
+        // (English Translation) If the configuration has a shader directory, mounts it in the virtual file system and watches shader files for changes.
+        #[cfg(not(target_os = "android"))]
+        let mut vfs = vfs;
+        #[cfg(not(target_os = "android"))]
+        let shader_watcher = config.shader_dir.as_ref().map(|shader_dir| {
+            vfs.mount(SHADER_MOUNT_POINT, DirectoryFs::new(shader_dir));
+            ShaderWatcher::new(shader_dir)
+        });
+
+        let scene = scene_factory(&vfs, surface_format, &device, &queue)?;
+
+        profiler::set_enabled(config.cpu_profiling);
+
+        Ok(Self {
+            #[cfg(feature = "egui")]
+            gui: Gui::new(surface_format, &device), 
+            #[cfg(not(target_os = "android"))]
+            shader_watcher, 
+            timer: GameTimer::new(), 
+            pacer: FramePacer::new(&config), 
+            config, 
+            instance, 
+            adapter, 
+            device, 
+            queue, 
+            device_lost, 
+            window, 
+            surface, 
+            surface_format, 
+            depth_stencil_view: None, 
+            scene, 
+        })
+    }
+
+    /// #### 한국어 </br>
+    /// 이벤트 루프를 돌며 애플리케이션을 동작합니다. 이벤트 루프 안에서 발생한 치명적인 오류는 루프를 종료하고 반환됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Runs the application while running the event loop. A fatal error that occurred inside the event loop exits the loop and is returned. </br>
+    /// 
+    fn run(mut self, event_loop: EventLoop<()>) -> Result<(), AppError> {
+        let mut result = Ok(());
+        event_loop.set_control_flow(self.pacer.control_flow());
+        event_loop.run(|event, elwt| {
+            let handled = match event {
+                Event::AboutToWait => self.on_about_to_wait(elwt), 
+                Event::WindowEvent { window_id, event }
+                if self.window.as_ref().is_some_and(|window| window.id() == window_id) => {
+                    self.on_window_event(event, elwt)
                 }, 
-                WindowEvent::RedrawRequested => {
-                    // (한국어) `winit`에게 다음 프레임을 그릴 준비가 되었음을 알립니다.
-                    // (English Translation) Notifies `winit` that the next frame is ready to be drawn.
-                    window.pre_present_notify();
-
-                    // (한국어) 이전 작업이 끝날 때 까지 기다립니다.
-                    // (English Translation) Wait until the previous operation is finished. 
-                    device.poll(wgpu::Maintain::Wait);
-
-                    // (한국어) 다음 프레임을 가져옵니다.
-                    // (English Translation) Get the next frame.
-                    let frame = surface.get_current_texture()
-                        .expect("Could not get the following frame buffer!");
-
-                    // (한국어) 렌더 타겟의 텍스처 뷰를 생성합니다.
-                    // (English Translation) Creates a texture view of render target.
-                    let render_target_view = frame.texture.create_view(
-                        &wgpu::TextureViewDescriptor { 
-                            ..Default::default() 
-                        }
-                    );
-
-                    sample_scene.on_draw(&render_target_view, &depth_stencil_view, &device, &queue);
-
-                    // (한국어) 프레임 버퍼를 화면에 출력합니다.
-                    // (English Translation) Prints the framebuffer to the screen. 
-                    frame.present();
+                Event::Suspended => {
+                    self.on_suspended();
+                    Ok(())
                 }, 
-                WindowEvent::Resized(size) if size.width > 0 && size.height > 0 => {
-                    // (한국어) 모든 작업이 끝날 때 까지 기다립니다.
-                    // (English Translation) Wait until all operations are completed.
-                    instance.poll_all(true);
-
-                    setup_swapchain(size.width, size.height, &device, &surface);
-                    depth_stencil_view = setup_depth_stencil_view(size.width, size.height, &device);
-
-                    sample_scene.on_resized(&window, &device, &queue);
-                },
-                WindowEvent::KeyboardInput { event, .. } => {
-                    sample_scene.on_keyboard_event(event, &device, &queue);
+                Event::Resumed => self.on_resumed(elwt), 
+                _ => Ok(()), 
+            };
+
+            if let Err(err) = handled {
+                result = Err(err);
+                elwt.exit();
+            }
+        })?;
+
+        result
+    }
+
+    fn on_about_to_wait(&mut self, elwt: &EventLoopWindowTarget<()>) -> Result<(), AppError> {
+        // (한국어) 장치가 손실된 경우 새로운 장치를 생성하고 모든 GPU 자원을 다시 생성합니다.
+        // (English Translation) If the device is lost, creates a new device and recreates all GPU resources.
+        if self.device_lost.swap(false, Ordering::AcqRel) {
+            log::warn!("The device was lost. Recreating the device and all GPU resources.");
+            let (adapter, device, queue) = recreate_device(&self.instance, &self.config, &self.device_lost, &mut self.scene)?;
+            self.adapter = adapter;
+            self.device = device;
+            self.queue = queue;
+            self.pacer.request_redraw();
+
+            #[cfg(feature = "egui")]
+            { self.gui = Gui::new(self.surface_format, &self.device); }
+
+            self.configure_surface()?;
+        }
+
+        // (한국어) 변경된 셰이더 파일이 있다면 파이프라인을 다시 생성합니다.
+        // (English Translation) If there are changed shader files, recreate the pipelines.
+        #[cfg(not(target_os = "android"))]
+        if let Some(shader_watcher) = self.shader_watcher.as_mut() {
+            for path in shader_watcher.poll() {
+                let Some(name) = path.file_name() else {
+                    continue;
+                };
+                let path = std::path::Path::new(SHADER_MOUNT_POINT).join(name);
+                log::info!("Shader file changed: {}", path.display());
+                self.scene.on_shader_changed(&path, &self.device, &self.queue);
+                self.pacer.request_redraw();
+            }
+        }
+
+        // (한국어) 루프 방식에 따라 이번 루프에서 프레임을 갱신하고 그릴지 결정합니다.
+        // (English Translation) Decides whether to update and draw a frame in this loop according to the loop mode.
+        let run_frame = self.pacer.should_run_frame(self.scene.is_dirty());
+        elwt.set_control_flow(self.pacer.control_flow());
+        if !run_frame {
+            return Ok(());
+        }
+
+        // (한국어) 쉬었던 시간은 시뮬레이션에 반영하지 않습니다.
+        // (English Translation) The idle time is not reflected in the simulation.
+        if self.pacer.resumed_from_idle() {
+            self.timer.reset();
+        }
+
+        // (한국어) 누적된 시간만큼 고정 갱신을 실행한 후, 프레임 갱신을 실행합니다.
+        // (English Translation) Runs the fixed updates for the accumulated time, then runs the frame update.
+        profiler::begin_frame();
+        self.timer.tick();
+        {
+            let _scope = profiler::scope("FixedUpdate");
+            while self.timer.consume_fixed_step() {
+                self.scene.on_fixed_update(&self.timer, &self.device, &self.queue);
+            }
+        }
+        {
+            let _scope = profiler::scope("Update");
+            self.scene.on_update(&mut self.timer, &self.device, &self.queue);
+        }
+
+        if let Some(window) = self.window.as_ref() {
+            #[cfg(feature = "egui")]
+            {
+                let _scope = profiler::scope("Gui");
+                let (scene, timer, device, queue) = (&mut self.scene, &self.timer, &self.device, &self.queue);
+                self.gui.run(window, |ctx| scene.on_gui(ctx, timer, device, queue));
+            }
+
+            window.request_redraw();
+        }
+        Ok(())
+    }
+
+    fn on_window_event(&mut self, event: WindowEvent, elwt: &EventLoopWindowTarget<()>) -> Result<(), AppError> {
+        // (한국어) GUI가 사용한 입력 이벤트는 장면에 전달하지 않습니다.
+        // (English Translation) Input events consumed by the GUI are not passed to the scene.
+        #[cfg(feature = "egui")]
+        let gui_consumed = self.window.as_ref().is_some_and(|window| self.gui.on_window_event(window, &event));
+        #[cfg(not(feature = "egui"))]
+        let gui_consumed = false;
+
+        // (한국어) 입력이나 윈도우의 변경이 있으면 다음 루프에서 프레임을 그립니다.
+        // (English Translation) If there is input or a change of the window, draws a frame in the next loop.
+        if !matches!(event, WindowEvent::RedrawRequested) {
+            self.pacer.request_redraw();
+        }
+
+        match event {
+            WindowEvent::CloseRequested | WindowEvent::Destroyed => {
+                elwt.exit();
+            }, 
+            WindowEvent::RedrawRequested => {
+                self.redraw()?;
+            }, 
+            WindowEvent::Resized(size) if size.width > 0 && size.height > 0 => {
+                if let Some((window, surface)) = self.window.as_ref().zip(self.surface.as_ref()) {
+                    setup_swapchain(size.width, size.height, self.surface_format, &self.config, &self.adapter, &self.device, surface);
+                    self.depth_stencil_view = Some(setup_depth_stencil_view(size.width, size.height, &self.device));
+
+                    self.scene.on_resized(window, &self.device, &self.queue);
                 }
-                _ => { /* empty */ }
-            },
-            Event::Suspended => {
-                sample_scene.on_paused(&device, &queue);
             }, 
-            Event::Resumed => {
-                sample_scene.on_resumed(&window, &device, &queue);
-            },
+            WindowEvent::KeyboardInput { event, .. } if !gui_consumed => {
+                self.scene.on_keyboard_event(event, &self.device, &self.queue);
+            }, 
+            WindowEvent::CursorMoved { position, .. } if !gui_consumed => {
+                self.scene.on_cursor_moved(position, &self.device, &self.queue);
+            }, 
+            WindowEvent::MouseInput { state, button, .. } if !gui_consumed => {
+                self.scene.on_mouse_input(state, button, &self.device, &self.queue);
+            }, 
+            WindowEvent::Touch(touch) if !gui_consumed => {
+                self.scene.on_touch_event(touch, &self.device, &self.queue);
+            }, 
             _ => { /* empty */ }
         };
-    })
-    .expect("Failed to run event loop!");
+        Ok(())
+    }
+
+    fn redraw(&mut self) -> Result<(), AppError> {
+        let Some((window, surface)) = self.window.as_ref().zip(self.surface.as_ref()) else {
+            return Ok(());
+        };
+        let Some(depth_stencil_view) = self.depth_stencil_view.as_ref() else {
+            return Ok(());
+        };
+
+        // (한국어) `winit`에게 다음 프레임을 그릴 준비가 되었음을 알립니다.
+        // (English Translation) Notifies `winit` that the next frame is ready to be drawn.
+        window.pre_present_notify();
+
+        // (한국어) 완료된 GPU 작업의 콜백들을 처리합니다. 이전 프레임을 기다리지 않으므로 CPU와 GPU가 프레임을 겹쳐서 처리합니다.
+        // (English Translation) Processes the callbacks of finished GPU work. It does not wait for the previous frame, so the CPU and GPU work on frames in parallel.
+        self.device.poll(wgpu::Maintain::Poll);
+
+        // (한국어) 다음 프레임을 가져옵니다. 동시에 처리되는 프레임의 수는 스왑체인의 프레임 지연으로 제한됩니다.
+        // (English Translation) Get the next frame. The number of frames in flight is limited by the frame latency of the swapchain.
+        let acquire_scope = profiler::scope("AcquireFrame");
+        let Some(frame) = acquire_frame(window, surface, self.surface_format, &self.config, &self.adapter, &self.device)? else {
+            window.request_redraw();
+            return Ok(());
+        };
+        drop(acquire_scope);
+
+        // (한국어) 렌더 타겟의 텍스처 뷰를 생성합니다.
+        // (English Translation) Creates a texture view of render target.
+        let render_target_view = frame.texture.create_view(
+            &wgpu::TextureViewDescriptor {
+                ..Default::default()
+            }
+        );
+
+        {
+            let _scope = profiler::scope("Draw");
+            self.scene.on_draw(&render_target_view, depth_stencil_view, self.timer.interpolation_alpha(), &self.device, &self.queue);
+        }
+
+        #[cfg(feature = "egui")]
+        {
+            let _scope = profiler::scope("DrawGui");
+            self.gui.draw(&render_target_view, &self.device, &self.queue);
+        }
+
+        // (한국어) 프레임 버퍼를 화면에 출력합니다.
+        // (English Translation) Prints the framebuffer to the screen.
+        let _scope = profiler::scope("Present");
+        frame.present();
+        Ok(())
+    }
+
+    fn on_suspended(&mut self) {
+        // (한국어) Android에서는 일시 정지된 동안 윈도우 핸들이 유효하지 않으므로, 기존의 윈도우 핸들과 `surface`를 제거합니다.
+        // (English Translation) On Android the window handle is invalid while suspended, so removes the existing window handle and `surface`.
+        if cfg!(target_os = "android") {
+            self.depth_stencil_view = None;
+            self.surface = None;
+            self.window = None;
+
+            #[cfg(feature = "egui")]
+            self.gui.suspend();
+        }
+
+        self.scene.on_paused(&self.device, &self.queue);
+    }
+
+    fn on_resumed(&mut self, elwt: &EventLoopWindowTarget<()>) -> Result<(), AppError> {
+        self.pacer.request_redraw();
+
+        // (한국어) 윈도우가 없다면 새로운 윈도우 핸들과 `surface`를 생성합니다.
+        // (English Translation) If there is no window, creates a new window handle and `surface`.
+        if self.window.is_none() {
+            let window = Arc::new(Window::new(elwt)?);
+            let surface = self.instance.create_surface(wgpu::SurfaceTarget::from(window.clone()))?;
+            self.window = Some(window);
+            self.surface = Some(surface);
+        }
+
+        self.configure_surface()
+    }
+
+    /// #### 한국어 </br>
+    /// 현재 장치로 `surface`의 형식을 다시 선택하고, 스왑체인과 깊이-스텐실 뷰를 설정한 후 장면에 알립니다. </br>
+    /// 형식이 바뀌었다면 그래픽스 파이프라인들이 다시 생성됩니다. 윈도우가 없다면 아무것도 하지 않습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Reselects the format of the `surface` with the current device, sets up the swapchain and the depth-stencil view, and notifies the scene. </br>
+    /// If the format has changed, the graphics pipelines are recreated. Does nothing if there is no window. </br>
+    /// 
+    fn configure_surface(&mut self) -> Result<(), AppError> {
+        let Some((window, surface)) = self.window.as_ref().zip(self.surface.as_ref()) else {
+            return Ok(());
+        };
+
+        let format = select_surface_format(&self.config, &self.adapter, surface)?;
+        let changed = change_surface_format(&mut self.surface_format, format, &self.device, &self.queue, &mut self.scene)?;
+        #[cfg(feature = "egui")]
+        if changed {
+            self.gui = Gui::new(self.surface_format, &self.device);
+        }
+        #[cfg(not(feature = "egui"))]
+        let _ = changed;
+
+        let size = window.inner_size();
+        if size.width > 0 && size.height > 0 {
+            setup_swapchain(size.width, size.height, self.surface_format, &self.config, &self.adapter, &self.device, surface);
+            self.depth_stencil_view = Some(setup_depth_stencil_view(size.width, size.height, &self.device));
+        }
+
+        self.scene.on_resumed(window, &self.device, &self.queue);
+
+        #[cfg(feature = "egui")]
+        self.gui.resume(window, &self.device);
+        Ok(())
+    }
 }
 
 /// #### 한국어 </br>
-/// `surface`를 제외한 wgpu 렌더링 인스턴스들을 생성합니다. </br>
+/// wgpu 어댑터를 선택하고 장치와 명령어 대기열을 생성합니다. </br>
 /// 
 /// #### English (Translation) </br>
-/// Create wgpu rendering instances excluding `surface`. </br>
+/// Selects a wgpu adapter and creates the device and command queue. </br>
 /// 
 #[allow(dead_code)]
-#[cfg(target_os = "android")]
-fn init_wgpu_renderer_without_surface() -> (
-    Arc<wgpu::Instance>, 
-    Arc<wgpu::Adapter>, 
-    Arc<wgpu::Device>, 
-    Arc<wgpu::Queue>
-) {
-    let instance = wgpu::Instance::new(
-        wgpu::InstanceDescriptor {
-            backends: wgpu::Backends::VULKAN, 
-            ..Default::default()
-        }, 
-    );
-
+fn request_device(instance: &wgpu::Instance, config: &RendererConfig) -> Result<DeviceHandles, AppError> {
     let adapter = pollster::block_on(
         instance.request_adapter(
             &wgpu::RequestAdapterOptions {
                 compatible_surface: None, 
                 force_fallback_adapter: false, 
-                power_preference: wgpu::PowerPreference::default(), 
+                power_preference: config.power_preference.into(), 
             }, 
         )
     )
-    .expect("Can't create wgpu adapter!");
+    .ok_or(AppError::AdapterNotFound)?;
+
+    let info = adapter.get_info();
+    log::info!(
+        "Adapter: {} ({:?}, {:?}, driver: {} {})", 
+        info.name, info.backend, info.device_type, info.driver, info.driver_info
+    );
+
+    // (한국어) 설정에서 요구한 기능들을 어댑터가 모두 지원하는지 확인합니다.
+    // (English Translation) Checks whether the adapter supports all features required by the configuration.
+    let required_features = config.wgpu_features()?;
+    let unsupported_features = required_features - adapter.features();
+    if !unsupported_features.is_empty() {
+        return Err(AppError::UnsupportedFeatures(unsupported_features));
+    }
+
+    // (한국어) GPU 프로파일링이 켜져 있다면 지원되는 경우 타임스탬프 쿼리를 활성화합니다.
+    // (English Translation) If GPU profiling is on, enables timestamp queries if supported.
+    let mut optional_features = adapter.features() & wgpu::Features::POLYGON_MODE_LINE;
+    if config.gpu_profiling {
+        match adapter.features().contains(wgpu::Features::TIMESTAMP_QUERY) {
+            true => optional_features |= wgpu::Features::TIMESTAMP_QUERY, 
+            false => log::warn!("GPU profiling is not available: the adapter does not support timestamp queries."), 
+        };
+    }
 
     let (device, queue) = pollster::block_on(
         adapter.request_device(
             &wgpu::DeviceDescriptor {
                 label: Some("GpuDevice"), 
-                required_features: wgpu::Features::empty(), 
-                required_limits: wgpu::Limits::default(), 
+                // (한국어) 와이어프레임 디버그 뷰를 위해 지원되는 경우 `PolygonMode::Line`을 활성화합니다.
+                // (English Translation) Enable `PolygonMode::Line` for the wireframe debug view if supported.
+                required_features: required_features | optional_features, 
+                required_limits: config.limits.to_wgpu(&adapter), 
             }, 
             None
         )
-    )
-    .expect("No suitable device was found!");
+    )?;
 
-    return (
-        instance.into(), 
+    Ok((
         adapter.into(), 
         device.into(), 
         queue.into()
-    );
+    ))
 }
 
 /// #### 한국어 </br>
-/// wgpu 렌더링 인스턴스들을 생성합니다. </br>
+/// 장치가 손실되었을 때 주어진 플래그를 설정하는 콜백을 등록합니다. </br>
+/// 장치가 해제되어 호출된 경우는 손실로 간주하지 않습니다. </br>
 /// 
 /// #### English (Translation) </br>
-/// Create wgpu rendering instances. </br>
+/// Registers a callback that sets the given flag when the device is lost. </br>
+/// Calls caused by the device being dropped are not considered a loss. </br>
 /// 
 #[allow(dead_code)]
-#[cfg(not(target_os = "android"))]
-fn init_wgpu_renderer<'a>(window: &'a Window) -> (
-    Arc<wgpu::Instance>, 
-    Arc<wgpu::Surface<'a>>, 
-    Arc<wgpu::Adapter>, 
-    Arc<wgpu::Device>, 
-    Arc<wgpu::Queue>
-) {
-    let instance = wgpu::Instance::new(
-        wgpu::InstanceDescriptor {
-            backends: wgpu::Backends::PRIMARY, 
-            ..Default::default()
-        }, 
-    );
-
-    let surface = instance.create_surface(
-        wgpu::SurfaceTarget::from(window)
-    )
-    .expect("Can't create wgpu surface!");
-
-    let adapter = pollster::block_on(
-        instance.request_adapter(
-            &wgpu::RequestAdapterOptions {
-                compatible_surface: None, 
-                force_fallback_adapter: false, 
-                power_preference: wgpu::PowerPreference::default(), 
+fn watch_device_lost(device: &wgpu::Device, device_lost: Arc<AtomicBool>) {
+    device.set_device_lost_callback(move |reason, message| {
+        match reason {
+            wgpu::DeviceLostReason::Dropped | wgpu::DeviceLostReason::ReplacedCallback => { /* empty */ }, 
+            _ => {
+                log::error!("Device lost ({:?}): {}", reason, message);
+                device_lost.store(true, Ordering::Release);
             }, 
-        )
-    )
-    .expect("Can't create wgpu adapter!");
+        }
+    });
+}
 
-    let (device, queue) = pollster::block_on(
-        adapter.request_device(
-            &wgpu::DeviceDescriptor {
-                label: Some("GpuDevice"), 
-                required_features: wgpu::Features::empty(), 
-                required_limits: wgpu::Limits::default(), 
-            }, 
-            None
-        )
-    )
-    .expect("No suitable device was found!");
+/// #### 한국어 </br>
+/// 손실된 장치를 대신할 새로운 장치를 생성하고, 장면의 모든 GPU 자원을 새로운 장치에서 다시 생성합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates a new device to replace the lost one and recreates all GPU resources of the scene on the new device. </br>
+/// 
+#[allow(dead_code)]
+fn recreate_device<S: GameScene>(
+    instance: &wgpu::Instance, 
+    config: &RendererConfig, 
+    device_lost: &Arc<AtomicBool>, 
+    scene: &mut S
+) -> Result<DeviceHandles, AppError> {
+    let (adapter, device, queue) = request_device(instance, config)?;
+    watch_device_lost(&device, device_lost.clone());
+    scene.on_device_recreated(&device, &queue)?;
+    Ok((adapter, device, queue))
+}
 
-    return (
-        instance.into(), 
-        surface.into(), 
-        adapter.into(), 
-        device.into(), 
-        queue.into()
-    );
+/// #### 한국어 </br>
+/// 렌더러 설정에 따라 `surface`가 지원하는 텍스처 형식을 선택합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Selects a texture format supported by the `surface` according to the renderer configuration. </br>
+/// 
+#[allow(dead_code)]
+fn select_surface_format(
+    config: &RendererConfig, 
+    adapter: &wgpu::Adapter, 
+    surface: &wgpu::Surface<'_>
+) -> Result<wgpu::TextureFormat, AppError> {
+    let capabilities = surface.get_capabilities(adapter);
+    let format = config.surface_format(&capabilities)
+        .ok_or(AppError::IncompatibleSurface)?;
+    log::info!("Surface format: {:?} (supported: {:?})", format, capabilities.formats);
+    Ok(format)
 }
 
 /// #### 한국어 </br>
-/// 스왑체인을 설정합니다. </br>
+/// 스왑체인의 텍스처 형식을 변경합니다. 형식이 바뀐 경우 장면에 알리고 `true`를 반환합니다. </br>
 /// 
 /// #### English (Translation) </br>
-/// Setup the swapchain. </br>
+/// Changes the texture format of the swapchain. If the format changed, notifies the scene and returns `true`. </br>
 /// 
 #[allow(dead_code)]
-fn setup_swapchain(width: u32, height: u32, device: &wgpu::Device, surface: &wgpu::Surface<'_>) {
-    let config = wgpu::SurfaceConfiguration {
+fn change_surface_format<S: GameScene>(
+    surface_format: &mut wgpu::TextureFormat, 
+    format: wgpu::TextureFormat, 
+    device: &wgpu::Device, 
+    queue: &wgpu::Queue, 
+    scene: &mut S
+) -> Result<bool, AppError> {
+    if *surface_format == format {
+        return Ok(false);
+    }
+
+    log::info!("Surface format changed: {:?} -> {:?}", surface_format, format);
+    scene.on_surface_format_changed(format, device, queue)?;
+    *surface_format = format;
+    Ok(true)
+}
+
+/// #### 한국어 </br>
+/// 렌더러 설정의 화면 출력 방식과 프레임 지연으로 스왑체인을 설정합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Setup the swapchain with the presentation mode and frame latency of the renderer configuration. </br>
+/// 
+#[allow(dead_code)]
+fn setup_swapchain(
+    width: u32, 
+    height: u32, 
+    format: wgpu::TextureFormat, 
+    config: &RendererConfig, 
+    adapter: &wgpu::Adapter, 
+    device: &wgpu::Device, 
+    surface: &wgpu::Surface<'_>
+) {
+    let capabilities = surface.get_capabilities(adapter);
+    let surface_config = wgpu::SurfaceConfiguration {
         usage: wgpu::TextureUsages::RENDER_ATTACHMENT, 
-        format: RENDER_TARGET_FORMAT, 
+        format, 
         width, 
         height, 
-        present_mode: wgpu::PresentMode::AutoVsync, 
-        desired_maximum_frame_latency: 2, 
+        present_mode: config.wgpu_present_mode(&capabilities), 
+        desired_maximum_frame_latency: config.desired_maximum_frame_latency, 
         alpha_mode: wgpu::CompositeAlphaMode::Auto, 
         view_formats: vec![],
     };
-    surface.configure(device, &config);
+    surface.configure(device, &surface_config);
+}
+
+/// #### 한국어 </br>
+/// 스왑체인의 다음 프레임을 가져옵니다. </br>
+/// `surface`가 손실되었거나 오래된 경우 스왑체인을 다시 설정하고, 시간이 초과된 경우와 함께 `None`을 반환합니다. </br>
+/// 호출자는 `None`을 받으면 이번 프레임을 건너뛰어야 합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Gets the next frame of the swapchain. </br>
+/// If the `surface` is lost or outdated, reconfigures the swapchain and returns `None`, as it does on a timeout. </br>
+/// The caller should skip this frame when it receives `None`. </br>
+/// 
+#[allow(dead_code)]
+fn acquire_frame(
+    window: &Window, 
+    surface: &wgpu::Surface<'_>, 
+    format: wgpu::TextureFormat, 
+    config: &RendererConfig, 
+    adapter: &wgpu::Adapter, 
+    device: &wgpu::Device
+) -> Result<Option<wgpu::SurfaceTexture>, AppError> {
+    match surface.get_current_texture() {
+        Ok(frame) => Ok(Some(frame)), 
+        Err(wgpu::SurfaceError::Lost | wgpu::SurfaceError::Outdated) => {
+            log::warn!("Surface is lost or outdated. Reconfiguring the swapchain.");
+            let size = window.inner_size();
+            if size.width > 0 && size.height > 0 {
+                setup_swapchain(size.width, size.height, format, config, adapter, device, surface);
+            }
+            Ok(None)
+        }, 
+        Err(wgpu::SurfaceError::Timeout) => {
+            log::warn!("Timed out while acquiring the next frame. Skipping the frame.");
+            Ok(None)
+        }, 
+        Err(err) => Err(err.into()), 
+    }
 }
 
 /// #### 한국어 </br>
@@ -0,0 +1,727 @@
+use std::io;
+use std::fmt;
+use std::hash;
+use std::thread;
+use std::sync::mpsc;
+use std::sync::Arc;
+use std::sync::Weak;
+use std::sync::Mutex;
+use std::marker::PhantomData;
+use std::path::Path;
+use std::path::PathBuf;
+use std::collections::HashMap;
+use crossbeam_queue::SegQueue;
+
+use crate::vfs::Vfs;
+
+
+
+/// #### 한국어 </br>
+/// 에셋을 읽거나 디코딩하거나 업로드하는 중에 발생할 수 있는 오류입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Errors that can occur while reading, decoding or uploading an asset. </br>
+/// 
+#[derive(Debug)]
+pub enum AssetError {
+    Io(PathBuf, io::Error), 
+    Decode(PathBuf, String), 
+    Upload(String), 
+}
+
+impl fmt::Display for AssetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(path, err) => write!(f, "Could not read asset file {}: {}", path.display(), err), 
+            Self::Decode(path, msg) => write!(f, "Could not decode asset {}: {}", path.display(), msg), 
+            Self::Upload(msg) => write!(f, "Could not upload asset: {}", msg), 
+        }
+    }
+}
+
+impl std::error::Error for AssetError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(_, err) => Some(err), 
+            _ => None, 
+        }
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 에셋 저장소가 불러올 수 있는 에셋의 trait입니다. </br>
+/// `decode`는 작업 스레드에서 파일의 내용을 CPU 측 데이터로 변환하고,
+/// `upload`는 메인 스레드에서 그 데이터로 GPU 자원을 생성합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The trait of assets that the asset store can load. </br>
+/// `decode` converts the contents of a file into CPU-side data on a worker thread,
+/// and `upload` creates GPU resources from that data on the main thread. </br>
+/// 
+pub trait Asset: Sized + 'static {
+    type Data: Send + 'static;
+
+    fn decode(path: &Path, bytes: &[u8]) -> Result<Self::Data, AssetError>;
+
+    fn upload(data: Self::Data, device: &wgpu::Device, queue: &wgpu::Queue) -> Result<Self, AssetError>;
+}
+
+
+
+/// #### 한국어 </br>
+/// 에셋의 불러오기 상태입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The load state of an asset. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LoadState {
+    Loading, 
+    Ready, 
+    Failed, 
+}
+
+/// #### 한국어 </br>
+/// 에셋 저장소 안에서 에셋을 구별하는 번호입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The number that identifies an asset in an asset store. </br>
+/// 
+pub type AssetId = u64;
+
+
+
+/// #### 한국어 </br>
+/// `Assets<T>`에 있는 에셋을 가리키는 참조 계수 핸들입니다. </br>
+/// 에셋을 가리키는 모든 핸들이 제거되면, `Assets::free_unused`에서 그 에셋이 해제됩니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A reference-counted handle pointing to an asset in `Assets<T>`. </br>
+/// Once all handles pointing to an asset are dropped, the asset is released in `Assets::free_unused`. </br>
+/// 
+pub struct Handle<T> {
+    id: Arc<AssetId>, 
+    _marker: PhantomData<fn() -> T>, 
+}
+
+#[allow(dead_code)]
+impl<T> Handle<T> {
+    #[inline]
+    pub fn id(&self) -> AssetId {
+        *self.id
+    }
+}
+
+impl<T> Clone for Handle<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self { id: self.id.clone(), _marker: PhantomData }
+    }
+}
+
+impl<T> fmt::Debug for Handle<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Handle<{}>({})", std::any::type_name::<T>(), self.id())
+    }
+}
+
+impl<T> Eq for Handle<T> { }
+
+impl<T> PartialEq<Self> for Handle<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.id().eq(&other.id())
+    }
+}
+
+impl<T> hash::Hash for Handle<T> {
+    #[inline]
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.id().hash(state);
+    }
+}
+
+
+
+type Job = Box<dyn FnOnce() + Send + 'static>;
+
+/// #### 한국어 </br>
+/// 에셋 파일을 가상 파일 시스템에서 읽고 디코딩하는 작업 스레드들입니다. 여러 에셋 저장소가 복제하여 함께 사용할 수 있습니다. </br>
+/// 마지막 복제본이 제거되면 남은 작업을 마친 후 작업 스레드들이 종료됩니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Worker threads that read asset files from the virtual file system and decode them. Several asset stores can clone and share it. </br>
+/// When the last clone is dropped, the worker threads exit after finishing the remaining jobs. </br>
+/// 
+#[derive(Debug, Clone)]
+pub struct AssetServer {
+    inner: Arc<AssetServerInner>, 
+}
+
+#[derive(Debug)]
+struct AssetServerInner {
+    vfs: Vfs, 
+    sender: Option<mpsc::Sender<Job>>, 
+    workers: Vec<thread::JoinHandle<()>>, 
+}
+
+#[allow(dead_code)]
+impl AssetServer {
+    /// #### 한국어 </br>
+    /// 가상 파일 시스템 `vfs`에서 에셋을 읽는 `num_workers`개의 작업 스레드를 생성합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates `num_workers` worker threads that read assets from the virtual file system `vfs`. </br>
+    /// 
+    pub fn new(vfs: Vfs, num_workers: usize) -> Self {
+        let (sender, receiver) = mpsc::channel::<Job>();
+        let receiver = Arc::new(Mutex::new(receiver));
+
+        let workers = (0..num_workers.max(1))
+            .filter_map(|index| {
+                let receiver = receiver.clone();
+                let spawned = thread::Builder::new()
+                    .name(format!("AssetWorker{}", index))
+                    .spawn(move || loop {
+                        // (한국어) 수신자를 잠근 채로 작업을 실행하지 않도록 작업을 꺼낸 후 잠금을 해제합니다.
+                        // (English Translation) Releases the lock after taking a job so that the job does not run while holding the receiver.
+                        let job = match receiver.lock() {
+                            Ok(receiver) => receiver.recv(), 
+                            Err(_) => return, 
+                        };
+                        match job {
+                            Ok(job) => job(), 
+                            Err(_) => return, 
+                        };
+                    });
+
+                match spawned {
+                    Ok(worker) => Some(worker), 
+                    Err(err) => {
+                        log::error!("Could not spawn asset worker thread: {}", err);
+                        None
+                    }
+                }
+            })
+            .collect();
+
+        Self {
+            inner: Arc::new(AssetServerInner {
+                vfs, 
+                sender: Some(sender), 
+                workers, 
+            }), 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 사용 가능한 CPU 수에 맞추어 작업 스레드를 생성합니다. 메인 스레드를 위해 하나를 남기며, 최대 4개까지 생성합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates worker threads according to the available CPUs. One is left for the main thread, and at most 4 are created. </br>
+    /// 
+    pub fn with_default_workers(vfs: Vfs) -> Self {
+        let num_workers = thread::available_parallelism()
+            .map(|num| num.get().saturating_sub(1))
+            .unwrap_or(1)
+            .clamp(1, 4);
+        Self::new(vfs, num_workers)
+    }
+
+    #[inline]
+    pub fn vfs(&self) -> &Vfs {
+        &self.inner.vfs
+    }
+
+    #[inline]
+    pub fn num_workers(&self) -> usize {
+        self.inner.workers.len()
+    }
+
+    /// #### 한국어 </br>
+    /// 작업 스레드에서 실행할 작업을 추가합니다. 작업 스레드가 없다면 현재 스레드에서 바로 실행합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Adds a job to run on a worker thread. If there are no worker threads, it runs immediately on the current thread. </br>
+    /// 
+    fn spawn(&self, job: Job) {
+        let job = match self.inner.sender.as_ref() {
+            Some(sender) if !self.inner.workers.is_empty() => match sender.send(job) {
+                Ok(_) => return, 
+                Err(mpsc::SendError(job)) => job, 
+            }, 
+            _ => job, 
+        };
+        job();
+    }
+}
+
+impl Drop for AssetServerInner {
+    fn drop(&mut self) {
+        // (한국어) 송신자를 제거하여 작업 스레드들이 남은 작업을 마친 후 종료되도록 합니다.
+        // (English Translation) Drops the sender so that the worker threads exit after finishing the remaining jobs.
+        self.sender = None;
+        for worker in self.workers.drain(..) {
+            if worker.join().is_err() {
+                log::error!("An asset worker thread panicked.");
+            }
+        }
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 작업 스레드에서 메인 스레드로 돌아오는 불러오기 결과입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The load result returned from a worker thread to the main thread. </br>
+/// 
+struct LoadResult<T: Asset> {
+    id: AssetId, 
+    generation: u32, 
+    data: Result<T::Data, AssetError>, 
+}
+
+#[derive(Debug)]
+struct AssetEntry<T> {
+    handle: Weak<AssetId>, 
+    path: Option<PathBuf>, 
+    generation: u32, 
+    state: LoadState, 
+    asset: Option<T>, 
+    error: Option<String>, 
+}
+
+
+
+/// #### 한국어 </br>
+/// 한 종류의 에셋들을 `Handle<T>`로 보관하는 저장소입니다. </br>
+/// 같은 경로의 에셋은 한 번만 불러오며, 파일은 작업 스레드에서 디코딩됩니다. </br>
+/// 디코딩이 끝난 데이터는 잠금 없는 대기열로 돌아오고, `process_loaded`에서 메인 스레드가 GPU에 업로드합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A store that keeps one kind of assets with `Handle<T>`. </br>
+/// An asset of the same path is loaded only once, and files are decoded on worker threads. </br>
+/// The decoded data comes back on a lock-free queue, and the main thread uploads it to the GPU in `process_loaded`. </br>
+/// 
+pub struct Assets<T: Asset> {
+    server: AssetServer, 
+    next_id: AssetId, 
+    entries: HashMap<AssetId, AssetEntry<T>>, 
+    paths: HashMap<PathBuf, AssetId>, 
+    loaded: Arc<SegQueue<LoadResult<T>>>, 
+}
+
+#[allow(dead_code)]
+impl<T: Asset> Assets<T> {
+    pub fn new(server: AssetServer) -> Self {
+        Self {
+            server, 
+            next_id: 0, 
+            entries: HashMap::new(), 
+            paths: HashMap::new(), 
+            loaded: Arc::new(SegQueue::new()), 
+        }
+    }
+
+    #[inline]
+    pub fn server(&self) -> &AssetServer {
+        &self.server
+    }
+
+    /// #### 한국어 </br>
+    /// 주어진 경로의 에셋을 불러오기 시작하고 핸들을 반환합니다. </br>
+    /// 같은 경로의 에셋이 이미 있다면, 다시 불러오지 않고 그 에셋의 핸들을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Starts loading the asset at the given path and returns its handle. </br>
+    /// If an asset of the same path already exists, returns the handle of that asset without loading it again. </br>
+    /// 
+    pub fn load<P: AsRef<Path>>(&mut self, path: P) -> Handle<T> {
+        let path = path.as_ref();
+        if let Some(handle) = self.paths.get(path)
+            .and_then(|id| self.entries.get(id))
+            .and_then(|entry| entry.handle.upgrade()) {
+            return Handle { id: handle, _marker: PhantomData };
+        }
+
+        let handle = self.create_handle();
+        self.entries.insert(handle.id(), AssetEntry {
+            handle: Arc::downgrade(&handle.id), 
+            path: Some(path.to_path_buf()), 
+            generation: 0, 
+            state: LoadState::Loading, 
+            asset: None, 
+            error: None, 
+        });
+        self.paths.insert(path.to_path_buf(), handle.id());
+        self.request_load(handle.id(), 0, path.to_path_buf());
+        handle
+    }
+
+    /// #### 한국어 </br>
+    /// 이미 생성된 에셋을 저장소에 추가하고 핸들을 반환합니다. </br>
+    /// 경로가 없으므로 `reload`와 `reload_all`로 다시 불러올 수 없습니다.
+    /// GPU 자원을 가진 에셋이라면, 장치가 손실된 후 호출자가 직접 다시 생성하여 교체해야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Adds an already created asset to the store and returns its handle. </br>
+    /// Since it has no path, it cannot be loaded again with `reload` and `reload_all`.
+    /// If the asset owns GPU resources, the caller must recreate and replace it after the device was lost. </br>
+    /// 
+    pub fn insert(&mut self, asset: T) -> Handle<T> {
+        let handle = self.create_handle();
+        self.entries.insert(handle.id(), AssetEntry {
+            handle: Arc::downgrade(&handle.id), 
+            path: None, 
+            generation: 0, 
+            state: LoadState::Ready, 
+            asset: Some(asset), 
+            error: None, 
+        });
+        handle
+    }
+
+    #[inline]
+    pub fn get(&self, handle: &Handle<T>) -> Option<&T> {
+        self.entries.get(&handle.id()).and_then(|entry| entry.asset.as_ref())
+    }
+
+    #[inline]
+    pub fn get_mut(&mut self, handle: &Handle<T>) -> Option<&mut T> {
+        self.entries.get_mut(&handle.id()).and_then(|entry| entry.asset.as_mut())
+    }
+
+    /// #### 한국어 </br>
+    /// 에셋의 불러오기 상태를 반환합니다. 다시 불러오는 중인 에셋은 이전 에셋이 남아있어도 `Loading`입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the load state of the asset. An asset being reloaded is `Loading` even if the previous asset remains. </br>
+    /// 
+    #[inline]
+    pub fn load_state(&self, handle: &Handle<T>) -> LoadState {
+        self.entries.get(&handle.id())
+            .map(|entry| entry.state)
+            .unwrap_or(LoadState::Failed)
+    }
+
+    #[inline]
+    pub fn load_error(&self, handle: &Handle<T>) -> Option<&str> {
+        self.entries.get(&handle.id()).and_then(|entry| entry.error.as_deref())
+    }
+
+    #[inline]
+    pub fn path(&self, handle: &Handle<T>) -> Option<&Path> {
+        self.entries.get(&handle.id()).and_then(|entry| entry.path.as_deref())
+    }
+
+    /// #### 한국어 </br>
+    /// 불러오는 중인 에셋이 있는지 여부를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns whether there is an asset being loaded. </br>
+    /// 
+    #[inline]
+    pub fn is_loading(&self) -> bool {
+        self.entries.values().any(|entry| entry.state == LoadState::Loading)
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// #### 한국어 </br>
+    /// 작업 스레드에서 디코딩이 끝난 에셋들을 GPU에 업로드합니다. 메인 스레드에서 매 프레임마다 호출해야 합니다. </br>
+    /// 불러오기가 끝난(성공 또는 실패) 에셋들의 핸들 번호를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Uploads the assets decoded on worker threads to the GPU. It must be called every frame on the main thread. </br>
+    /// Returns the handle ids of the assets whose loading has finished (succeeded or failed). </br>
+    /// 
+    pub fn process_loaded(&mut self, device: &wgpu::Device, queue: &wgpu::Queue) -> Vec<AssetId> {
+        self.finish_loaded(|data| T::upload(data, device, queue))
+    }
+
+    /// #### 한국어 </br>
+    /// 작업 스레드에서 돌아온 결과들을 `upload`로 에셋으로 만들고 에셋의 상태를 갱신합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Turns the results returned from worker threads into assets with `upload`, and updates the states of the assets. </br>
+    /// 
+    fn finish_loaded<F>(&mut self, mut upload: F) -> Vec<AssetId>
+    where F: FnMut(T::Data) -> Result<T, AssetError> {
+        let mut finished = Vec::new();
+        while let Some(result) = self.loaded.pop() {
+            // (한국어) 해제되었거나 다시 불러오기가 요청된 에셋의 이전 결과는 버립니다.
+            // (English Translation) Discards outdated results of assets that were released or requested to be loaded again.
+            let Some(entry) = self.entries.get_mut(&result.id) else {
+                continue;
+            };
+            if entry.generation != result.generation {
+                continue;
+            }
+
+            match result.data.and_then(&mut upload) {
+                Ok(asset) => {
+                    entry.state = LoadState::Ready;
+                    entry.asset = Some(asset);
+                    entry.error = None;
+                }, 
+                Err(err) => {
+                    log::error!("{}", err);
+                    entry.state = LoadState::Failed;
+                    entry.error = Some(err.to_string());
+                }, 
+            };
+            finished.push(result.id);
+        }
+        finished
+    }
+
+    /// #### 한국어 </br>
+    /// 경로가 있는 에셋을 다시 불러옵니다. 새로운 에셋이 준비될 때 까지 이전 에셋이 유지됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Loads an asset with a path again. The previous asset is kept until the new one is ready. </br>
+    /// 
+    pub fn reload(&mut self, handle: &Handle<T>) {
+        self.reload_by_id(handle.id());
+    }
+
+    /// #### 한국어 </br>
+    /// 경로가 있는 모든 에셋을 다시 불러옵니다. 장치가 손실된 후 GPU 자원을 다시 생성하는 데 사용됩니다. </br>
+    /// `insert`로 추가된 에셋은 경로가 없으므로 건너뛰며, 이전 장치의 자원을 그대로 가지고 있습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Loads all assets with a path again. Used to recreate GPU resources after the device was lost. </br>
+    /// Assets added with `insert` have no path, so they are skipped and keep the resources of the previous device. </br>
+    /// 
+    pub fn reload_all(&mut self) {
+        let ids: Vec<_> = self.entries.keys().copied().collect();
+        for id in ids {
+            self.reload_by_id(id);
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 핸들이 모두 제거된 에셋들을 해제하고, 해제된 에셋의 수를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Releases the assets whose handles have all been dropped, and returns the number of released assets. </br>
+    /// 
+    pub fn free_unused(&mut self) -> usize {
+        let num_entries = self.entries.len();
+        self.entries.retain(|_, entry| entry.handle.strong_count() > 0);
+        let entries = &self.entries;
+        self.paths.retain(|_, id| entries.contains_key(id));
+        num_entries - self.entries.len()
+    }
+
+    fn create_handle(&mut self) -> Handle<T> {
+        let id = self.next_id;
+        self.next_id += 1;
+        Handle { id: Arc::new(id), _marker: PhantomData }
+    }
+
+    fn reload_by_id(&mut self, id: AssetId) {
+        let Some(entry) = self.entries.get_mut(&id) else {
+            return;
+        };
+        let Some(path) = entry.path.clone() else {
+            return;
+        };
+
+        entry.generation = entry.generation.wrapping_add(1);
+        entry.state = LoadState::Loading;
+        let generation = entry.generation;
+        self.request_load(id, generation, path);
+    }
+
+    fn request_load(&self, id: AssetId, generation: u32, path: PathBuf) {
+        let vfs = self.server.vfs().clone();
+        let loaded = self.loaded.clone();
+        self.server.spawn(Box::new(move || {
+            let data = vfs.read(&path)
+                .map_err(|err| AssetError::Io(path.clone(), err))
+                .and_then(|bytes| T::decode(&path, &bytes));
+            loaded.push(LoadResult { id, generation, data });
+        }));
+    }
+}
+
+impl<T: Asset + fmt::Debug> fmt::Debug for Assets<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Assets")
+            .field("server", &self.server)
+            .field("entries", &self.entries)
+            .field("paths", &self.paths)
+            .finish_non_exhaustive()
+    }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+    use std::time::Instant;
+    use crate::vfs::MemoryFs;
+    use super::*;
+
+    #[derive(Debug)]
+    struct Text(String);
+
+    impl Asset for Text {
+        type Data = String;
+
+        fn decode(path: &Path, bytes: &[u8]) -> Result<Self::Data, AssetError> {
+            String::from_utf8(bytes.to_vec()).map_err(|err| AssetError::Decode(path.to_path_buf(), err.to_string()))
+        }
+
+        fn upload(data: Self::Data, _device: &wgpu::Device, _queue: &wgpu::Queue) -> Result<Self, AssetError> {
+            Ok(Self(data))
+        }
+    }
+
+    fn text_assets(fs: &MemoryFs) -> Assets<Text> {
+        Assets::new(AssetServer::new(Vfs::new().with_mount("", fs.clone()), 1))
+    }
+
+    /// #### 한국어 </br>
+    /// 작업 스레드에서 `num_results`개의 결과가 돌아올 때 까지 기다린 후, GPU 없이 처리합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Waits until `num_results` results come back from the worker threads, then processes them without a GPU. </br>
+    /// 
+    fn finish(assets: &mut Assets<Text>, num_results: usize) -> Vec<AssetId> {
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while assets.loaded.len() < num_results {
+            assert!(Instant::now() < deadline, "timed out waiting for the asset workers");
+            thread::sleep(Duration::from_millis(1));
+        }
+        assets.finish_loaded(|data| Ok(Text(data)))
+    }
+
+    #[test]
+    fn load_deduplicates_paths() {
+        let fs = MemoryFs::new().with_file("a.txt", b"a".to_vec()).unwrap();
+        let mut assets = text_assets(&fs);
+
+        let first = assets.load("a.txt");
+        let second = assets.load("a.txt");
+        let other = assets.load("b.txt");
+        assert_eq!(first, second);
+        assert_ne!(first, other);
+        assert_eq!(assets.len(), 2);
+        assert_eq!(assets.path(&first), Some(Path::new("a.txt")));
+
+        finish(&mut assets, 2);
+        assert_eq!(assets.get(&second).unwrap().0, "a");
+    }
+
+    #[test]
+    fn load_state_transitions() {
+        let fs = MemoryFs::new()
+            .with_file("a.txt", b"a".to_vec()).unwrap()
+            .with_file("invalid.txt", vec![0xff, 0xfe]).unwrap();
+        let mut assets = text_assets(&fs);
+
+        let ready = assets.load("a.txt");
+        let missing = assets.load("missing.txt");
+        let invalid = assets.load("invalid.txt");
+        assert_eq!(assets.load_state(&ready), LoadState::Loading);
+        assert!(assets.is_loading());
+        assert!(assets.get(&ready).is_none());
+
+        let mut finished = finish(&mut assets, 3);
+        finished.sort();
+        assert_eq!(finished, vec![ready.id(), missing.id(), invalid.id()]);
+        assert!(!assets.is_loading());
+        assert_eq!(assets.load_state(&ready), LoadState::Ready);
+        assert_eq!(assets.load_state(&missing), LoadState::Failed);
+        assert_eq!(assets.load_state(&invalid), LoadState::Failed);
+        assert!(assets.load_error(&ready).is_none());
+        assert!(assets.load_error(&missing).is_some());
+        assert!(assets.load_error(&invalid).is_some());
+
+        // (한국어) 다시 불러오는 동안에는 이전 에셋이 유지됩니다.
+        // (English Translation) The previous asset is kept while loading again.
+        fs.insert("a.txt", b"b".to_vec()).unwrap();
+        assets.reload(&ready);
+        assert_eq!(assets.load_state(&ready), LoadState::Loading);
+        assert_eq!(assets.get(&ready).unwrap().0, "a");
+        finish(&mut assets, 1);
+        assert_eq!(assets.load_state(&ready), LoadState::Ready);
+        assert_eq!(assets.get(&ready).unwrap().0, "b");
+
+        // (한국어) 실패한 에셋도 파일이 고쳐지면 다시 불러올 수 있습니다.
+        // (English Translation) A failed asset can be loaded again once the file is fixed.
+        fs.insert("missing.txt", b"found".to_vec()).unwrap();
+        assets.reload(&missing);
+        finish(&mut assets, 1);
+        assert_eq!(assets.load_state(&missing), LoadState::Ready);
+        assert!(assets.load_error(&missing).is_none());
+    }
+
+    #[test]
+    fn process_loaded_discards_stale_generations() {
+        let fs = MemoryFs::new().with_file("a.txt", b"old".to_vec()).unwrap();
+        let mut assets = text_assets(&fs);
+
+        let handle = assets.load("a.txt");
+        finish(&mut assets, 1);
+
+        // (한국어) 
+        // 두 번 다시 불러오기를 요청하면 두 결과가 모두 돌아오지만, 마지막 요청의 결과만 사용됩니다.
+        // 작업 스레드가 하나이므로 결과는 요청한 순서대로 돌아옵니다.
+        // 
+        // (English Translation) 
+        // Requesting to load again twice returns both results, but only the result of the last request is used.
+        // Since there is one worker thread, the results come back in the order they were requested.
+        // 
+        assets.reload(&handle);
+        fs.insert("a.txt", b"new".to_vec()).unwrap();
+        assets.reload(&handle);
+        let finished = finish(&mut assets, 2);
+        assert_eq!(finished, vec![handle.id()]);
+        assert_eq!(assets.get(&handle).unwrap().0, "new");
+        assert_eq!(assets.load_state(&handle), LoadState::Ready);
+    }
+
+    #[test]
+    fn free_unused_releases_assets_without_handles() {
+        let fs = MemoryFs::new().with_file("a.txt", b"a".to_vec()).unwrap();
+        let mut assets = text_assets(&fs);
+
+        let loaded = assets.load("a.txt");
+        let inserted = assets.insert(Text("inserted".to_string()));
+        let kept = assets.insert(Text("kept".to_string()));
+        let loaded_id = loaded.id();
+        assert_eq!(assets.free_unused(), 0);
+
+        drop(loaded);
+        drop(inserted.clone());
+        assert_eq!(assets.free_unused(), 1);
+        drop(inserted);
+        assert_eq!(assets.free_unused(), 1);
+        assert_eq!(assets.len(), 1);
+        assert_eq!(assets.get(&kept).unwrap().0, "kept");
+
+        // (한국어) 해제된 에셋의 결과는 버려지며, 같은 경로는 새로운 에셋으로 다시 불러옵니다.
+        // (English Translation) The result of a released asset is discarded, and the same path is loaded again as a new asset.
+        assert!(finish(&mut assets, 1).is_empty());
+        let reloaded = assets.load("a.txt");
+        assert_ne!(reloaded.id(), loaded_id);
+        finish(&mut assets, 1);
+        assert_eq!(assets.get(&reloaded).unwrap().0, "a");
+    }
+}
@@ -0,0 +1,404 @@
+use std::path::Path;
+use std::path::PathBuf;
+use serde::Deserialize;
+use serde::Serialize;
+use serde::de::IntoDeserializer;
+
+use crate::error::AppError;
+use crate::vfs::Vfs;
+
+
+
+/// #### 한국어 </br>
+/// 스왑체인의 화면 출력 방식입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The presentation mode of the swapchain. </br>
+/// 
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PresentMode {
+    #[default]
+    AutoVsync, 
+    AutoNoVsync, 
+    Fifo, 
+    Mailbox, 
+    Immediate, 
+}
+
+impl From<PresentMode> for wgpu::PresentMode {
+    #[inline]
+    fn from(value: PresentMode) -> Self {
+        match value {
+            PresentMode::AutoVsync => wgpu::PresentMode::AutoVsync, 
+            PresentMode::AutoNoVsync => wgpu::PresentMode::AutoNoVsync, 
+            PresentMode::Fifo => wgpu::PresentMode::Fifo, 
+            PresentMode::Mailbox => wgpu::PresentMode::Mailbox, 
+            PresentMode::Immediate => wgpu::PresentMode::Immediate, 
+        }
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 렌더링에 사용할 그래픽스 API 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The graphics API to use for rendering. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Backend {
+    Vulkan, 
+    Metal, 
+    Dx12, 
+    Gl, 
+}
+
+impl From<Backend> for wgpu::Backends {
+    #[inline]
+    fn from(value: Backend) -> Self {
+        match value {
+            Backend::Vulkan => wgpu::Backends::VULKAN, 
+            Backend::Metal => wgpu::Backends::METAL, 
+            Backend::Dx12 => wgpu::Backends::DX12, 
+            Backend::Gl => wgpu::Backends::GL, 
+        }
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 어댑터를 선택할 때의 전력 선호도입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The power preference when selecting an adapter. </br>
+/// 
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PowerPreference {
+    #[default]
+    None, 
+    LowPower, 
+    HighPerformance, 
+}
+
+impl From<PowerPreference> for wgpu::PowerPreference {
+    #[inline]
+    fn from(value: PowerPreference) -> Self {
+        match value {
+            PowerPreference::None => wgpu::PowerPreference::None, 
+            PowerPreference::LowPower => wgpu::PowerPreference::LowPower, 
+            PowerPreference::HighPerformance => wgpu::PowerPreference::HighPerformance, 
+        }
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 장치를 생성할 때 요구할 제한값의 기준입니다. </br>
+/// `Adapter`는 선택된 어댑터가 지원하는 최대 제한값을 사용합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The basis of the limits to require when creating the device. </br>
+/// `Adapter` uses the maximum limits supported by the selected adapter. </br>
+/// 
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Limits {
+    #[default]
+    Default, 
+    Downlevel, 
+    DownlevelWebgl2, 
+    Adapter, 
+}
+
+impl Limits {
+    pub fn to_wgpu(self, adapter: &wgpu::Adapter) -> wgpu::Limits {
+        match self {
+            Self::Default => wgpu::Limits::default(), 
+            Self::Downlevel => wgpu::Limits::downlevel_defaults(), 
+            Self::DownlevelWebgl2 => wgpu::Limits::downlevel_webgl2_defaults(), 
+            Self::Adapter => adapter.limits(), 
+        }
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 이벤트 루프가 프레임을 갱신하고 그리는 방식입니다. </br>
+/// - `Continuous`: 매 루프마다 프레임을 그립니다. 프레임 속도는 화면 출력 방식(수직 동기화)에 의해 제한됩니다. </br>
+/// - `Capped`: `target_fps`를 넘지 않도록 다음 프레임까지 기다립니다(`ControlFlow::WaitUntil`). </br>
+/// - `OnDemand`: 입력이 있거나 장면이 변경되었다고 알릴 때만 프레임을 그립니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// How the event loop updates and draws frames. </br>
+/// - `Continuous`: Draws a frame on every loop. The frame rate is limited by the presentation mode (vertical sync). </br>
+/// - `Capped`: Waits until the next frame so as not to exceed `target_fps` (`ControlFlow::WaitUntil`). </br>
+/// - `OnDemand`: Draws a frame only when there is input or the scene reports that it has changed. </br>
+/// 
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LoopMode {
+    #[default]
+    Continuous, 
+    Capped, 
+    OnDemand, 
+}
+
+
+
+/// #### 한국어 </br>
+/// 렌더러의 설정입니다. </br>
+/// 개발용 실행 파일에서는 파일(`--config <path>`)과 명령줄 인자로 설정할 수 있습니다. </br>
+/// `shader_dir`이 주어지면 데스크톱에서 그 디렉토리의 셰이더 파일들이 변경될 때 다시 불러옵니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The settings of the renderer. </br>
+/// In the dev binary, it can be set from a file (`--config <path>`) and command-line arguments. </br>
+/// If `shader_dir` is given, the shader files in that directory are reloaded on desktop when they change. </br>
+/// 
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RendererConfig {
+    pub present_mode: PresentMode, 
+    pub prefer_srgb: bool, 
+    pub desired_maximum_frame_latency: u32, 
+    pub backends: Vec<Backend>, 
+    pub power_preference: PowerPreference, 
+    pub required_features: Vec<String>, 
+    pub limits: Limits, 
+    pub loop_mode: LoopMode, 
+    pub target_fps: u32, 
+    pub gpu_profiling: bool, 
+    pub cpu_profiling: bool, 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shader_dir: Option<PathBuf>, 
+}
+
+#[allow(dead_code)]
+impl RendererConfig {
+    /// #### 한국어 </br>
+    /// 가상 파일 시스템에서 `TOML` 형식의 설정 파일을 읽어옵니다. 파일에 없는 항목은 기본값을 사용합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Reads a configuration file in `TOML` format from the virtual file system. Items missing from the file use the default values. </br>
+    /// 
+    pub fn from_vfs<P: AsRef<Path>>(vfs: &Vfs, path: P) -> Result<Self, AppError> {
+        let path = path.as_ref();
+        let text = vfs.read_to_string(path)
+            .map_err(|err| AppError::Config(format!("Could not read {}: {}", path.display(), err)))?;
+        Self::from_toml(&text)
+    }
+
+    /// #### 한국어 </br>
+    /// `TOML` 형식의 문자열에서 설정을 읽어옵니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Reads the configuration from a string in `TOML` format. </br>
+    /// 
+    pub fn from_toml(text: &str) -> Result<Self, AppError> {
+        let config: Self = toml::from_str(text).map_err(|err| AppError::Config(err.to_string()))?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// #### 한국어 </br>
+    /// 명령줄 인자에서 설정을 읽어옵니다. </br>
+    /// `--config <path>`가 주어지면 가상 파일 시스템에서 파일을 먼저 읽고, 나머지 인자로 값을 덮어씁니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Reads the configuration from command-line arguments. </br>
+    /// If `--config <path>` is given, the file is first read from the virtual file system and its values are overridden by the remaining arguments. </br>
+    /// 
+    /// - `--present-mode <auto_vsync|auto_no_vsync|fifo|mailbox|immediate>`
+    /// - `--srgb <true|false>`
+    /// - `--frame-latency <n>`
+    /// - `--backends <vulkan,metal,dx12,gl>`
+    /// - `--power-preference <none|low_power|high_performance>`
+    /// - `--features <NAME,...>`
+    /// - `--limits <default|downlevel|downlevel_webgl2|adapter>`
+    /// - `--loop-mode <continuous|capped|on_demand>`
+    /// - `--target-fps <n>`
+    /// - `--gpu-profiling <true|false>`
+    /// - `--cpu-profiling <true|false>`
+    /// - `--shader-dir <path>`
+    /// 
+    pub fn from_args<I: IntoIterator<Item = String>>(vfs: &Vfs, args: I) -> Result<Self, AppError> {
+        let mut options = Vec::new();
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let (key, value) = match arg.split_once('=') {
+                Some((key, value)) => (key.to_string(), value.to_string()), 
+                None => {
+                    let value = args.next()
+                        .ok_or_else(|| AppError::Config(format!("Missing value for {}", arg)))?;
+                    (arg, value)
+                }, 
+            };
+            options.push((key, value));
+        }
+
+        let mut config = match options.iter().find(|(key, _)| key == "--config") {
+            Some((_, path)) => Self::from_vfs(vfs, path)?, 
+            None => Self::default(), 
+        };
+
+        for (key, value) in options.iter() {
+            match key.as_str() {
+                "--config" => { /* empty */ }, 
+                "--present-mode" => config.present_mode = parse_value(key, value)?, 
+                "--srgb" => config.prefer_srgb = value.parse()
+                    .map_err(|_| AppError::Config(format!("Invalid value for {}: {}", key, value)))?, 
+                "--frame-latency" => config.desired_maximum_frame_latency = value.parse()
+                    .map_err(|_| AppError::Config(format!("Invalid value for {}: {}", key, value)))?, 
+                "--backends" => config.backends = split_list(value)
+                    .map(|value| parse_value(key, value))
+                    .collect::<Result<_, _>>()?, 
+                "--power-preference" => config.power_preference = parse_value(key, value)?, 
+                "--features" => config.required_features = split_list(value)
+                    .map(|value| value.to_string())
+                    .collect(), 
+                "--limits" => config.limits = parse_value(key, value)?, 
+                "--loop-mode" => config.loop_mode = parse_value(key, value)?, 
+                "--target-fps" => config.target_fps = value.parse()
+                    .map_err(|_| AppError::Config(format!("Invalid value for {}: {}", key, value)))?, 
+                "--gpu-profiling" => config.gpu_profiling = value.parse()
+                    .map_err(|_| AppError::Config(format!("Invalid value for {}: {}", key, value)))?, 
+                "--cpu-profiling" => config.cpu_profiling = value.parse()
+                    .map_err(|_| AppError::Config(format!("Invalid value for {}: {}", key, value)))?, 
+                "--shader-dir" => config.shader_dir = Some(PathBuf::from(value)), 
+                _ => return Err(AppError::Config(format!("Unknown argument: {}", key))), 
+            }
+        }
+
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// #### 한국어 </br>
+    /// 설정 값들이 올바른지 검사합니다. </br>
+    /// 빈 백엔드 목록은 어댑터를 찾지 못하는 오류로 이어지므로 미리 거부합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Checks whether the configuration values are valid. </br>
+    /// An empty backend list is rejected up front, since it would otherwise end in an adapter-not-found error. </br>
+    /// 
+    pub fn validate(&self) -> Result<(), AppError> {
+        if self.backends.is_empty() {
+            return Err(AppError::Config("At least one backend must be configured".to_string()));
+        }
+
+        Ok(())
+    }
+
+    /// #### 한국어 </br>
+    /// 설정된 그래픽스 API들을 `wgpu`의 백엔드 집합으로 변환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Converts the configured graphics APIs into a set of `wgpu` backends. </br>
+    /// 
+    pub fn wgpu_backends(&self) -> wgpu::Backends {
+        self.backends.iter()
+            .fold(wgpu::Backends::empty(), |backends, backend| backends | (*backend).into())
+    }
+
+    /// #### 한국어 </br>
+    /// 설정된 필수 기능들의 이름을 `wgpu`의 기능 집합으로 변환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Converts the names of the configured required features into a set of `wgpu` features. </br>
+    /// 
+    pub fn wgpu_features(&self) -> Result<wgpu::Features, AppError> {
+        self.required_features.iter()
+            .try_fold(wgpu::Features::empty(), |features, name| {
+                wgpu::Features::from_name(&name.to_uppercase())
+                    .map(|feature| features | feature)
+                    .ok_or_else(|| AppError::Config(format!("Unknown feature: {}", name)))
+            })
+    }
+
+    /// #### 한국어 </br>
+    /// 설정된 화면 출력 방식을 반환합니다. `surface`가 지원하지 않는 경우 `AutoVsync`를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the configured presentation mode. If the `surface` does not support it, returns `AutoVsync`. </br>
+    /// 
+    pub fn wgpu_present_mode(&self, capabilities: &wgpu::SurfaceCapabilities) -> wgpu::PresentMode {
+        let present_mode = wgpu::PresentMode::from(self.present_mode);
+        match present_mode {
+            wgpu::PresentMode::AutoVsync | wgpu::PresentMode::AutoNoVsync => present_mode, 
+            _ if capabilities.present_modes.contains(&present_mode) => present_mode, 
+            _ => {
+                log::warn!("Present mode {:?} is not supported. Falling back to AutoVsync.", present_mode);
+                wgpu::PresentMode::AutoVsync
+            }, 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// `surface`가 지원하는 텍스처 형식 중 하나를 선택합니다. </br>
+    /// `prefer_srgb`에 따라 sRGB 형식 또는 선형 형식을 우선하며, 없는 경우 `surface`가 가장 선호하는 형식을 반환합니다. </br>
+    /// `surface`가 어댑터와 호환되지 않는 경우 `None`을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Selects one of the texture formats supported by the `surface`. </br>
+    /// Prefers sRGB or linear formats according to `prefer_srgb`, and if there are none, returns the format most preferred by the `surface`. </br>
+    /// Returns `None` if the `surface` is not compatible with the adapter. </br>
+    /// 
+    pub fn surface_format(&self, capabilities: &wgpu::SurfaceCapabilities) -> Option<wgpu::TextureFormat> {
+        capabilities.formats.iter()
+            .find(|format| format.is_srgb() == self.prefer_srgb)
+            .or(capabilities.formats.first())
+            .copied()
+    }
+}
+
+impl Default for RendererConfig {
+    fn default() -> Self {
+        Self {
+            present_mode: PresentMode::default(), 
+            prefer_srgb: true, 
+            desired_maximum_frame_latency: 2, 
+            #[cfg(target_os = "android")]
+            backends: vec![Backend::Vulkan], 
+            #[cfg(not(target_os = "android"))]
+            backends: vec![Backend::Vulkan, Backend::Metal, Backend::Dx12], 
+            power_preference: PowerPreference::default(), 
+            required_features: Vec::new(), 
+            limits: Limits::default(), 
+            loop_mode: LoopMode::default(), 
+            target_fps: 60, 
+            gpu_profiling: false, 
+            cpu_profiling: false, 
+            shader_dir: None, 
+        }
+    }
+}
+
+/// #### 한국어 </br>
+/// 쉼표로 구분된 목록을 나눕니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Splits a comma separated list. </br>
+/// 
+fn split_list(value: &str) -> impl Iterator<Item = &str> {
+    value.split(',')
+        .map(|it| it.trim())
+        .filter(|it| !it.is_empty())
+}
+
+/// #### 한국어 </br>
+/// 설정 파일과 같은 이름 규칙(`snake_case`)으로 명령줄 인자의 값을 읽어옵니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Reads the value of a command-line argument with the same naming rule (`snake_case`) as the configuration file. </br>
+/// 
+fn parse_value<'de, T: Deserialize<'de>>(key: &str, value: &'de str) -> Result<T, AppError> {
+    T::deserialize(value.into_deserializer())
+        .map_err(|err: serde::de::value::Error| AppError::Config(format!("Invalid value for {}: {}", key, err)))
+}
@@ -0,0 +1,115 @@
+use std::fmt;
+
+use crate::render::shader::ShaderError;
+use crate::render::vertex::VertexFormat;
+
+
+
+/// #### 한국어 </br>
+/// 애플리케이션의 초기화와 렌더링 중에 발생할 수 있는 오류입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Errors that can occur during initialization and rendering of the application. </br>
+/// 
+#[derive(Debug)]
+pub enum AppError {
+    EventLoop(winit::error::EventLoopError), 
+    Window(winit::error::OsError), 
+    CreateSurface(wgpu::CreateSurfaceError), 
+    AdapterNotFound, 
+    RequestDevice(wgpu::RequestDeviceError), 
+    UnsupportedFeatures(wgpu::Features), 
+    Config(String), 
+    IncompatibleSurface, 
+    Surface(wgpu::SurfaceError), 
+    LayoutNotFound(String), 
+    Shader(ShaderError), 
+    Pipeline(String), 
+    VertexFormatMismatch {
+        material: String, 
+        expected: VertexFormat, 
+        found: VertexFormat, 
+    }, 
+}
+
+impl fmt::Display for AppError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::EventLoop(err) => write!(f, "Event loop error: {}", err), 
+            Self::Window(err) => write!(f, "Could not create window: {}", err), 
+            Self::CreateSurface(err) => write!(f, "Could not create wgpu surface: {}", err), 
+            Self::AdapterNotFound => write!(f, "No suitable wgpu adapter was found"), 
+            Self::RequestDevice(err) => write!(f, "Could not create wgpu device: {}", err), 
+            Self::UnsupportedFeatures(features) => write!(f, "Required features are not supported by the adapter: {:?}", features), 
+            Self::Config(msg) => write!(f, "Invalid renderer configuration: {}", msg), 
+            Self::IncompatibleSurface => write!(f, "The surface is not compatible with the adapter"), 
+            Self::Surface(err) => write!(f, "Surface error: {}", err), 
+            Self::LayoutNotFound(name) => write!(f, "BindGroupLayout not found: {}", name), 
+            Self::Shader(err) => write!(f, "{}", err), 
+            Self::Pipeline(msg) => write!(f, "Could not create render pipeline: {}", msg), 
+            Self::VertexFormatMismatch { material, expected, found } => write!(
+                f, 
+                "Vertex format mismatch: {} requires {}, but the mesh has {}", 
+                material, 
+                expected, 
+                found
+            ), 
+        }
+    }
+}
+
+impl std::error::Error for AppError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::EventLoop(err) => Some(err), 
+            Self::Window(err) => Some(err), 
+            Self::CreateSurface(err) => Some(err), 
+            Self::RequestDevice(err) => Some(err), 
+            Self::Surface(err) => Some(err), 
+            Self::Shader(err) => Some(err), 
+            _ => None, 
+        }
+    }
+}
+
+impl From<winit::error::EventLoopError> for AppError {
+    #[inline]
+    fn from(value: winit::error::EventLoopError) -> Self {
+        Self::EventLoop(value)
+    }
+}
+
+impl From<winit::error::OsError> for AppError {
+    #[inline]
+    fn from(value: winit::error::OsError) -> Self {
+        Self::Window(value)
+    }
+}
+
+impl From<wgpu::CreateSurfaceError> for AppError {
+    #[inline]
+    fn from(value: wgpu::CreateSurfaceError) -> Self {
+        Self::CreateSurface(value)
+    }
+}
+
+impl From<wgpu::RequestDeviceError> for AppError {
+    #[inline]
+    fn from(value: wgpu::RequestDeviceError) -> Self {
+        Self::RequestDevice(value)
+    }
+}
+
+impl From<wgpu::SurfaceError> for AppError {
+    #[inline]
+    fn from(value: wgpu::SurfaceError) -> Self {
+        Self::Surface(value)
+    }
+}
+
+impl From<ShaderError> for AppError {
+    #[inline]
+    fn from(value: ShaderError) -> Self {
+        Self::Shader(value)
+    }
+}
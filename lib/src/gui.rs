@@ -0,0 +1,194 @@
+use winit::window::Window;
+use winit::event::WindowEvent;
+
+
+
+/// #### 한국어 </br>
+/// 한 프레임 동안 생성된 즉시 모드 GUI의 그리기 데이터입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The drawing data of the immediate mode GUI generated during a single frame. </br>
+/// 
+struct GuiFrame {
+    paint_jobs: Vec<egui::ClippedPrimitive>, 
+    textures_delta: egui::TexturesDelta, 
+    screen_descriptor: egui_wgpu::ScreenDescriptor, 
+}
+
+
+
+/// #### 한국어 </br>
+/// 디버그 패널을 위한 즉시 모드 GUI(egui) 통합 계층입니다. </br>
+/// `winit` 이벤트를 입력으로 받고, 합성 패스 이후에 렌더 타겟 위에 그려집니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// An immediate mode GUI (egui) integration layer for debug panels. </br>
+/// It takes `winit` events as input and is drawn on top of the render target after the composite pass. </br>
+/// 
+pub struct Gui {
+    context: egui::Context, 
+    renderer: egui_wgpu::Renderer, 
+    state: Option<egui_winit::State>, 
+    frame: Option<GuiFrame>, 
+    pending_textures_delta: egui::TexturesDelta, 
+}
+
+#[allow(dead_code)]
+impl Gui {
+    pub fn new(color_format: wgpu::TextureFormat, device: &wgpu::Device) -> Self {
+        Self {
+            context: egui::Context::default(), 
+            renderer: egui_wgpu::Renderer::new(device, color_format, None, 1), 
+            state: None, 
+            frame: None, 
+            pending_textures_delta: egui::TexturesDelta::default(), 
+        }
+    }
+
+    #[inline]
+    pub fn context(&self) -> &egui::Context {
+        &self.context
+    }
+
+    /// #### 한국어 </br>
+    /// 새로운 윈도우에 대한 입력 상태를 생성합니다. </br>
+    /// Android에서는 윈도우가 다시 생성될 때마다 호출되어야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates the input state for a new window. </br>
+    /// On Android, it must be called every time the window is recreated. </br>
+    /// 
+    pub fn resume(&mut self, window: &Window, device: &wgpu::Device) {
+        self.state = Some(egui_winit::State::new(
+            self.context.clone(), 
+            egui::ViewportId::ROOT, 
+            window, 
+            Some(window.scale_factor() as f32), 
+            Some(device.limits().max_texture_dimension_2d as usize)
+        ));
+    }
+
+    /// #### 한국어 </br>
+    /// 윈도우가 제거될 때 입력 상태와 그리기 데이터를 제거합니다. </br>
+    /// 그려지지 않은 텍스처 변경 사항은 유지되어, 다시 시작된 후 처음 그려지는 프레임에 적용됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Removes the input state and the drawing data when the window is destroyed. </br>
+    /// Texture changes that were not drawn are kept, and applied with the first frame drawn after resuming. </br>
+    /// 
+    #[inline]
+    pub fn suspend(&mut self) {
+        self.state = None;
+        if let Some(frame) = self.frame.take() {
+            self.pending_textures_delta.append(frame.textures_delta);
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 윈도우 이벤트를 GUI에 전달합니다. </br>
+    /// GUI가 이벤트를 사용한 경우 `true`를 반환하며, 이때 장면에는 이벤트를 전달하지 않아야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Passes the window event to the GUI. </br>
+    /// Returns `true` if the GUI consumed the event, in which case the event should not be passed to the scene. </br>
+    /// 
+    pub fn on_window_event(&mut self, window: &Window, event: &WindowEvent) -> bool {
+        match self.state.as_mut() {
+            Some(state) => state.on_window_event(window, event).consumed, 
+            None => false, 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 주어진 함수로 GUI의 한 프레임을 구성하고, 그리기 데이터를 생성합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Builds a single frame of the GUI with the given function and generates the drawing data. </br>
+    /// 
+    pub fn run(&mut self, window: &Window, run_ui: impl FnOnce(&egui::Context)) {
+        let Some(state) = self.state.as_mut() else { return };
+
+        let raw_input = state.take_egui_input(window);
+        let full_output = self.context.run(raw_input, run_ui);
+        state.handle_platform_output(window, full_output.platform_output);
+
+        // (한국어) 이전 프레임이나 중단되기 전의 프레임이 그려지지 않았다면 텍스처 변경 사항을 함께 적용합니다.
+        // (English Translation) If the previous frame or the frame before suspending was not drawn, its texture changes are applied together.
+        let mut textures_delta = std::mem::take(&mut self.pending_textures_delta);
+        if let Some(frame) = self.frame.take() {
+            textures_delta.append(frame.textures_delta);
+        }
+        textures_delta.append(full_output.textures_delta);
+
+        let paint_jobs = self.context.tessellate(full_output.shapes, full_output.pixels_per_point);
+        self.frame = Some(GuiFrame {
+            paint_jobs, 
+            textures_delta, 
+            screen_descriptor: egui_wgpu::ScreenDescriptor {
+                size_in_pixels: [window.inner_size().width, window.inner_size().height], 
+                pixels_per_point: full_output.pixels_per_point, 
+            }, 
+        });
+    }
+
+    /// #### 한국어 </br>
+    /// 마지막으로 구성된 프레임을 렌더 타겟 위에 그립니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Draws the most recently built frame on top of the render target. </br>
+    /// 
+    pub fn draw(
+        &mut self, 
+        render_target_view: &wgpu::TextureView, 
+        device: &wgpu::Device, 
+        queue: &wgpu::Queue
+    ) {
+        let Some(frame) = self.frame.take() else { return };
+
+        for (id, image_delta) in frame.textures_delta.set.iter() {
+            self.renderer.update_texture(device, queue, *id, image_delta);
+        }
+
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("CommandEncoder(Gui)"), 
+        });
+        let command_buffers = self.renderer.update_buffers(
+            device, 
+            queue, 
+            &mut encoder, 
+            &frame.paint_jobs, 
+            &frame.screen_descriptor
+        );
+
+        {
+            let mut rpass = encoder.begin_render_pass(
+                &wgpu::RenderPassDescriptor {
+                    label: Some("RenderPass(Gui)"), 
+                    color_attachments: &[
+                        Some(wgpu::RenderPassColorAttachment {
+                            view: render_target_view, 
+                            resolve_target: None, 
+                            ops: wgpu::Operations {
+                                load: wgpu::LoadOp::Load, 
+                                store: wgpu::StoreOp::Store, 
+                            }, 
+                        }), 
+                    ], 
+                    depth_stencil_attachment: None, 
+                    occlusion_query_set: None, 
+                    timestamp_writes: None, 
+                }, 
+            );
+
+            self.renderer.render(&mut rpass, &frame.paint_jobs, &frame.screen_descriptor);
+        }
+
+        // (한국어) 명령어 대기열에 명령어 목록을 제출합니다.
+        // (English Translation) Submit a list of commands to the command queue.
+        queue.submit(command_buffers.into_iter().chain(Some(encoder.finish())));
+
+        for id in frame.textures_delta.free.iter() {
+            self.renderer.free_texture(id);
+        }
+    }
+}
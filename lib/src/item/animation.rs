@@ -0,0 +1,536 @@
+use std::sync::Arc;
+
+use crate::item::skeleton::JointPose;
+use crate::item::skeleton::Skeleton;
+
+
+
+/// #### 한국어 </br>
+/// 키프레임 사이의 값을 구하는 방식입니다. </br>
+/// `CubicSpline`은 각 키프레임마다 (들어오는 접선, 값, 나가는 접선)의 세 값을 가지는 에르미트 스플라인입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The way to compute values between keyframes. </br>
+/// `CubicSpline` is a Hermite spline with three values per keyframe: (in-tangent, value, out-tangent). </br>
+/// 
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Interpolation {
+    Step, 
+    #[default]
+    Linear, 
+    CubicSpline, 
+}
+
+
+
+/// #### 한국어 </br>
+/// 키프레임으로 사용할 수 있는 값입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A value that can be used as a keyframe. </br>
+/// 
+pub trait Keyframe: Copy {
+    fn lerp(a: Self, b: Self, t: f32) -> Self;
+
+    fn hermite(v0: Self, out_tangent0: Self, in_tangent1: Self, v1: Self, dt: f32, t: f32) -> Self;
+}
+
+impl Keyframe for glam::Vec3 {
+    #[inline]
+    fn lerp(a: Self, b: Self, t: f32) -> Self {
+        a.lerp(b, t)
+    }
+
+    #[inline]
+    fn hermite(v0: Self, out_tangent0: Self, in_tangent1: Self, v1: Self, dt: f32, t: f32) -> Self {
+        let [h00, h10, h01, h11] = hermite_basis(t);
+        h00 * v0 + h10 * dt * out_tangent0 + h01 * v1 + h11 * dt * in_tangent1
+    }
+}
+
+impl Keyframe for glam::Vec4 {
+    #[inline]
+    fn lerp(a: Self, b: Self, t: f32) -> Self {
+        a.lerp(b, t)
+    }
+
+    #[inline]
+    fn hermite(v0: Self, out_tangent0: Self, in_tangent1: Self, v1: Self, dt: f32, t: f32) -> Self {
+        let [h00, h10, h01, h11] = hermite_basis(t);
+        h00 * v0 + h10 * dt * out_tangent0 + h01 * v1 + h11 * dt * in_tangent1
+    }
+}
+
+impl Keyframe for f32 {
+    #[inline]
+    fn lerp(a: Self, b: Self, t: f32) -> Self {
+        a + (b - a) * t
+    }
+
+    #[inline]
+    fn hermite(v0: Self, out_tangent0: Self, in_tangent1: Self, v1: Self, dt: f32, t: f32) -> Self {
+        let [h00, h10, h01, h11] = hermite_basis(t);
+        h00 * v0 + h10 * dt * out_tangent0 + h01 * v1 + h11 * dt * in_tangent1
+    }
+}
+
+impl Keyframe for glam::Quat {
+    #[inline]
+    fn lerp(a: Self, b: Self, t: f32) -> Self {
+        a.slerp(b, t)
+    }
+
+    fn hermite(v0: Self, out_tangent0: Self, in_tangent1: Self, v1: Self, dt: f32, t: f32) -> Self {
+        let [h00, h10, h01, h11] = hermite_basis(t);
+        let value = h00 * glam::Vec4::from(v0)
+            + h10 * dt * glam::Vec4::from(out_tangent0)
+            + h01 * glam::Vec4::from(v1)
+            + h11 * dt * glam::Vec4::from(in_tangent1);
+        glam::Quat::from_vec4(value).normalize()
+    }
+}
+
+#[inline]
+fn hermite_basis(t: f32) -> [f32; 4] {
+    let t2 = t * t;
+    let t3 = t2 * t;
+    [
+        2.0 * t3 - 3.0 * t2 + 1.0, 
+        t3 - 2.0 * t2 + t, 
+        -2.0 * t3 + 3.0 * t2, 
+        t3 - t2, 
+    ]
+}
+
+
+
+/// #### 한국어 </br>
+/// 시간 순서로 정렬된 키프레임들입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Keyframes sorted in time order. </br>
+/// 
+#[derive(Debug, Clone, PartialEq)]
+pub struct Keyframes<T> {
+    times: Vec<f32>, 
+    values: Vec<T>, 
+    interpolation: Interpolation, 
+}
+
+#[allow(dead_code)]
+impl<T: Keyframe> Keyframes<T> {
+    /// #### 한국어 </br>
+    /// 키프레임들을 생성합니다. 시간은 감소하지 않아야 하며, </br>
+    /// 값의 수는 시간의 수와 같아야 합니다(`CubicSpline`인 경우 세 배). </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates keyframes. The times must not decrease, </br>
+    /// and the number of values must equal the number of times (three times as many for `CubicSpline`). </br>
+    /// 
+    pub fn new(times: Vec<f32>, values: Vec<T>, interpolation: Interpolation) -> Result<Self, String> {
+        if times.is_empty() {
+            return Err("keyframes must have at least one time".to_string());
+        }
+        if times.windows(2).any(|pair| pair[1] < pair[0]) {
+            return Err("keyframe times must not decrease".to_string());
+        }
+
+        let expected = match interpolation {
+            Interpolation::CubicSpline => times.len() * 3, 
+            _ => times.len(), 
+        };
+        if values.len() != expected {
+            return Err(format!("expected {} keyframe values, found {}", expected, values.len()));
+        }
+        Ok(Self { times, values, interpolation })
+    }
+
+    #[inline]
+    pub fn times(&self) -> &[f32] {
+        &self.times
+    }
+
+    #[inline]
+    pub fn interpolation(&self) -> Interpolation {
+        self.interpolation
+    }
+
+    #[inline]
+    pub fn end_time(&self) -> f32 {
+        self.times.last().copied().unwrap_or_default()
+    }
+
+    /// #### 한국어 </br>
+    /// 주어진 시간의 값을 구합니다. 범위를 벗어난 시간은 처음 또는 마지막 키프레임의 값을 가집니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Computes the value at the given time. Times out of range get the value of the first or last keyframe. </br>
+    /// 
+    pub fn sample(&self, time: f32) -> T {
+        let next = self.times.partition_point(|&it| it <= time);
+        if next == 0 {
+            return self.value(0);
+        }
+        if next == self.times.len() {
+            return self.value(next - 1);
+        }
+
+        let prev = next - 1;
+        let dt = self.times[next] - self.times[prev];
+        let t = match dt > 0.0 {
+            true => (time - self.times[prev]) / dt, 
+            false => 0.0, 
+        };
+        match self.interpolation {
+            Interpolation::Step => self.value(prev), 
+            Interpolation::Linear => T::lerp(self.values[prev], self.values[next], t), 
+            Interpolation::CubicSpline => T::hermite(
+                self.values[prev * 3 + 1], 
+                self.values[prev * 3 + 2], 
+                self.values[next * 3], 
+                self.values[next * 3 + 1], 
+                dt, 
+                t
+            ), 
+        }
+    }
+
+    #[inline]
+    fn value(&self, index: usize) -> T {
+        match self.interpolation {
+            Interpolation::CubicSpline => self.values[index * 3 + 1], 
+            _ => self.values[index], 
+        }
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 관절의 한 속성에 대한 키프레임들입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The keyframes for one property of a joint. </br>
+/// 
+#[derive(Debug, Clone, PartialEq)]
+pub enum ChannelKeyframes {
+    Translation(Keyframes<glam::Vec3>), 
+    Rotation(Keyframes<glam::Quat>), 
+    Scale(Keyframes<glam::Vec3>), 
+}
+
+impl ChannelKeyframes {
+    #[inline]
+    pub fn end_time(&self) -> f32 {
+        match self {
+            Self::Translation(keyframes) => keyframes.end_time(), 
+            Self::Rotation(keyframes) => keyframes.end_time(), 
+            Self::Scale(keyframes) => keyframes.end_time(), 
+        }
+    }
+}
+
+/// #### 한국어 </br>
+/// 골격의 한 관절을 움직이는 애니메이션 채널입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// An animation channel that moves a joint of the skeleton. </br>
+/// 
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnimationChannel {
+    pub joint: usize, 
+    pub keyframes: ChannelKeyframes, 
+}
+
+
+
+/// #### 한국어 </br>
+/// 관절들의 움직임을 담은 애니메이션 클립입니다. 길이는 가장 늦은 키프레임의 시간입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// An animation clip containing the movement of joints. The duration is the time of the latest keyframe. </br>
+/// 
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnimationClip {
+    name: String, 
+    duration: f32, 
+    channels: Vec<AnimationChannel>, 
+}
+
+#[allow(dead_code)]
+impl AnimationClip {
+    pub fn new<T: Into<String>>(name: T, channels: Vec<AnimationChannel>) -> Self {
+        let duration = channels.iter()
+            .map(|channel| channel.keyframes.end_time())
+            .fold(0.0, f32::max);
+        Self { name: name.into(), duration, channels }
+    }
+
+    #[inline]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    #[inline]
+    pub fn duration(&self) -> f32 {
+        self.duration
+    }
+
+    #[inline]
+    pub fn channels(&self) -> &[AnimationChannel] {
+        &self.channels
+    }
+
+    /// #### 한국어 </br>
+    /// 주어진 시간의 관절 자세들을 구하여 `pose`에 씁니다. 채널이 없는 속성은 그대로 유지됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Computes the joint poses at the given time and writes them to `pose`. Properties without a channel are kept. </br>
+    /// 
+    pub fn sample(&self, time: f32, pose: &mut [JointPose]) {
+        for channel in self.channels.iter() {
+            let Some(joint) = pose.get_mut(channel.joint) else {
+                continue;
+            };
+            match &channel.keyframes {
+                ChannelKeyframes::Translation(keyframes) => joint.translation = keyframes.sample(time), 
+                ChannelKeyframes::Rotation(keyframes) => joint.rotation = keyframes.sample(time), 
+                ChannelKeyframes::Scale(keyframes) => joint.scale = keyframes.sample(time), 
+            };
+        }
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 애니메이션 클립을 재생하여 같은 엔티티의 골격에 적용하는 컴포넌트입니다. </br>
+/// 반복하지 않는 클립은 끝에 도달하면 마지막 자세로 멈춥니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A component that plays an animation clip and applies it to the skeleton of the same entity. </br>
+/// A clip that does not loop stops at the last pose when it reaches the end. </br>
+/// 
+#[derive(Debug, Clone)]
+pub struct AnimationPlayer {
+    clip: Arc<AnimationClip>, 
+    time: f32, 
+    speed: f32, 
+    looping: bool, 
+    playing: bool, 
+}
+
+#[allow(dead_code)]
+impl AnimationPlayer {
+    #[inline]
+    pub fn new(clip: Arc<AnimationClip>) -> Self {
+        Self { clip, time: 0.0, speed: 1.0, looping: true, playing: true }
+    }
+
+    #[inline]
+    pub fn with_speed(mut self, speed: f32) -> Self {
+        self.speed = speed;
+        self
+    }
+
+    #[inline]
+    pub fn with_looping(mut self, looping: bool) -> Self {
+        self.looping = looping;
+        self
+    }
+
+    #[inline]
+    pub fn clip(&self) -> &Arc<AnimationClip> {
+        &self.clip
+    }
+
+    /// #### 한국어 </br>
+    /// 재생할 클립을 교체하고 처음부터 재생합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Replaces the clip to play and plays it from the beginning. </br>
+    /// 
+    #[inline]
+    pub fn set_clip(&mut self, clip: Arc<AnimationClip>) {
+        self.clip = clip;
+        self.time = 0.0;
+        self.playing = true;
+    }
+
+    #[inline]
+    pub fn time(&self) -> f32 {
+        self.time
+    }
+
+    #[inline]
+    pub fn set_time(&mut self, time: f32) {
+        self.time = time.clamp(0.0, self.clip.duration());
+    }
+
+    #[inline]
+    pub fn is_playing(&self) -> bool {
+        self.playing
+    }
+
+    #[inline]
+    pub fn play(&mut self) {
+        self.playing = true;
+    }
+
+    #[inline]
+    pub fn pause(&mut self) {
+        self.playing = false;
+    }
+
+    /// #### 한국어 </br>
+    /// 재생 시간을 진행시킵니다. 반복하는 클립은 길이로 감싸고, 그렇지 않으면 끝에서 재생을 멈춥니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Advances the playback time. A looping clip wraps around its duration, otherwise playback stops at the end. </br>
+    /// 
+    pub fn advance(&mut self, delta_time_sec: f32) {
+        if !self.playing {
+            return;
+        }
+
+        let duration = self.clip.duration();
+        self.time += delta_time_sec * self.speed;
+        if duration <= 0.0 {
+            self.time = 0.0;
+        } else if self.looping {
+            self.time = self.time.rem_euclid(duration);
+        } else if !(0.0..=duration).contains(&self.time) {
+            self.time = self.time.clamp(0.0, duration);
+            self.playing = false;
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 골격을 휴식 자세로 되돌린 후 현재 시간의 클립 자세를 적용합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Resets the skeleton to its rest pose, then applies the pose of the clip at the current time. </br>
+    /// 
+    pub fn apply(&self, skeleton: &mut Skeleton) {
+        skeleton.reset_pose();
+        self.clip.sample(self.time, skeleton.pose_mut());
+    }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scalar_keyframes(values: Vec<f32>, interpolation: Interpolation) -> Keyframes<f32> {
+        Keyframes::new(vec![1.0, 2.0, 4.0], values, interpolation).unwrap()
+    }
+
+    #[test]
+    fn keyframes_reject_invalid_input() {
+        assert!(Keyframes::<f32>::new(Vec::new(), Vec::new(), Interpolation::Linear).is_err());
+        assert!(Keyframes::new(vec![1.0, 0.5], vec![0.0, 1.0], Interpolation::Linear).is_err());
+        assert!(Keyframes::new(vec![0.0, 1.0], vec![0.0], Interpolation::Step).is_err());
+        assert!(Keyframes::new(vec![0.0, 1.0], vec![0.0, 1.0], Interpolation::CubicSpline).is_err());
+        assert!(Keyframes::new(vec![0.0, 1.0], vec![0.0; 6], Interpolation::CubicSpline).is_ok());
+    }
+
+    #[test]
+    fn step_holds_the_previous_value() {
+        let keyframes = scalar_keyframes(vec![10.0, 20.0, 40.0], Interpolation::Step);
+        assert_eq!(keyframes.sample(1.0), 10.0);
+        assert_eq!(keyframes.sample(1.99), 10.0);
+        assert_eq!(keyframes.sample(2.0), 20.0);
+        assert_eq!(keyframes.sample(3.5), 20.0);
+        assert_eq!(keyframes.end_time(), 4.0);
+    }
+
+    #[test]
+    fn linear_interpolates_between_keyframes() {
+        let keyframes = scalar_keyframes(vec![10.0, 20.0, 40.0], Interpolation::Linear);
+        assert_eq!(keyframes.sample(1.5), 15.0);
+        assert_eq!(keyframes.sample(2.0), 20.0);
+        assert_eq!(keyframes.sample(3.0), 30.0);
+
+        let rotations = Keyframes::new(
+            vec![0.0, 1.0], 
+            vec![glam::Quat::IDENTITY, glam::Quat::from_rotation_y(std::f32::consts::FRAC_PI_2)], 
+            Interpolation::Linear
+        ).unwrap();
+        let halfway = rotations.sample(0.5);
+        assert!(halfway.abs_diff_eq(glam::Quat::from_rotation_y(std::f32::consts::FRAC_PI_4), 1.0e-5));
+    }
+
+    #[test]
+    fn cubic_spline_passes_through_values_and_follows_tangents() {
+        // (한국어) 각 키프레임은 (들어오는 접선, 값, 나가는 접선) 입니다.
+        // (English Translation) Each keyframe is (in-tangent, value, out-tangent).
+        let keyframes = scalar_keyframes(
+            vec![
+                0.0, 10.0, 0.0, 
+                0.0, 20.0, 0.0, 
+                0.0, 40.0, 0.0, 
+            ], 
+            Interpolation::CubicSpline
+        );
+        assert_eq!(keyframes.sample(1.0), 10.0);
+        assert_eq!(keyframes.sample(2.0), 20.0);
+        assert_eq!(keyframes.sample(4.0), 40.0);
+        // (한국어) 접선이 0이면 구간의 가운데는 두 값의 평균입니다.
+        // (English Translation) With zero tangents, the middle of a segment is the average of the two values.
+        assert!((keyframes.sample(3.0) - 30.0).abs() < 1.0e-5);
+
+        // (한국어) 접선이 직선의 기울기와 같으면 선형 보간과 같은 결과를 가집니다.
+        // (English Translation) With tangents equal to the slope of a line, the result equals linear interpolation.
+        let line = Keyframes::new(vec![0.0, 2.0], vec![3.0, 0.0, 3.0, 3.0, 6.0, 3.0], Interpolation::CubicSpline).unwrap();
+        for time in [0.25, 0.5, 1.0, 1.5] {
+            assert!((line.sample(time) - 3.0 * time).abs() < 1.0e-5, "{}", time);
+        }
+    }
+
+    #[test]
+    fn sampling_clamps_to_the_first_and_last_keyframes() {
+        for interpolation in [Interpolation::Step, Interpolation::Linear] {
+            let keyframes = scalar_keyframes(vec![10.0, 20.0, 40.0], interpolation);
+            assert_eq!(keyframes.sample(-5.0), 10.0);
+            assert_eq!(keyframes.sample(0.0), 10.0);
+            assert_eq!(keyframes.sample(4.0), 40.0);
+            assert_eq!(keyframes.sample(100.0), 40.0);
+        }
+
+        let keyframes = scalar_keyframes(vec![1.0, 10.0, 2.0, 3.0, 20.0, 4.0, 5.0, 40.0, 6.0], Interpolation::CubicSpline);
+        assert_eq!(keyframes.sample(-5.0), 10.0);
+        assert_eq!(keyframes.sample(100.0), 40.0);
+    }
+
+    #[test]
+    fn player_loops_or_stops_at_the_end() {
+        let clip = Arc::new(AnimationClip::new("move", vec![
+            AnimationChannel {
+                joint: 0, 
+                keyframes: ChannelKeyframes::Translation(Keyframes::new(
+                    vec![0.0, 2.0], 
+                    vec![glam::Vec3::ZERO, glam::Vec3::X * 2.0], 
+                    Interpolation::Linear
+                ).unwrap()), 
+            }, 
+        ]));
+        assert_eq!(clip.duration(), 2.0);
+
+        let mut looping = AnimationPlayer::new(clip.clone());
+        looping.advance(2.5);
+        assert!(looping.is_playing());
+        assert!((looping.time() - 0.5).abs() < 1.0e-5);
+
+        let mut once = AnimationPlayer::new(clip.clone()).with_looping(false);
+        once.advance(2.5);
+        assert!(!once.is_playing());
+        assert_eq!(once.time(), 2.0);
+
+        let mut pose = vec![JointPose::IDENTITY];
+        clip.sample(1.0, &mut pose);
+        assert!(pose[0].translation.abs_diff_eq(glam::Vec3::X, 1.0e-5));
+        assert_eq!(pose[0].rotation, glam::Quat::IDENTITY);
+    }
+}
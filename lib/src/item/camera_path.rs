@@ -0,0 +1,541 @@
+use std::sync::Arc;
+use std::path::Path;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::asset::Asset;
+use crate::asset::AssetError;
+use crate::item::animation::Keyframe;
+use crate::item::projection::PerspectiveBuilder;
+use crate::item::projection::Projection;
+use crate::item::transform::Transform;
+
+
+
+/// #### 한국어 </br>
+/// 카메라 경로의 키프레임 사이를 잇는 곡선의 종류입니다. </br>
+/// `Bezier`는 키프레임의 핸들을 제어점으로 사용하며, 핸들이 없는 키프레임은 `CatmullRom`과 같은 접선을 사용합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The kind of curve connecting the keyframes of a camera path. </br>
+/// `Bezier` uses the handles of keyframes as control points, and keyframes without handles use the same tangents as `CatmullRom`. </br>
+/// 
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SplineKind {
+    #[default]
+    CatmullRom, 
+    Bezier, 
+}
+
+
+
+/// #### 한국어 </br>
+/// 카메라 경로의 키프레임입니다. 카메라는 `time`초에 `position`에서 `target`을 바라봅니다. </br>
+/// `in_handle`과 `out_handle`은 `Bezier` 곡선에서 위치가 키프레임으로 들어오고 나가는 제어점입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A keyframe of a camera path. At `time` seconds, the camera is at `position` looking at `target`. </br>
+/// `in_handle` and `out_handle` are the control points of the position entering and leaving the keyframe on a `Bezier` curve. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CameraKeyframe {
+    pub time: f32, 
+    pub position: glam::Vec3, 
+    pub target: glam::Vec3, 
+    #[serde(default = "default_fov_y_degrees")]
+    pub fov_y_degrees: f32, 
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub in_handle: Option<glam::Vec3>, 
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub out_handle: Option<glam::Vec3>, 
+}
+
+#[inline]
+fn default_fov_y_degrees() -> f32 {
+    60.0
+}
+
+
+
+/// #### 한국어 </br>
+/// 카메라 경로 위의 한 시점에서의 카메라 자세입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The camera pose at a point in time on a camera path. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CameraPose {
+    pub position: glam::Vec3, 
+    pub target: glam::Vec3, 
+    pub fov_y_radians: f32, 
+}
+
+impl CameraPose {
+    /// #### 한국어 </br>
+    /// 위치에서 대상을 바라보는 카메라의 변환을 반환합니다. 시선이 위쪽 축과 평행하다면 `-Z` 축을 위쪽으로 사용합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the transform of a camera at the position looking at the target. If the view direction is parallel to the up axis, `-Z` is used as up. </br>
+    /// 
+    pub fn to_transform(&self) -> Transform {
+        let direction = (self.target - self.position).normalize_or_zero();
+        let up = match direction.cross(glam::Vec3::Y).length_squared() > f32::EPSILON {
+            true => glam::Vec3::Y, 
+            false => glam::Vec3::NEG_Z, 
+        };
+
+        let mut transform = Transform::new();
+        if direction != glam::Vec3::ZERO {
+            *transform.world_matrix_mut() = glam::Mat4::look_at_rh(self.position, self.target, up).inverse();
+        } else {
+            transform.set_translation(self.position);
+        }
+        transform
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 키프레임들을 스플라인으로 잇는 카메라 경로입니다. `serde`로 저장하고 불러올 수 있습니다. </br>
+/// 위치와 바라보는 대상은 스플라인으로, 시야각은 선형으로 보간합니다. </br>
+/// 반복하는 경로는 마지막 키프레임이 첫 번째 키프레임과 같아야 끊김 없이 이어집니다. </br>
+/// 아직 `serde`로 읽는 장면 서술 형식이 없으므로, 경로는 독립된 `TOML` 파일(`paths/*.toml`)로 저장되고 에셋으로 불러옵니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A camera path connecting keyframes with a spline. It can be saved and loaded with `serde`. </br>
+/// Positions and look-at targets are interpolated along the spline, and the field of view linearly. </br>
+/// A looping path continues seamlessly only if its last keyframe is the same as its first keyframe. </br>
+/// There is no scene description format read with `serde` yet, so paths are stored as standalone `TOML` files (`paths/*.toml`) and loaded as assets. </br>
+/// 
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CameraPath {
+    #[serde(default)]
+    pub name: String, 
+    #[serde(default)]
+    pub spline: SplineKind, 
+    #[serde(default)]
+    pub looping: bool, 
+    pub keyframes: Vec<CameraKeyframe>, 
+}
+
+#[allow(dead_code)]
+impl CameraPath {
+    /// #### 한국어 </br>
+    /// `TOML` 문서에서 카메라 경로를 읽고 검사합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Reads a camera path from a `TOML` document and validates it. </br>
+    /// 
+    pub fn from_toml(text: &str) -> Result<Self, String> {
+        let path: Self = toml::from_str(text).map_err(|err| err.to_string())?;
+        path.validate()?;
+        Ok(path)
+    }
+
+    /// #### 한국어 </br>
+    /// 카메라 경로를 `TOML` 문서로 씁니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Writes the camera path as a `TOML` document. </br>
+    /// 
+    #[inline]
+    pub fn to_toml(&self) -> Result<String, String> {
+        toml::to_string_pretty(self).map_err(|err| err.to_string())
+    }
+
+    /// #### 한국어 </br>
+    /// 키프레임이 두 개 이상이고, 시간이 증가하는 순서이며, 시야각이 `(0, 180)` 범위인지 검사합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Checks that there are at least two keyframes, the times are in increasing order and the fields of view are in the range `(0, 180)`. </br>
+    /// 
+    pub fn validate(&self) -> Result<(), String> {
+        if self.keyframes.len() < 2 {
+            return Err(format!("expected at least 2 keyframes, found {}", self.keyframes.len()));
+        }
+        for (index, pair) in self.keyframes.windows(2).enumerate() {
+            if pair[1].time <= pair[0].time {
+                return Err(format!("the time of keyframe {} is not greater than the previous one", index + 1));
+            }
+        }
+        for (index, keyframe) in self.keyframes.iter().enumerate() {
+            if !(keyframe.fov_y_degrees > 0.0 && keyframe.fov_y_degrees < 180.0) {
+                return Err(format!("keyframe {} has an invalid field of view {}", index, keyframe.fov_y_degrees));
+            }
+        }
+        Ok(())
+    }
+
+    #[inline]
+    pub fn start_time(&self) -> f32 {
+        self.keyframes.first().map_or(0.0, |keyframe| keyframe.time)
+    }
+
+    #[inline]
+    pub fn end_time(&self) -> f32 {
+        self.keyframes.last().map_or(0.0, |keyframe| keyframe.time)
+    }
+
+    /// #### 한국어 </br>
+    /// 주어진 시간의 카메라 자세를 계산합니다. 시간은 경로의 범위로 제한됩니다. </br>
+    /// 키프레임이 없다면 `None`을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Computes the camera pose at the given time. The time is clamped to the range of the path. </br>
+    /// Returns `None` if there are no keyframes. </br>
+    /// 
+    pub fn sample(&self, time: f32) -> Option<CameraPose> {
+        let first = self.keyframes.first()?;
+        if self.keyframes.len() == 1 {
+            return Some(CameraPose {
+                position: first.position, 
+                target: first.target, 
+                fov_y_radians: first.fov_y_degrees.to_radians(), 
+            });
+        }
+
+        let time = time.clamp(self.start_time(), self.end_time());
+        let index = self.keyframes.partition_point(|keyframe| keyframe.time <= time)
+            .clamp(1, self.keyframes.len() - 1);
+        let (k0, k1) = (&self.keyframes[index - 1], &self.keyframes[index]);
+
+        let dt = k1.time - k0.time;
+        let t = (time - k0.time) / dt;
+        let [position_out, target_out] = self.out_tangents(index - 1);
+        let [position_in, target_in] = self.in_tangents(index);
+        Some(CameraPose {
+            position: Keyframe::hermite(k0.position, position_out, position_in, k1.position, dt, t), 
+            target: Keyframe::hermite(k0.target, target_out, target_in, k1.target, dt, t), 
+            fov_y_radians: Keyframe::lerp(k0.fov_y_degrees, k1.fov_y_degrees, t).to_radians(), 
+        })
+    }
+
+    /// #### 한국어 </br>
+    /// 키프레임에서 나가는 위치와 대상의 접선(초당 변화량)을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the tangents (change per second) of the position and target leaving the keyframe. </br>
+    /// 
+    fn out_tangents(&self, index: usize) -> [glam::Vec3; 2] {
+        let keyframe = &self.keyframes[index];
+        match (self.spline, keyframe.out_handle, self.keyframes.get(index + 1)) {
+            (SplineKind::Bezier, Some(handle), Some(next)) => {
+                let tangent = 3.0 * (handle - keyframe.position) / (next.time - keyframe.time);
+                [tangent, self.catmull_rom_tangents(index)[1]]
+            }, 
+            _ => self.catmull_rom_tangents(index), 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 키프레임으로 들어오는 위치와 대상의 접선(초당 변화량)을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the tangents (change per second) of the position and target entering the keyframe. </br>
+    /// 
+    fn in_tangents(&self, index: usize) -> [glam::Vec3; 2] {
+        let keyframe = &self.keyframes[index];
+        match (self.spline, keyframe.in_handle, index.checked_sub(1).map(|prev| &self.keyframes[prev])) {
+            (SplineKind::Bezier, Some(handle), Some(prev)) => {
+                let tangent = 3.0 * (keyframe.position - handle) / (keyframe.time - prev.time);
+                [tangent, self.catmull_rom_tangents(index)[1]]
+            }, 
+            _ => self.catmull_rom_tangents(index), 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 이웃한 두 키프레임의 차이로 위치와 대상의 접선을 계산합니다. </br>
+    /// 반복하지 않는 경로의 끝 키프레임은 한쪽 이웃만 사용하며, 반복하는 경로는 반대쪽 끝의 이웃을 사용합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Computes the tangents of the position and target from the difference of the two neighboring keyframes. </br>
+    /// The end keyframes of a path that does not loop use only one neighbor, and a looping path uses the neighbor at the opposite end. </br>
+    /// 
+    fn catmull_rom_tangents(&self, index: usize) -> [glam::Vec3; 2] {
+        let last = self.keyframes.len() - 1;
+        let duration = self.end_time() - self.start_time();
+        let neighbor = |index: usize, offset: f32| {
+            let keyframe = &self.keyframes[index];
+            (keyframe.time + offset, keyframe.position, keyframe.target)
+        };
+
+        let prev = match index {
+            0 if self.looping && last >= 2 => neighbor(last - 1, -duration), 
+            0 => neighbor(0, 0.0), 
+            _ => neighbor(index - 1, 0.0), 
+        };
+        let next = match index == last {
+            true if self.looping && last >= 2 => neighbor(1, duration), 
+            true => neighbor(last, 0.0), 
+            false => neighbor(index + 1, 0.0), 
+        };
+
+        let dt = next.0 - prev.0;
+        [(next.1 - prev.1) / dt, (next.2 - prev.2) / dt]
+    }
+}
+
+impl Asset for CameraPath {
+    type Data = CameraPath;
+
+    fn decode(path: &Path, bytes: &[u8]) -> Result<Self::Data, AssetError> {
+        let text = std::str::from_utf8(bytes)
+            .map_err(|err| AssetError::Decode(path.to_path_buf(), err.to_string()))?;
+        Self::from_toml(text)
+            .map_err(|msg| AssetError::Decode(path.to_path_buf(), msg))
+    }
+
+    fn upload(data: Self::Data, _device: &wgpu::Device, _queue: &wgpu::Queue) -> Result<Self, AssetError> {
+        Ok(data)
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 카메라 경로를 따라 같은 엔티티의 변환과 투영을 움직이는 컴포넌트입니다. </br>
+/// 재생을 시작할 때의 카메라를 사용자 카메라로 기억하고, 사용자 카메라와 경로 사이를 부드럽게 섞으며 들어가고 나옵니다. </br>
+/// 재생 중에 사용자가 카메라를 조작하면 `user_transform_mut`로 사용자 카메라를 움직여, 경로에서 나올 때 그 위치로 돌아갑니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A component that moves the transform and projection of the same entity along a camera path. </br>
+/// It remembers the camera at the start of playback as the user camera, and blends smoothly between the user camera and the path when entering and leaving. </br>
+/// If the user controls the camera during playback, moving the user camera with `user_transform_mut` makes the camera return there when leaving the path. </br>
+/// 
+#[derive(Debug, Clone)]
+pub struct CameraSequencer {
+    path: Arc<CameraPath>, 
+    time: f32, 
+    speed: f32, 
+    blend_in_sec: f32, 
+    blend_out_sec: f32, 
+    weight: f32, 
+    playing: bool, 
+    user_transform: Transform, 
+    user_fov_y_radians: f32, 
+}
+
+#[allow(dead_code)]
+impl CameraSequencer {
+    #[inline]
+    pub fn new(path: Arc<CameraPath>) -> Self {
+        Self {
+            time: path.start_time(), 
+            path, 
+            speed: 1.0, 
+            blend_in_sec: 1.0, 
+            blend_out_sec: 1.0, 
+            weight: 0.0, 
+            playing: false, 
+            user_transform: Transform::new(), 
+            user_fov_y_radians: 60.0f32.to_radians(), 
+        }
+    }
+
+    #[inline]
+    pub fn with_speed(mut self, speed: f32) -> Self {
+        self.speed = speed;
+        self
+    }
+
+    #[inline]
+    pub fn with_blend_durations(mut self, blend_in_sec: f32, blend_out_sec: f32) -> Self {
+        self.blend_in_sec = blend_in_sec.max(0.0);
+        self.blend_out_sec = blend_out_sec.max(0.0);
+        self
+    }
+
+    #[inline]
+    pub fn path(&self) -> &Arc<CameraPath> {
+        &self.path
+    }
+
+    #[inline]
+    pub fn time(&self) -> f32 {
+        self.time
+    }
+
+    #[inline]
+    pub fn is_playing(&self) -> bool {
+        self.playing
+    }
+
+    /// #### 한국어 </br>
+    /// 경로가 카메라에 영향을 주고 있는지 여부를 반환합니다. 경로에서 나오는 중에도 `true`입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns whether the path is affecting the camera. It is `true` while leaving the path as well. </br>
+    /// 
+    #[inline]
+    pub fn is_active(&self) -> bool {
+        self.playing || self.weight > 0.0
+    }
+
+    #[inline]
+    pub fn user_transform_mut(&mut self) -> &mut Transform {
+        &mut self.user_transform
+    }
+
+    /// #### 한국어 </br>
+    /// 경로를 처음부터 재생합니다. 경로가 카메라에 영향을 주지 않고 있었다면 현재 카메라를 사용자 카메라로 기억합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Plays the path from the beginning. If the path was not affecting the camera, remembers the current camera as the user camera. </br>
+    /// 
+    pub fn play(&mut self, transform: &Transform, projection: &Projection) {
+        if !self.is_active() {
+            self.user_transform = *transform;
+            if let Projection::Perspective(perspective) = projection {
+                self.user_fov_y_radians = Into::<PerspectiveBuilder>::into(*perspective).fov_y_radians;
+            }
+        }
+        self.time = self.path.start_time();
+        self.playing = true;
+    }
+
+    /// #### 한국어 </br>
+    /// 재생을 멈추고 사용자 카메라로 돌아가기 시작합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Stops playback and starts returning to the user camera. </br>
+    /// 
+    #[inline]
+    pub fn stop(&mut self) {
+        self.playing = false;
+    }
+
+    /// #### 한국어 </br>
+    /// 재생 시간과 섞는 비율을 진행시키고, 섞인 카메라를 변환과 투영에 씁니다. </br>
+    /// 반복하지 않는 경로는 끝에 도달하면 사용자 카메라로 돌아갑니다. 카메라에 값을 썼다면 `true`를 반환합니다. </br>
+    /// 키프레임이 없는 경로는 카메라에 값을 쓰지 않고 바로 재생을 멈춥니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Advances the playback time and the blend weight, and writes the blended camera to the transform and projection. </br>
+    /// A path that does not loop returns to the user camera when it reaches the end. Returns `true` if it wrote to the camera. </br>
+    /// A path without keyframes stops playback immediately without writing to the camera. </br>
+    /// 
+    pub fn update(&mut self, delta_time_sec: f32, transform: &mut Transform, projection: &mut Projection) -> bool {
+        if !self.is_active() {
+            return false;
+        }
+
+        if self.playing {
+            let (start, end) = (self.path.start_time(), self.path.end_time());
+            self.time += delta_time_sec * self.speed;
+            if self.path.looping && end > start {
+                self.time = start + (self.time - start).rem_euclid(end - start);
+            } else if self.time >= end {
+                self.time = end;
+                self.playing = false;
+            }
+        }
+
+        self.weight = match self.playing {
+            true if self.blend_in_sec > 0.0 => (self.weight + delta_time_sec / self.blend_in_sec).min(1.0), 
+            true => 1.0, 
+            false if self.blend_out_sec > 0.0 => (self.weight - delta_time_sec / self.blend_out_sec).max(0.0), 
+            false => 0.0, 
+        };
+
+        // (한국어) 섞는 비율에 스무스스텝을 적용하여 경로에 들어가고 나올 때 카메라가 부드럽게 가감속하도록 합니다.
+        // (English Translation) Applies smoothstep to the blend weight so that the camera eases in and out when entering and leaving the path.
+        let weight = self.weight * self.weight * (3.0 - 2.0 * self.weight);
+        let Some(pose) = self.path.sample(self.time) else {
+            self.playing = false;
+            self.weight = 0.0;
+            return false;
+        };
+        *transform = self.user_transform.lerp(&pose.to_transform(), weight);
+        if let Projection::Perspective(perspective) = projection {
+            *perspective = Into::<PerspectiveBuilder>::into(*perspective)
+                .set_fov_y_radians(Keyframe::lerp(self.user_fov_y_radians, pose.fov_y_radians, weight))
+                .build();
+        }
+        true
+    }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keyframe(time: f32, position: glam::Vec3, fov_y_degrees: f32) -> CameraKeyframe {
+        CameraKeyframe {
+            time, 
+            position, 
+            target: glam::Vec3::ZERO, 
+            fov_y_degrees, 
+            in_handle: None, 
+            out_handle: None, 
+        }
+    }
+
+    fn path(keyframes: Vec<CameraKeyframe>) -> CameraPath {
+        CameraPath {
+            name: "test".to_string(), 
+            spline: SplineKind::CatmullRom, 
+            looping: false, 
+            keyframes, 
+        }
+    }
+
+    #[test]
+    fn sample_without_keyframes_is_none() {
+        let path = path(Vec::new());
+        assert_eq!(path.sample(0.0), None);
+        assert!(path.validate().is_err());
+
+        let mut sequencer = CameraSequencer::new(Arc::new(path));
+        let (mut transform, mut projection) = (Transform::new(), Projection::Perspective(PerspectiveBuilder::default().build()));
+        sequencer.play(&transform, &projection);
+        assert!(!sequencer.update(0.1, &mut transform, &mut projection));
+        assert!(!sequencer.is_active());
+    }
+
+    #[test]
+    fn sample_hits_keyframes_and_clamps_time() {
+        let path = path(vec![
+            keyframe(1.0, glam::Vec3::new(0.0, 0.0, 5.0), 60.0), 
+            keyframe(2.0, glam::Vec3::new(5.0, 0.0, 0.0), 40.0), 
+            keyframe(4.0, glam::Vec3::new(0.0, 0.0, -5.0), 80.0), 
+        ]);
+        assert_eq!(path.validate(), Ok(()));
+
+        for keyframe in path.keyframes.iter() {
+            let pose = path.sample(keyframe.time).unwrap();
+            assert!(pose.position.abs_diff_eq(keyframe.position, 1.0e-5));
+            assert!((pose.fov_y_radians - keyframe.fov_y_degrees.to_radians()).abs() < 1.0e-5);
+        }
+        assert_eq!(path.sample(-1.0), path.sample(1.0));
+        assert_eq!(path.sample(10.0), path.sample(4.0));
+        assert!((path.sample(3.0).unwrap().fov_y_radians - 60.0f32.to_radians()).abs() < 1.0e-5);
+    }
+
+    #[test]
+    fn single_keyframe_is_a_fixed_pose() {
+        let path = path(vec![keyframe(0.0, glam::Vec3::Y, 50.0)]);
+        let pose = path.sample(3.0).unwrap();
+        assert_eq!(pose.position, glam::Vec3::Y);
+        assert_eq!(pose.target, glam::Vec3::ZERO);
+        assert!((pose.fov_y_radians - 50.0f32.to_radians()).abs() < 1.0e-6);
+    }
+
+    #[test]
+    fn toml_round_trip_keeps_the_path() {
+        let mut original = path(vec![
+            keyframe(0.0, glam::Vec3::ZERO, 60.0), 
+            keyframe(1.0, glam::Vec3::X, 45.0), 
+        ]);
+        original.spline = SplineKind::Bezier;
+        original.keyframes[0].out_handle = Some(glam::Vec3::new(0.3, 1.0, 0.0));
+
+        let text = original.to_toml().unwrap();
+        assert_eq!(CameraPath::from_toml(&text), Ok(original));
+        assert!(CameraPath::from_toml("keyframes = []").is_err());
+    }
+}
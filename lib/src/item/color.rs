@@ -19,7 +19,13 @@ pub enum Color {
     }
 }
 
+#[allow(dead_code)]
 impl Color {
+    #[inline]
+    pub fn from_vec4(rgba: glam::Vec4) -> Self {
+        Self::Rgba { red: rgba.x, green: rgba.y, blue: rgba.z, alpha: rgba.w }
+    }
+
     #[inline]
     pub fn as_vec4(&self) -> glam::Vec4 {
         match self {
@@ -1,3 +1,7 @@
+pub mod animation;
+pub mod camera_path;
 pub mod color;
 pub mod projection;
+pub mod skeleton;
 pub mod transform;
+pub mod tween;
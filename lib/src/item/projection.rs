@@ -20,6 +20,7 @@ impl AsRef<glam::Mat4> for Perspective {
     }
 }
 
+#[allow(clippy::from_over_into)]
 impl Into<PerspectiveBuilder> for Perspective {
     #[inline]
     fn into(self) -> PerspectiveBuilder {
@@ -152,6 +153,7 @@ impl AsRef<glam::Mat4> for Orthographic {
     }
 }
 
+#[allow(clippy::from_over_into)]
 impl Into<OrthographicBuilder> for Orthographic {
     #[inline]
     fn into(self) -> OrthographicBuilder {
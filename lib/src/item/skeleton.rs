@@ -0,0 +1,285 @@
+use std::sync::Arc;
+
+
+
+/// #### 한국어 </br>
+/// 부모 관절에 대한 관절의 국소 변환(이동, 회전, 크기)입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The local transform (translation, rotation, scale) of a joint relative to its parent joint. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct JointPose {
+    pub translation: glam::Vec3, 
+    pub rotation: glam::Quat, 
+    pub scale: glam::Vec3, 
+}
+
+#[allow(dead_code)]
+impl JointPose {
+    pub const IDENTITY: Self = Self {
+        translation: glam::Vec3::ZERO, 
+        rotation: glam::Quat::IDENTITY, 
+        scale: glam::Vec3::ONE, 
+    };
+
+    #[inline]
+    pub fn to_mat4(&self) -> glam::Mat4 {
+        glam::Mat4::from_scale_rotation_translation(self.scale, self.rotation, self.translation)
+    }
+}
+
+impl Default for JointPose {
+    #[inline]
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 골격을 이루는 관절입니다. </br>
+/// `inverse_bind_matrix`는 모델 공간의 버텍스를 바인드 자세에서의 관절 공간으로 옮기는 행렬입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A joint that makes up a skeleton. </br>
+/// `inverse_bind_matrix` is the matrix that moves vertices in model space into the joint space of the bind pose. </br>
+/// 
+#[derive(Debug, Clone, PartialEq)]
+pub struct Joint {
+    pub name: String, 
+    pub parent: Option<usize>, 
+    pub inverse_bind_matrix: glam::Mat4, 
+    pub rest_pose: JointPose, 
+}
+
+
+
+/// #### 한국어 </br>
+/// 관절들의 계층 구조와 현재 자세를 가진 골격 컴포넌트입니다. </br>
+/// 관절의 정의는 복제된 골격들이 공유하며, 자세는 엔티티마다 따로 가집니다. </br>
+/// 스키닝 행렬은 관절의 전역 변환에 역 바인드 행렬을 곱한 것으로, 버텍스의 관절 인덱스와 같은 순서를 가집니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A skeleton component with the hierarchy of joints and the current pose. </br>
+/// The joint definitions are shared by cloned skeletons, while each entity has its own pose. </br>
+/// A skinning matrix is the global transform of a joint multiplied by its inverse bind matrix, in the same order as the joint indices of vertices. </br>
+/// 
+#[derive(Debug, Clone)]
+pub struct Skeleton {
+    joints: Arc<[Joint]>, 
+    order: Arc<[usize]>, 
+    root_transform: glam::Mat4, 
+    pose: Vec<JointPose>, 
+}
+
+#[allow(dead_code)]
+impl Skeleton {
+    /// #### 한국어 </br>
+    /// 관절들로 골격을 생성합니다. 자세는 각 관절의 휴식 자세로 초기화됩니다. </br>
+    /// 부모 인덱스가 범위를 벗어나거나 계층 구조에 순환이 있다면 오류를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates a skeleton from the joints. The pose is initialized to the rest pose of each joint. </br>
+    /// Returns an error if a parent index is out of range or the hierarchy has a cycle. </br>
+    /// 
+    pub fn new(joints: Vec<Joint>) -> Result<Self, String> {
+        if joints.is_empty() {
+            return Err("the skeleton has no joints".to_string());
+        }
+
+        // (한국어) 부모가 자식보다 먼저 계산되도록 관절들을 깊이 순서로 정렬합니다.
+        // (English Translation) Sorts the joints by depth so that parents are computed before their children.
+        let mut depths = Vec::with_capacity(joints.len());
+        for (index, joint) in joints.iter().enumerate() {
+            let mut depth = 0;
+            let mut parent = joint.parent;
+            while let Some(current) = parent {
+                if current >= joints.len() {
+                    return Err(format!("joint {} has an invalid parent {}", index, current));
+                }
+                depth += 1;
+                if depth > joints.len() {
+                    return Err(format!("joint {} is part of a cycle", index));
+                }
+                parent = joints[current].parent;
+            }
+            depths.push(depth);
+        }
+        let mut order: Vec<usize> = (0..joints.len()).collect();
+        order.sort_by_key(|&index| depths[index]);
+
+        let pose = joints.iter().map(|joint| joint.rest_pose).collect();
+        Ok(Self {
+            joints: joints.into(), 
+            order: order.into(), 
+            root_transform: glam::Mat4::IDENTITY, 
+            pose, 
+        })
+    }
+
+    /// #### 한국어 </br>
+    /// 부모가 없는 관절들에 적용할 변환을 설정합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the transform applied to the joints without a parent. </br>
+    /// 
+    #[inline]
+    pub fn with_root_transform(mut self, root_transform: glam::Mat4) -> Self {
+        self.root_transform = root_transform;
+        self
+    }
+
+    #[inline]
+    pub fn num_joints(&self) -> usize {
+        self.joints.len()
+    }
+
+    #[inline]
+    pub fn joints(&self) -> &[Joint] {
+        &self.joints
+    }
+
+    #[inline]
+    pub fn find_joint(&self, name: &str) -> Option<usize> {
+        self.joints.iter().position(|joint| joint.name == name)
+    }
+
+    #[inline]
+    pub fn pose(&self) -> &[JointPose] {
+        &self.pose
+    }
+
+    #[inline]
+    pub fn pose_mut(&mut self) -> &mut [JointPose] {
+        &mut self.pose
+    }
+
+    /// #### 한국어 </br>
+    /// 모든 관절의 자세를 휴식 자세로 되돌립니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Resets the pose of all joints to their rest pose. </br>
+    /// 
+    pub fn reset_pose(&mut self) {
+        for (pose, joint) in self.pose.iter_mut().zip(self.joints.iter()) {
+            *pose = joint.rest_pose;
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 현재 자세에서 모든 관절의 모델 공간 변환을 계산합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Computes the model space transforms of all joints in the current pose. </br>
+    /// 
+    pub fn joint_transforms(&self) -> Vec<glam::Mat4> {
+        let mut transforms = vec![glam::Mat4::IDENTITY; self.joints.len()];
+        for &index in self.order.iter() {
+            let parent = match self.joints[index].parent {
+                Some(parent) => transforms[parent], 
+                None => self.root_transform, 
+            };
+            transforms[index] = parent * self.pose[index].to_mat4();
+        }
+        transforms
+    }
+
+    /// #### 한국어 </br>
+    /// 현재 자세에서 모든 관절의 스키닝 행렬을 계산합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Computes the skinning matrices of all joints in the current pose. </br>
+    /// 
+    pub fn joint_matrices(&self) -> Vec<glam::Mat4> {
+        self.joint_transforms()
+            .into_iter()
+            .zip(self.joints.iter())
+            .map(|(transform, joint)| transform * joint.inverse_bind_matrix)
+            .collect()
+    }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn joint(name: &str, parent: Option<usize>, translation: glam::Vec3) -> Joint {
+        Joint {
+            name: name.to_string(), 
+            parent, 
+            inverse_bind_matrix: glam::Mat4::IDENTITY, 
+            rest_pose: JointPose { translation, ..JointPose::IDENTITY }, 
+        }
+    }
+
+    #[test]
+    fn new_rejects_invalid_hierarchies() {
+        assert!(Skeleton::new(Vec::new()).is_err());
+        assert!(Skeleton::new(vec![joint("a", Some(3), glam::Vec3::ZERO)]).is_err());
+        assert!(Skeleton::new(vec![
+            joint("a", Some(1), glam::Vec3::ZERO), 
+            joint("b", Some(0), glam::Vec3::ZERO), 
+        ]).is_err());
+        assert!(Skeleton::new(vec![joint("a", Some(0), glam::Vec3::ZERO)]).is_err());
+    }
+
+    #[test]
+    fn pose_to_matrix_applies_scale_then_rotation_then_translation() {
+        let pose = JointPose {
+            translation: glam::Vec3::new(1.0, 2.0, 3.0), 
+            rotation: glam::Quat::from_rotation_z(std::f32::consts::FRAC_PI_2), 
+            scale: glam::Vec3::splat(2.0), 
+        };
+        let point = pose.to_mat4().transform_point3(glam::Vec3::X);
+        assert!(point.abs_diff_eq(glam::Vec3::new(1.0, 4.0, 3.0), 1.0e-5));
+        assert_eq!(JointPose::default().to_mat4(), glam::Mat4::IDENTITY);
+    }
+
+    #[test]
+    fn joint_transforms_compose_parents_before_children() {
+        // (한국어) 자식이 부모보다 앞에 있어도 부모의 변환이 먼저 계산되어야 합니다.
+        // (English Translation) The parent's transform must be computed first even if the child comes before its parent.
+        let mut skeleton = Skeleton::new(vec![
+            joint("hand", Some(2), glam::Vec3::X), 
+            joint("root", None, glam::Vec3::Y), 
+            joint("arm", Some(1), glam::Vec3::X), 
+        ]).unwrap()
+            .with_root_transform(glam::Mat4::from_translation(glam::Vec3::Z));
+        assert_eq!(skeleton.find_joint("arm"), Some(2));
+
+        let origins: Vec<_> = skeleton.joint_transforms()
+            .iter()
+            .map(|transform| transform.transform_point3(glam::Vec3::ZERO))
+            .collect();
+        assert!(origins[1].abs_diff_eq(glam::Vec3::new(0.0, 1.0, 1.0), 1.0e-5));
+        assert!(origins[2].abs_diff_eq(glam::Vec3::new(1.0, 1.0, 1.0), 1.0e-5));
+        assert!(origins[0].abs_diff_eq(glam::Vec3::new(2.0, 1.0, 1.0), 1.0e-5));
+
+        // (한국어) 부모의 회전은 자식들의 위치를 함께 회전시킵니다.
+        // (English Translation) The rotation of a parent rotates the positions of its children as well.
+        skeleton.pose_mut()[2].rotation = glam::Quat::from_rotation_z(std::f32::consts::FRAC_PI_2);
+        let hand = skeleton.joint_transforms()[0].transform_point3(glam::Vec3::ZERO);
+        assert!(hand.abs_diff_eq(glam::Vec3::new(1.0, 2.0, 1.0), 1.0e-5));
+
+        skeleton.reset_pose();
+        assert_eq!(skeleton.pose()[2], skeleton.joints()[2].rest_pose);
+    }
+
+    #[test]
+    fn joint_matrices_are_identity_in_the_bind_pose() {
+        let mut joints = vec![
+            joint("root", None, glam::Vec3::new(0.0, 1.0, 0.0)), 
+            joint("child", Some(0), glam::Vec3::new(0.5, 0.0, 0.0)), 
+        ];
+        joints[0].inverse_bind_matrix = glam::Mat4::from_translation(glam::Vec3::new(0.0, -1.0, 0.0));
+        joints[1].inverse_bind_matrix = glam::Mat4::from_translation(glam::Vec3::new(-0.5, -1.0, 0.0));
+        let skeleton = Skeleton::new(joints).unwrap();
+        for matrix in skeleton.joint_matrices() {
+            assert!(matrix.abs_diff_eq(glam::Mat4::IDENTITY, 1.0e-5));
+        }
+    }
+}
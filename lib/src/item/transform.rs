@@ -108,6 +108,24 @@ impl Transform {
         self.rotate(glam::Quat::from_rotation_z(angle));
     }
 
+    /// #### 한국어 </br>
+    /// 두 변환 사이를 보간합니다. 크기와 위치는 선형 보간하고, 회전은 구면 선형 보간합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Interpolates between two transforms. Scale and translation are linearly interpolated, and rotation is spherically interpolated. </br>
+    /// 
+    pub fn lerp(&self, rhs: &Self, t: f32) -> Self {
+        let (scale_0, rotation_0, translation_0) = self.inner.to_scale_rotation_translation();
+        let (scale_1, rotation_1, translation_1) = rhs.inner.to_scale_rotation_translation();
+        Self {
+            inner: glam::Mat4::from_scale_rotation_translation(
+                scale_0.lerp(scale_1, t), 
+                rotation_0.slerp(rotation_1, t), 
+                translation_0.lerp(translation_1, t)
+            ), 
+        }
+    }
+
     #[inline]
     pub fn world_matrix_ref(&self) -> &glam::Mat4 {
         &self.inner
@@ -119,6 +137,7 @@ impl Transform {
     }
 
     #[inline]
+    #[allow(clippy::needless_return)]
     pub fn view_matrix(&self) -> glam::Mat4 {
         let translation = self.get_translation();
         let right = self.get_right_vec();
@@ -0,0 +1,706 @@
+use std::f32::consts::PI;
+
+use crate::item::animation::Keyframe;
+use crate::item::color::Color;
+use crate::item::projection::PerspectiveBuilder;
+use crate::item::projection::Projection;
+use crate::item::transform::Transform;
+
+
+
+/// #### 한국어 </br>
+/// 트윈의 진행률을 변형하는 이징 곡선입니다. </br>
+/// `Back`과 `Elastic` 곡선은 목표 값을 잠시 넘어섭니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// An easing curve that reshapes the progress of a tween. </br>
+/// The `Back` and `Elastic` curves briefly overshoot the target value. </br>
+/// 
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Easing {
+    #[default]
+    Linear, 
+    QuadIn, 
+    QuadOut, 
+    QuadInOut, 
+    CubicIn, 
+    CubicOut, 
+    CubicInOut, 
+    SineIn, 
+    SineOut, 
+    SineInOut, 
+    BackIn, 
+    BackOut, 
+    ElasticOut, 
+    BounceOut, 
+}
+
+impl Easing {
+    /// #### 한국어 </br>
+    /// `[0, 1]` 범위의 진행률 `t`에 이징 곡선을 적용합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Applies the easing curve to the progress `t` in the range `[0, 1]`. </br>
+    /// 
+    pub fn apply(self, t: f32) -> f32 {
+        const BACK_C1: f32 = 1.70158;
+        const BACK_C3: f32 = BACK_C1 + 1.0;
+        const ELASTIC_C4: f32 = 2.0 * PI / 3.0;
+
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Self::Linear => t, 
+            Self::QuadIn => t * t, 
+            Self::QuadOut => 1.0 - (1.0 - t).powi(2), 
+            Self::QuadInOut => match t < 0.5 {
+                true => 2.0 * t * t, 
+                false => 1.0 - (-2.0 * t + 2.0).powi(2) * 0.5, 
+            }, 
+            Self::CubicIn => t * t * t, 
+            Self::CubicOut => 1.0 - (1.0 - t).powi(3), 
+            Self::CubicInOut => match t < 0.5 {
+                true => 4.0 * t * t * t, 
+                false => 1.0 - (-2.0 * t + 2.0).powi(3) * 0.5, 
+            }, 
+            Self::SineIn => 1.0 - (t * PI * 0.5).cos(), 
+            Self::SineOut => (t * PI * 0.5).sin(), 
+            Self::SineInOut => -((PI * t).cos() - 1.0) * 0.5, 
+            Self::BackIn => BACK_C3 * t * t * t - BACK_C1 * t * t, 
+            Self::BackOut => 1.0 + BACK_C3 * (t - 1.0).powi(3) + BACK_C1 * (t - 1.0).powi(2), 
+            Self::ElasticOut if t <= 0.0 || t >= 1.0 => t, 
+            Self::ElasticOut => 2.0f32.powf(-10.0 * t) * ((10.0 * t - 0.75) * ELASTIC_C4).sin() + 1.0, 
+            Self::BounceOut => bounce_out(t), 
+        }
+    }
+}
+
+#[inline]
+fn bounce_out(t: f32) -> f32 {
+    const N1: f32 = 7.5625;
+    const D1: f32 = 2.75;
+    if t < 1.0 / D1 {
+        N1 * t * t
+    } else if t < 2.0 / D1 {
+        let t = t - 1.5 / D1;
+        N1 * t * t + 0.75
+    } else if t < 2.5 / D1 {
+        let t = t - 2.25 / D1;
+        N1 * t * t + 0.9375
+    } else {
+        let t = t - 2.625 / D1;
+        N1 * t * t + 0.984375
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 트윈이 바꾸는 속성과 그 시작 값, 끝 값입니다. </br>
+/// 회전은 구면 선형 보간하며, `Fov`는 원근 투영의 세로 시야각(라디안)만 바꿉니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The property a tween changes, with its start and end values. </br>
+/// Rotation is spherically interpolated, and `Fov` only changes the vertical field of view (in radians) of a perspective projection. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TweenTarget {
+    Translation { from: glam::Vec3, to: glam::Vec3 }, 
+    Rotation { from: glam::Quat, to: glam::Quat }, 
+    Scale { from: glam::Vec3, to: glam::Vec3 }, 
+    Color { from: glam::Vec4, to: glam::Vec4 }, 
+    Alpha { from: f32, to: f32 }, 
+    Fov { from: f32, to: f32 }, 
+}
+
+
+
+/// #### 한국어 </br>
+/// 하나의 속성을 주어진 시간 동안 이징 곡선을 따라 바꾸는 트윈입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A tween that changes one property along an easing curve over the given duration. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Tween {
+    pub target: TweenTarget, 
+    pub duration: f32, 
+    pub easing: Easing, 
+}
+
+#[allow(dead_code)]
+impl Tween {
+    #[inline]
+    pub fn new(target: TweenTarget, duration: f32) -> Self {
+        Self { target, duration: duration.max(0.0), easing: Easing::default() }
+    }
+
+    #[inline]
+    pub fn with_easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 트윈이 시작된 후 `time`초가 지났을 때의 값을 대상들에 씁니다. 대상에 없는 속성은 무시됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Writes the value at `time` seconds after the tween started to the targets. Properties missing from the targets are ignored. </br>
+    /// 
+    pub fn apply(&self, time: f32, targets: &mut TweenTargets) {
+        let progress = match self.duration > 0.0 {
+            true => time / self.duration, 
+            false => 1.0, 
+        };
+        let t = self.easing.apply(progress);
+
+        match self.target {
+            TweenTarget::Translation { from, to } => if let Some(transform) = targets.transform.as_deref_mut() {
+                transform.set_translation(Keyframe::lerp(from, to, t));
+            }, 
+            TweenTarget::Rotation { from, to } => if let Some(transform) = targets.transform.as_deref_mut() {
+                let (scale, _, translation) = transform.world_matrix_ref().to_scale_rotation_translation();
+                let rotation: glam::Quat = Keyframe::lerp(from, to, t);
+                *transform.world_matrix_mut() = glam::Mat4::from_scale_rotation_translation(scale, rotation, translation);
+            }, 
+            TweenTarget::Scale { from, to } => if let Some(transform) = targets.transform.as_deref_mut() {
+                let (_, rotation, translation) = transform.world_matrix_ref().to_scale_rotation_translation();
+                let scale: glam::Vec3 = Keyframe::lerp(from, to, t);
+                *transform.world_matrix_mut() = glam::Mat4::from_scale_rotation_translation(scale, rotation, translation);
+            }, 
+            TweenTarget::Color { from, to } => if let Some(color) = targets.color.as_deref_mut() {
+                *color = Color::from_vec4(Keyframe::lerp(from, to, t));
+            }, 
+            TweenTarget::Alpha { from, to } => if let Some(color) = targets.color.as_deref_mut() {
+                let mut rgba = color.as_vec4();
+                rgba.w = Keyframe::lerp(from, to, t);
+                *color = Color::from_vec4(rgba);
+            }, 
+            TweenTarget::Fov { from, to } => if let Some(Projection::Perspective(perspective)) = targets.projection.as_deref_mut() {
+                *perspective = Into::<PerspectiveBuilder>::into(*perspective)
+                    .set_fov_y_radians(Keyframe::lerp(from, to, t))
+                    .build();
+            }, 
+        };
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 트윈이 값을 쓸 엔티티의 컴포넌트들입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The components of an entity that tweens write values to. </br>
+/// 
+#[derive(Debug, Default)]
+pub struct TweenTargets<'a> {
+    pub transform: Option<&'a mut Transform>, 
+    pub color: Option<&'a mut Color>, 
+    pub projection: Option<&'a mut Projection>, 
+}
+
+
+
+/// #### 한국어 </br>
+/// 시퀀스를 끝까지 재생한 후의 동작입니다. </br>
+/// `PingPong`은 방향을 바꾸어 거꾸로 재생합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The behavior after a sequence is played to the end. </br>
+/// `PingPong` reverses the direction and plays backwards. </br>
+/// 
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RepeatMode {
+    #[default]
+    Once, 
+    Loop, 
+    PingPong, 
+}
+
+
+
+/// #### 한국어 </br>
+/// 트윈 시퀀스에서 발생한 이벤트입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// An event that occurred in a tween sequence. </br>
+/// 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TweenEvent {
+    /// #### 한국어 </br>
+    /// 반복되는 시퀀스가 한 번 재생을 마쳤습니다. `count`는 지금까지 마친 재생 횟수입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// A repeating sequence finished one playback. `count` is the number of playbacks finished so far. </br>
+    /// 
+    Repeated { sequence: String, count: u32 }, 
+
+    /// #### 한국어 </br>
+    /// 시퀀스가 모든 재생을 마치고 멈췄습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// A sequence finished all playbacks and stopped. </br>
+    /// 
+    Completed { sequence: String }, 
+}
+
+
+
+/// #### 한국어 </br>
+/// 시퀀스의 한 단계입니다. 단계의 트윈들은 동시에 재생되며, 단계의 길이는 가장 긴 트윈의 길이입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A step of a sequence. The tweens of a step play at the same time, and the length of the step is that of its longest tween. </br>
+/// 
+#[derive(Debug, Clone, PartialEq)]
+struct TweenStep {
+    tweens: Vec<Tween>, 
+    duration: f32, 
+}
+
+
+
+/// #### 한국어 </br>
+/// 단계들을 차례대로 재생하는 트윈 시퀀스입니다. </br>
+/// 시작된 모든 단계를 순서대로 적용하므로, 같은 속성을 바꾸는 단계들은 나중 단계의 값이 남습니다. </br>
+/// 아직 시작되지 않은 단계들은 시작 값으로 적용되므로, 거꾸로 재생해도 같은 결과를 얻습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A tween sequence that plays its steps one after another. </br>
+/// All started steps are applied in order, so for steps changing the same property the value of the later step remains. </br>
+/// Steps that have not started yet are applied with their start values, so playing backwards gives the same result. </br>
+/// 
+#[derive(Debug, Clone, PartialEq)]
+pub struct TweenSequence {
+    name: String, 
+    steps: Vec<TweenStep>, 
+    repeat_mode: RepeatMode, 
+    repeat_count: Option<u32>, 
+    elapsed: f32, 
+    num_finished: u32, 
+    reversed: bool, 
+    paused: bool, 
+    finished: bool, 
+}
+
+#[allow(dead_code)]
+impl TweenSequence {
+    #[inline]
+    pub fn new<T: Into<String>>(name: T) -> Self {
+        Self {
+            name: name.into(), 
+            steps: Vec::new(), 
+            repeat_mode: RepeatMode::default(), 
+            repeat_count: None, 
+            elapsed: 0.0, 
+            num_finished: 0, 
+            reversed: false, 
+            paused: false, 
+            finished: false, 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 이전 단계가 끝난 후 재생되는 새 단계로 트윈을 추가합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Adds the tween as a new step that plays after the previous step ends. </br>
+    /// 
+    #[inline]
+    pub fn then(mut self, tween: Tween) -> Self {
+        self.steps.push(TweenStep { tweens: vec![tween], duration: tween.duration });
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 트윈을 마지막 단계에 추가하여 그 단계의 다른 트윈들과 동시에 재생합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Adds the tween to the last step so that it plays at the same time as the other tweens of that step. </br>
+    /// 
+    pub fn with(mut self, tween: Tween) -> Self {
+        match self.steps.last_mut() {
+            Some(step) => {
+                step.duration = step.duration.max(tween.duration);
+                step.tweens.push(tween);
+            }, 
+            None => return self.then(tween), 
+        };
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 아무것도 바꾸지 않고 주어진 시간 동안 기다리는 단계를 추가합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Adds a step that waits for the given duration without changing anything. </br>
+    /// 
+    #[inline]
+    pub fn then_wait(mut self, duration: f32) -> Self {
+        self.steps.push(TweenStep { tweens: Vec::new(), duration: duration.max(0.0) });
+        self
+    }
+
+    #[inline]
+    pub fn with_repeat_mode(mut self, repeat_mode: RepeatMode) -> Self {
+        self.repeat_mode = repeat_mode;
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 반복되는 시퀀스의 전체 재생 횟수를 제한합니다. `PingPong`은 한 방향의 재생을 한 번으로 셉니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Limits the total number of playbacks of a repeating sequence. `PingPong` counts a playback in one direction as one. </br>
+    /// 
+    #[inline]
+    pub fn with_repeat_count(mut self, repeat_count: u32) -> Self {
+        self.repeat_count = Some(repeat_count.max(1));
+        self
+    }
+
+    #[inline]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    #[inline]
+    pub fn duration(&self) -> f32 {
+        self.steps.iter().map(|step| step.duration).sum()
+    }
+
+    #[inline]
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    #[inline]
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    #[inline]
+    pub fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    #[inline]
+    pub fn resume(&mut self) {
+        self.paused = false;
+    }
+
+    /// #### 한국어 </br>
+    /// 시퀀스를 처음부터 다시 재생합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Plays the sequence again from the beginning. </br>
+    /// 
+    #[inline]
+    pub fn restart(&mut self) {
+        self.elapsed = 0.0;
+        self.num_finished = 0;
+        self.reversed = false;
+        self.paused = false;
+        self.finished = false;
+    }
+
+    /// #### 한국어 </br>
+    /// 재생 시간을 진행시키고, 재생이 끝나거나 반복될 때마다 이벤트를 추가합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Advances the playback time, and adds an event each time playback finishes or repeats. </br>
+    /// 
+    pub fn advance(&mut self, delta_time_sec: f32, events: &mut Vec<TweenEvent>) {
+        if self.paused || self.finished {
+            return;
+        }
+
+        let duration = self.duration();
+        self.elapsed += delta_time_sec.max(0.0);
+        while self.elapsed >= duration {
+            self.num_finished += 1;
+            let is_last = self.repeat_mode == RepeatMode::Once
+                || self.repeat_count.is_some_and(|count| self.num_finished >= count)
+                || duration <= 0.0;
+            if is_last {
+                self.elapsed = duration;
+                self.finished = true;
+                events.push(TweenEvent::Completed { sequence: self.name.clone() });
+                break;
+            }
+
+            self.elapsed -= duration;
+            if self.repeat_mode == RepeatMode::PingPong {
+                self.reversed = !self.reversed;
+            }
+            events.push(TweenEvent::Repeated { sequence: self.name.clone(), count: self.num_finished });
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 현재 재생 위치의 값들을 대상들에 씁니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Writes the values at the current playback position to the targets. </br>
+    /// 
+    pub fn apply(&self, targets: &mut TweenTargets) {
+        let time = match self.reversed {
+            true => self.duration() - self.elapsed, 
+            false => self.elapsed, 
+        };
+
+        let starts: Vec<f32> = self.steps.iter()
+            .scan(0.0, |start, step| {
+                let step_start = *start;
+                *start += step.duration;
+                Some(step_start)
+            })
+            .collect();
+        let num_started = starts.iter().take_while(|&&start| start <= time).count();
+
+        // (한국어) 
+        // 시작되지 않은 단계들을 뒤에서부터 시작 값으로 적용하여, 속성마다 가장 먼저 시작될 단계의 시작 값이 남도록 합니다.
+        // 그 후 시작된 단계들을 순서대로 적용하며, 끝난 단계는 끝 값으로 고정됩니다.
+        // 
+        // (English Translation) 
+        // Applies the steps that have not started with their start values from the back, so that for each property the start value of the step starting first remains.
+        // Then applies the started steps in order, and finished steps are clamped to their end values.
+        // 
+        for step in self.steps[num_started..].iter().rev() {
+            for tween in step.tweens.iter() {
+                tween.apply(0.0, targets);
+            }
+        }
+        for (step, start) in self.steps[..num_started].iter().zip(starts) {
+            for tween in step.tweens.iter() {
+                tween.apply(time - start, targets);
+            }
+        }
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 같은 엔티티의 변환, 색상, 투영을 트윈 시퀀스들로 움직이는 컴포넌트입니다. </br>
+/// 시퀀스들은 동시에 재생되며, 발생한 이벤트는 `drain_events`로 꺼낼 때까지 쌓입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A component that moves the transform, color and projection of the same entity with tween sequences. </br>
+/// The sequences play at the same time, and events that occurred accumulate until they are taken out with `drain_events`. </br>
+/// 
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Tweener {
+    sequences: Vec<TweenSequence>, 
+    events: Vec<TweenEvent>, 
+}
+
+#[allow(dead_code)]
+impl Tweener {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    pub fn with_sequence(mut self, sequence: TweenSequence) -> Self {
+        self.sequences.push(sequence);
+        self
+    }
+
+    #[inline]
+    pub fn push(&mut self, sequence: TweenSequence) {
+        self.sequences.push(sequence);
+    }
+
+    #[inline]
+    pub fn sequences(&self) -> &[TweenSequence] {
+        &self.sequences
+    }
+
+    #[inline]
+    pub fn find_sequence_mut(&mut self, name: &str) -> Option<&mut TweenSequence> {
+        self.sequences.iter_mut().find(|sequence| sequence.name() == name)
+    }
+
+    /// #### 한국어 </br>
+    /// 멈추지 않은 시퀀스가 있는지 여부를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns whether there is a sequence that has not stopped. </br>
+    /// 
+    #[inline]
+    pub fn is_playing(&self) -> bool {
+        self.sequences.iter().any(|sequence| !sequence.is_finished() && !sequence.is_paused())
+    }
+
+    /// #### 한국어 </br>
+    /// 끝난 시퀀스들을 제거합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Removes the finished sequences. </br>
+    /// 
+    #[inline]
+    pub fn remove_finished(&mut self) {
+        self.sequences.retain(|sequence| !sequence.is_finished());
+    }
+
+    #[inline]
+    pub fn advance(&mut self, delta_time_sec: f32) {
+        for sequence in self.sequences.iter_mut() {
+            sequence.advance(delta_time_sec, &mut self.events);
+        }
+    }
+
+    #[inline]
+    pub fn apply(&self, targets: &mut TweenTargets) {
+        for sequence in self.sequences.iter() {
+            sequence.apply(targets);
+        }
+    }
+
+    #[inline]
+    pub fn drain_events(&mut self) -> std::vec::Drain<'_, TweenEvent> {
+        self.events.drain(..)
+    }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::item::transform::TransformBuilder;
+
+    const ALL_EASINGS: [Easing; 14] = [
+        Easing::Linear, Easing::QuadIn, Easing::QuadOut, Easing::QuadInOut, 
+        Easing::CubicIn, Easing::CubicOut, Easing::CubicInOut, 
+        Easing::SineIn, Easing::SineOut, Easing::SineInOut, 
+        Easing::BackIn, Easing::BackOut, Easing::ElasticOut, Easing::BounceOut, 
+    ];
+
+    fn assert_near(actual: f32, expected: f32) {
+        assert!((actual - expected).abs() < 1.0e-5, "expected {}, found {}", expected, actual);
+    }
+
+    fn advance_events(sequence: &mut TweenSequence, delta_time_sec: f32, num_frames: usize) -> Vec<TweenEvent> {
+        let mut events = Vec::new();
+        for _ in 0..num_frames {
+            sequence.advance(delta_time_sec, &mut events);
+        }
+        events
+    }
+
+    fn repeated(count: u32) -> TweenEvent {
+        TweenEvent::Repeated { sequence: "test".to_string(), count }
+    }
+
+    fn completed() -> TweenEvent {
+        TweenEvent::Completed { sequence: "test".to_string() }
+    }
+
+    /// #### 한국어 </br>
+    /// 이동한 후 크기를 바꾸는 두 단계의 시퀀스를 만듭니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates a sequence of two steps that moves and then scales. </br>
+    /// 
+    fn move_then_scale() -> TweenSequence {
+        TweenSequence::new("test")
+            .then(Tween::new(TweenTarget::Translation { from: glam::Vec3::ZERO, to: glam::Vec3::X }, 1.0))
+            .then(Tween::new(TweenTarget::Scale { from: glam::Vec3::ONE, to: glam::Vec3::splat(2.0) }, 1.0))
+    }
+
+    fn apply_to_transform(sequence: &TweenSequence) -> Transform {
+        let mut transform = TransformBuilder::new()
+            .set_translation(glam::Vec3::new(5.0, 5.0, 5.0))
+            .set_scale(glam::Vec3::splat(3.0))
+            .build();
+        sequence.apply(&mut TweenTargets { transform: Some(&mut transform), ..Default::default() });
+        transform
+    }
+
+    #[test]
+    fn easings_start_at_zero_and_end_at_one() {
+        for easing in ALL_EASINGS {
+            assert_near(easing.apply(0.0), 0.0);
+            assert_near(easing.apply(1.0), 1.0);
+            assert_near(easing.apply(-1.0), 0.0);
+            assert_near(easing.apply(2.0), 1.0);
+        }
+        assert_near(Easing::Linear.apply(0.25), 0.25);
+        assert_near(Easing::QuadInOut.apply(0.5), 0.5);
+        assert!(Easing::BackIn.apply(0.2) < 0.0);
+        assert!(Easing::BackOut.apply(0.8) > 1.0);
+    }
+
+    #[test]
+    fn once_completes_a_single_time() {
+        let mut sequence = move_then_scale();
+        let events = advance_events(&mut sequence, 0.25, 12);
+        assert_eq!(events, [completed()]);
+        assert!(sequence.is_finished());
+    }
+
+    #[test]
+    fn loop_reports_each_repeat_until_the_count() {
+        let mut sequence = move_then_scale()
+            .with_repeat_mode(RepeatMode::Loop)
+            .with_repeat_count(3);
+        let events = advance_events(&mut sequence, 0.5, 20);
+        assert_eq!(events, [repeated(1), repeated(2), completed()]);
+
+        let mut sequence = move_then_scale().with_repeat_mode(RepeatMode::Loop);
+        let events = advance_events(&mut sequence, 5.0, 1);
+        assert_eq!(events, [repeated(1), repeated(2)]);
+        assert!(!sequence.is_finished());
+    }
+
+    #[test]
+    fn ping_pong_counts_each_direction() {
+        let mut sequence = move_then_scale()
+            .with_repeat_mode(RepeatMode::PingPong)
+            .with_repeat_count(4);
+        let events = advance_events(&mut sequence, 0.5, 20);
+        assert_eq!(events, [repeated(1), repeated(2), repeated(3), completed()]);
+
+        sequence.restart();
+        assert!(advance_events(&mut sequence, 0.5, 3).is_empty());
+        assert!(!sequence.is_finished());
+    }
+
+    #[test]
+    fn reverse_playback_resets_steps_that_have_not_started() {
+        let mut sequence = move_then_scale().with_repeat_mode(RepeatMode::PingPong);
+        let mut events = Vec::new();
+
+        sequence.advance(0.5, &mut events);
+        let transform = apply_to_transform(&sequence);
+        assert!(transform.get_translation().abs_diff_eq(glam::Vec3::new(0.5, 0.0, 0.0), 1.0e-5));
+        assert!(transform.world_matrix_ref().to_scale_rotation_translation().0.abs_diff_eq(glam::Vec3::ONE, 1.0e-5));
+
+        sequence.advance(3.0, &mut events);
+        assert_eq!(events, [repeated(1)]);
+        let transform = apply_to_transform(&sequence);
+        assert!(transform.get_translation().abs_diff_eq(glam::Vec3::new(0.5, 0.0, 0.0), 1.0e-5));
+        assert!(transform.world_matrix_ref().to_scale_rotation_translation().0.abs_diff_eq(glam::Vec3::ONE, 1.0e-5));
+    }
+
+    #[test]
+    fn finished_steps_are_clamped_to_their_end_values() {
+        let mut sequence = move_then_scale();
+        let mut events = Vec::new();
+
+        sequence.advance(1.5, &mut events);
+        let transform = apply_to_transform(&sequence);
+        assert!(transform.get_translation().abs_diff_eq(glam::Vec3::X, 1.0e-5));
+        assert!(transform.world_matrix_ref().to_scale_rotation_translation().0.abs_diff_eq(glam::Vec3::splat(1.5), 1.0e-5));
+
+        let mut color = Color::from_vec4(glam::Vec4::ONE);
+        let sequence = TweenSequence::new("test")
+            .then(Tween::new(TweenTarget::Alpha { from: 1.0, to: 0.0 }, 1.0))
+            .then(Tween::new(TweenTarget::Alpha { from: 0.0, to: 0.5 }, 1.0));
+        sequence.apply(&mut TweenTargets { color: Some(&mut color), ..Default::default() });
+        assert_near(color.as_vec4().w, 1.0);
+    }
+}
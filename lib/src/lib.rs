@@ -1,10 +1,34 @@
-mod item;
-mod render;
+pub mod item;
+pub mod render;
 
-mod app;
-mod object;
-mod scene;
-mod timer;
+pub mod app;
+pub mod asset;
+pub mod config;
+pub mod error;
+#[cfg(feature = "egui")]
+mod gui;
+pub mod object;
+mod pacer;
+pub mod profiler;
+pub mod scene;
+pub mod timer;
+pub mod vfs;
+
+// (한국어) 엔진을 사용하는 게임이 같은 버전의 의존성을 사용할 수 있도록 다시 내보냅니다.
+// (English Translation) Re-exports the dependencies so that games using the engine can use the same versions.
+pub use glam;
+pub use hecs;
+pub use wgpu;
+pub use winit;
+#[cfg(feature = "egui")]
+pub use egui;
+
+pub use app::AppRunner;
+pub use config::RendererConfig;
+pub use error::AppError;
+pub use scene::GameScene;
+pub use timer::GameTimer;
+pub use vfs::Vfs;
 
 #[cfg(target_os = "android")]
 use winit::platform::android::activity::AndroidApp;
@@ -15,33 +39,45 @@ use winit::platform::android::activity::AndroidApp;
 #[allow(dead_code)]
 #[cfg(target_os = "android")]
 pub fn android_main(app: AndroidApp) {
-    use winit::event_loop::EventLoop;
-    use winit::event_loop::EventLoopBuilder;
-    use winit::platform::android::EventLoopBuilderExtAndroid;
     use android_logger::Config;
+    use scene::SampleScene;
 
     android_logger::init_once(Config::default());
 
-    let event_loop: EventLoop<()> = EventLoopBuilder::new()
-        .with_android_app(app)
-        .build()
-        .expect("Can't create event loop!");
+    let result = AppRunner::new(SampleScene::new)
+        .with_config(RendererConfig::default())
+        .run(app);
 
-    app::run_android(event_loop);
+    if let Err(err) = result {
+        log::error!("{}", err);
+    }
 }
 
 #[allow(dead_code)]
 #[cfg(not(target_os = "android"))]
 pub fn main() {
-    use winit::window::Window;
-    use winit::window::WindowBuilder;
-    use winit::event_loop::EventLoop;
+    use scene::SampleScene;
+
+    env_logger::Builder::from_env(
+        env_logger::Env::default().default_filter_or("info")
+    ).init();
 
-    let event_loop: EventLoop<()> = EventLoop::new()
-        .expect("Can't create event loop!");
-    let window: Window = WindowBuilder::new()
-        .build(&event_loop)
-        .expect("Can't create window");
+    // (한국어) 명령줄 인자에서 렌더러 설정을 읽어옵니다. 설정 파일은 애플리케이션의 가상 파일 시스템에서 읽습니다.
+    // (English Translation) Reads the renderer configuration from the command-line arguments. The configuration file is read from the application's virtual file system.
+    let vfs = Vfs::desktop();
+    let result = RendererConfig::from_args(&vfs, std::env::args().skip(1))
+        .and_then(|mut config| {
+            // (한국어) 샘플은 이 크레이트의 셰이더 디렉토리를 감시하여 셰이더를 다시 불러옵니다.
+            // (English Translation) The sample watches the shader directory of this crate to reload shaders.
+            config.shader_dir.get_or_insert_with(|| concat!(env!("CARGO_MANIFEST_DIR"), "/shaders").into());
+            AppRunner::new(SampleScene::new)
+                .with_config(config)
+                .with_vfs(vfs)
+                .run()
+        });
 
-    app::run_dev(window, event_loop);
+    if let Err(err) = result {
+        log::error!("{}", err);
+        std::process::exit(1);
+    }
 }
@@ -0,0 +1,115 @@
+use std::time::Duration;
+use std::time::Instant;
+use winit::event_loop::ControlFlow;
+
+use crate::config::LoopMode;
+use crate::config::RendererConfig;
+
+
+
+/// #### 한국어 </br>
+/// 이벤트 루프의 방식에 따라 이번 루프에서 프레임을 갱신하고 그릴지 결정합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Decides whether to update and draw a frame in this loop according to the loop mode. </br>
+/// 
+#[derive(Debug, Clone, Copy)]
+pub struct FramePacer {
+    mode: LoopMode, 
+    frame_interval: Duration, 
+    next_frame: Instant, 
+    redraw_requested: bool, 
+    idle: bool, 
+    resumed_from_idle: bool, 
+}
+
+#[allow(dead_code)]
+impl FramePacer {
+    pub fn new(config: &RendererConfig) -> Self {
+        Self {
+            mode: config.loop_mode, 
+            frame_interval: Duration::from_secs_f64(1.0 / config.target_fps.max(1) as f64), 
+            next_frame: Instant::now(), 
+            redraw_requested: true, 
+            idle: false, 
+            resumed_from_idle: false, 
+        }
+    }
+
+    #[inline]
+    pub fn mode(&self) -> LoopMode {
+        self.mode
+    }
+
+    /// #### 한국어 </br>
+    /// 다음 루프에서 프레임을 그리도록 요청합니다. `OnDemand` 방식에서만 의미가 있습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Requests a frame to be drawn in the next loop. Only meaningful in the `OnDemand` mode. </br>
+    /// 
+    #[inline]
+    pub fn request_redraw(&mut self) {
+        self.redraw_requested = true;
+    }
+
+    /// #### 한국어 </br>
+    /// 이번 루프에서 프레임을 갱신하고 그려야 한다면 `true`를 반환합니다. </br>
+    /// `scene_dirty`는 장면이 마지막 프레임 이후 변경되었는지 여부입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns `true` if a frame should be updated and drawn in this loop. </br>
+    /// `scene_dirty` is whether the scene has changed since the last frame. </br>
+    /// 
+    pub fn should_run_frame(&mut self, scene_dirty: bool) -> bool {
+        match self.mode {
+            LoopMode::Continuous => true, 
+            LoopMode::Capped => {
+                let now = Instant::now();
+                if now < self.next_frame {
+                    return false;
+                }
+
+                // (한국어) 프레임이 밀린 경우 따라잡으려 하지 않고 현재 시각부터 다시 계산합니다.
+                // (English Translation) If frames are behind, does not try to catch up and recalculates from the current time.
+                self.next_frame += self.frame_interval;
+                if self.next_frame < now {
+                    self.next_frame = now + self.frame_interval;
+                }
+                true
+            }, 
+            LoopMode::OnDemand => {
+                let run = std::mem::take(&mut self.redraw_requested) || scene_dirty;
+                self.resumed_from_idle = run && self.idle;
+                self.idle = !run;
+                run
+            }, 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 이번 프레임이 프레임을 그리지 않고 쉬었던 이후의 첫 프레임이라면 `true`를 반환합니다. </br>
+    /// 쉬었던 시간이 시뮬레이션에 반영되지 않도록 타이머를 초기화할 때 사용합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns `true` if this frame is the first frame after idling without drawing frames. </br>
+    /// Used to reset the timer so that the idle time is not reflected in the simulation. </br>
+    /// 
+    #[inline]
+    pub fn resumed_from_idle(&self) -> bool {
+        self.resumed_from_idle
+    }
+
+    /// #### 한국어 </br>
+    /// 다음 루프까지 이벤트 루프가 기다리는 방식을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns how the event loop waits until the next loop. </br>
+    /// 
+    #[inline]
+    pub fn control_flow(&self) -> ControlFlow {
+        match self.mode {
+            LoopMode::Capped => ControlFlow::WaitUntil(self.next_frame), 
+            LoopMode::Continuous | LoopMode::OnDemand => ControlFlow::Wait, 
+        }
+    }
+}
@@ -0,0 +1,321 @@
+use std::io;
+use std::io::Write;
+use std::fs::File;
+use std::path::Path;
+use std::sync::Mutex;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::AtomicU32;
+use std::sync::atomic::Ordering;
+use std::time::Instant;
+use std::collections::VecDeque;
+
+
+
+/// #### 한국어 </br>
+/// 링 버퍼에 보관하는 최근 프레임의 수입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The number of recent frames kept in the ring buffer. </br>
+/// 
+pub const MAX_FRAMES: usize = 300;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static NEXT_THREAD_ID: AtomicU32 = AtomicU32::new(0);
+static PROFILER: Mutex<CpuProfiler> = Mutex::new(CpuProfiler::new());
+
+thread_local! {
+    static THREAD_ID: u32 = NEXT_THREAD_ID.fetch_add(1, Ordering::Relaxed);
+}
+
+
+
+/// #### 한국어 </br>
+/// 한 구간의 시작 시각과 걸린 시간(마이크로초)입니다. </br>
+/// 시작 시각은 프로파일러가 처음 프레임을 시작한 시점을 기준으로 합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The start time and duration (in microseconds) of a scope. </br>
+/// The start time is relative to when the profiler started its first frame. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScopeEvent {
+    pub name: &'static str, 
+    pub thread_id: u32, 
+    pub start_us: f64, 
+    pub duration_us: f64, 
+}
+
+/// #### 한국어 </br>
+/// 한 프레임 동안 기록된 구간들입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The scopes recorded during a frame. </br>
+/// 
+#[derive(Debug, Clone, PartialEq)]
+pub struct CpuFrame {
+    pub index: u64, 
+    pub thread_id: u32, 
+    pub start_us: f64, 
+    pub duration_us: f64, 
+    pub events: Vec<ScopeEvent>, 
+}
+
+
+
+/// #### 한국어 </br>
+/// 구간 표시자들을 프레임 단위로 모으는 CPU 프로파일러의 상태입니다. </br>
+/// 전역으로 하나만 존재하며, 이 모듈의 함수들을 통해 사용합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The state of the CPU profiler that gathers scope markers per frame. </br>
+/// Only one exists globally, and it is used through the functions of this module. </br>
+/// 
+#[derive(Debug)]
+struct CpuProfiler {
+    epoch: Option<Instant>, 
+    next_frame_index: u64, 
+    current: Option<CpuFrame>, 
+    frames: VecDeque<CpuFrame>, 
+    thread_names: Vec<(u32, String)>, 
+}
+
+impl CpuProfiler {
+    const fn new() -> Self {
+        Self {
+            epoch: None, 
+            next_frame_index: 0, 
+            current: None, 
+            frames: VecDeque::new(), 
+            thread_names: Vec::new(), 
+        }
+    }
+
+    fn elapsed_us(&mut self, timepoint: Instant) -> f64 {
+        let epoch = *self.epoch.get_or_insert(timepoint);
+        timepoint.saturating_duration_since(epoch).as_secs_f64() * 1_000_000.0
+    }
+
+    fn register_thread(&mut self, thread_id: u32) {
+        if self.thread_names.iter().any(|(id, _)| *id == thread_id) {
+            return;
+        }
+
+        let thread = std::thread::current();
+        let name = thread.name()
+            .map(|name| name.to_string())
+            .unwrap_or_else(|| format!("Thread {}", thread_id));
+        self.thread_names.push((thread_id, name));
+    }
+
+    fn finish_frame(&mut self, end_us: f64) {
+        if let Some(mut frame) = self.current.take() {
+            frame.duration_us = end_us - frame.start_us;
+            if self.frames.len() == MAX_FRAMES {
+                self.frames.pop_front();
+            }
+            self.frames.push_back(frame);
+        }
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 생성된 시점부터 제거되는 시점까지를 하나의 구간으로 기록하는 표시자입니다. </br>
+/// `scope` 함수로 생성합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A marker that records the time from its creation to its drop as a single scope. </br>
+/// Created with the `scope` function. </br>
+/// 
+#[must_use = "The scope ends when the marker is dropped."]
+#[derive(Debug)]
+pub struct ProfileScope {
+    name: &'static str, 
+    start: Option<Instant>, 
+}
+
+impl Drop for ProfileScope {
+    fn drop(&mut self) {
+        let Some(start) = self.start else {
+            return;
+        };
+
+        let end = Instant::now();
+        let thread_id = THREAD_ID.with(|id| *id);
+        if let Ok(mut profiler) = PROFILER.lock() {
+            if profiler.current.is_none() {
+                return;
+            }
+
+            profiler.register_thread(thread_id);
+            let start_us = profiler.elapsed_us(start);
+            let end_us = profiler.elapsed_us(end);
+            if let Some(frame) = profiler.current.as_mut() {
+                frame.events.push(ScopeEvent {
+                    name: self.name, 
+                    thread_id, 
+                    start_us, 
+                    duration_us: end_us - start_us, 
+                });
+            }
+        }
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 프로파일러를 켜거나 끕니다. 꺼져 있는 동안 구간 표시자는 아무것도 기록하지 않습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Turns the profiler on or off. While it is off, scope markers record nothing. </br>
+/// 
+pub fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// #### 한국어 </br>
+/// 프로파일러가 켜져 있는지 여부를 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns whether the profiler is turned on. </br>
+/// 
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// #### 한국어 </br>
+/// 현재 프레임을 끝내고 링 버퍼에 저장한 후, 새로운 프레임을 시작합니다. </br>
+/// 매 프레임의 시작에 한 번 호출해야 합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Finishes the current frame, stores it in the ring buffer, and starts a new frame. </br>
+/// It must be called once at the start of every frame. </br>
+/// 
+pub fn begin_frame() {
+    if !is_enabled() {
+        return;
+    }
+
+    let now = Instant::now();
+    let thread_id = THREAD_ID.with(|id| *id);
+    if let Ok(mut profiler) = PROFILER.lock() {
+        let start_us = profiler.elapsed_us(now);
+        profiler.finish_frame(start_us);
+        profiler.register_thread(thread_id);
+
+        let index = profiler.next_frame_index;
+        profiler.next_frame_index += 1;
+        profiler.current = Some(CpuFrame {
+            index, 
+            thread_id, 
+            start_us, 
+            duration_us: 0.0, 
+            events: Vec::new(), 
+        });
+    }
+}
+
+/// #### 한국어 </br>
+/// `name` 이름의 구간 표시자를 생성합니다. 반환된 표시자가 제거될 때 구간이 기록됩니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates a scope marker named `name`. The scope is recorded when the returned marker is dropped. </br>
+/// 
+pub fn scope(name: &'static str) -> ProfileScope {
+    ProfileScope {
+        name, 
+        start: is_enabled().then(Instant::now), 
+    }
+}
+
+/// #### 한국어 </br>
+/// 마지막으로 완료된 프레임을 반환합니다. 프로파일러가 꺼져 있다면 `None`을 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns the last completed frame. Returns `None` if the profiler is turned off. </br>
+/// 
+pub fn last_frame() -> Option<CpuFrame> {
+    if !is_enabled() {
+        return None;
+    }
+
+    PROFILER.lock().ok()
+        .and_then(|profiler| profiler.frames.back().cloned())
+}
+
+/// #### 한국어 </br>
+/// 링 버퍼의 프레임들을 `Chrome trace` `JSON` 형식으로 씁니다. </br>
+/// 결과는 `chrome://tracing` 또는 `Perfetto`에서 열 수 있습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Writes the frames of the ring buffer in `Chrome trace` `JSON` format. </br>
+/// The result can be opened in `chrome://tracing` or `Perfetto`. </br>
+/// 
+pub fn write_chrome_trace<W: Write>(mut writer: W) -> io::Result<()> {
+    let (thread_names, frames) = match PROFILER.lock() {
+        Ok(profiler) => (profiler.thread_names.clone(), profiler.frames.clone()), 
+        Err(_) => (Vec::new(), VecDeque::new()), 
+    };
+
+    let mut separator = "";
+    write!(writer, "{{\"displayTimeUnit\":\"ms\",\"traceEvents\":[")?;
+    for (thread_id, name) in thread_names.iter() {
+        write!(
+            writer, 
+            "{}\n{{\"name\":\"thread_name\",\"ph\":\"M\",\"pid\":0,\"tid\":{},\"args\":{{\"name\":\"{}\"}}}}", 
+            separator, thread_id, escape_json(name)
+        )?;
+        separator = ",";
+    }
+
+    for frame in frames.iter() {
+        write!(
+            writer, 
+            "{}\n{{\"name\":\"Frame {}\",\"cat\":\"frame\",\"ph\":\"X\",\"ts\":{:.3},\"dur\":{:.3},\"pid\":0,\"tid\":{}}}", 
+            separator, frame.index, frame.start_us, frame.duration_us, frame.thread_id
+        )?;
+        separator = ",";
+
+        for event in frame.events.iter() {
+            write!(
+                writer, 
+                ",\n{{\"name\":\"{}\",\"cat\":\"cpu\",\"ph\":\"X\",\"ts\":{:.3},\"dur\":{:.3},\"pid\":0,\"tid\":{}}}", 
+                escape_json(event.name), event.start_us, event.duration_us, event.thread_id
+            )?;
+        }
+    }
+    writeln!(writer, "\n]}}")?;
+    Ok(())
+}
+
+/// #### 한국어 </br>
+/// 링 버퍼의 프레임들을 `Chrome trace` `JSON` 파일로 내보냅니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Exports the frames of the ring buffer to a `Chrome trace` `JSON` file. </br>
+/// 
+pub fn export_chrome_trace<P: AsRef<Path>>(path: P) -> io::Result<()> {
+    let file = File::create(path)?;
+    write_chrome_trace(io::BufWriter::new(file))
+}
+
+/// #### 한국어 </br>
+/// `JSON` 문자열에 넣을 수 있도록 특수 문자를 이스케이프합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Escapes special characters so that the text can be placed in a `JSON` string. </br>
+/// 
+fn escape_json(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match ch {
+            '"' => escaped.push_str("\\\""), 
+            '\\' => escaped.push_str("\\\\"), 
+            ch if ch.is_control() => escaped.push_str(&format!("\\u{:04x}", ch as u32)), 
+            ch => escaped.push(ch), 
+        };
+    }
+    escaped
+}
@@ -0,0 +1,263 @@
+use std::mem;
+use std::f32::consts::TAU;
+use bytemuck::Pod;
+use bytemuck::Zeroable;
+
+use crate::item::color::Color;
+
+pub const DEBUG_DRAW_VERTEX_BUFFER_LAYOUT: wgpu::VertexBufferLayout = wgpu::VertexBufferLayout {
+    array_stride: mem::size_of::<DebugDrawVertex>() as wgpu::BufferAddress, 
+    step_mode: wgpu::VertexStepMode::Vertex, 
+    attributes: &[
+        wgpu::VertexAttribute {
+            format: wgpu::VertexFormat::Float32x3, 
+            offset: 0x00 as wgpu::BufferAddress, 
+            shader_location: 0, 
+        }, 
+        wgpu::VertexAttribute {
+            format: wgpu::VertexFormat::Float32x4, 
+            offset: 0x0C as wgpu::BufferAddress, 
+            shader_location: 1, 
+        }, 
+    ], 
+};
+
+/// #### 한국어 </br>
+/// 원을 근사하는 선분의 개수입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The number of line segments approximating a circle. </br>
+/// 
+const CIRCLE_SEGMENTS: usize = 32;
+
+
+
+/// #### 한국어 </br>
+/// 디버그 선의 버텍스 입력 레이아웃 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The vertex input layout for a debug line. </br>
+/// 
+#[repr(C)]
+#[derive(Pod, Zeroable)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DebugDrawVertex {
+    pub position: glam::Vec3, 
+    pub color: [f32; 4], 
+}
+
+
+
+/// #### 한국어 </br>
+/// 한 프레임 동안 월드 공간에 그려질 디버그 도형들을 모으는 수집기입니다. </br>
+/// 장면은 `on_update`에서 도형들을 추가하고 `upload`를 호출하며, `on_draw`에서 `draw`를 호출합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A collector that gathers debug shapes to be drawn in world space for a single frame. </br>
+/// The scene adds shapes and calls `upload` in `on_update`, and calls `draw` in `on_draw`. </br>
+/// 
+#[derive(Debug, Default)]
+pub struct DebugDraw {
+    vertices: Vec<DebugDrawVertex>, 
+    buffer: Option<wgpu::Buffer>, 
+    num_vertices: u32, 
+}
+
+#[allow(dead_code)]
+impl DebugDraw {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// #### 한국어 </br>
+    /// 지금까지 추가된 도형들을 모두 지웁니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Clears all shapes added so far. </br>
+    /// 
+    #[inline]
+    pub fn clear(&mut self) {
+        self.vertices.clear();
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.vertices.is_empty()
+    }
+
+    /// #### 한국어 </br>
+    /// 두 점을 잇는 선분을 추가합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Adds a line segment connecting two points. </br>
+    /// 
+    #[inline]
+    pub fn line(&mut self, start: glam::Vec3, end: glam::Vec3, color: Color) {
+        let color = color.as_vec4().to_array();
+        self.vertices.push(DebugDrawVertex { position: start, color });
+        self.vertices.push(DebugDrawVertex { position: end, color });
+    }
+
+    /// #### 한국어 </br>
+    /// 최소점과 최대점으로 정의된 축 정렬 경계 상자를 추가합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Adds an axis-aligned bounding box defined by its minimum and maximum points. </br>
+    /// 
+    pub fn aabb(&mut self, min: glam::Vec3, max: glam::Vec3, color: Color) {
+        let corners = [
+            glam::vec3(min.x, min.y, min.z), 
+            glam::vec3(max.x, min.y, min.z), 
+            glam::vec3(max.x, max.y, min.z), 
+            glam::vec3(min.x, max.y, min.z), 
+            glam::vec3(min.x, min.y, max.z), 
+            glam::vec3(max.x, min.y, max.z), 
+            glam::vec3(max.x, max.y, max.z), 
+            glam::vec3(min.x, max.y, max.z), 
+        ];
+        self.box_edges(&corners, color);
+    }
+
+    /// #### 한국어 </br>
+    /// 세 개의 축에 수직인 원으로 근사한 구를 추가합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Adds a sphere approximated by circles perpendicular to the three axes. </br>
+    /// 
+    pub fn sphere(&mut self, center: glam::Vec3, radius: f32, color: Color) {
+        self.circle(center, glam::Vec3::X, radius, color);
+        self.circle(center, glam::Vec3::Y, radius, color);
+        self.circle(center, glam::Vec3::Z, radius, color);
+    }
+
+    /// #### 한국어 </br>
+    /// 주어진 법선에 수직인 원을 추가합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Adds a circle perpendicular to the given normal. </br>
+    /// 
+    pub fn circle(&mut self, center: glam::Vec3, normal: glam::Vec3, radius: f32, color: Color) {
+        let (u, v) = normal.normalize().any_orthonormal_pair();
+        let point = |i: usize| {
+            let angle = TAU * i as f32 / CIRCLE_SEGMENTS as f32;
+            center + (u * angle.cos() + v * angle.sin()) * radius
+        };
+
+        for i in 0..CIRCLE_SEGMENTS {
+            self.line(point(i), point(i + 1), color);
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 변환 행렬의 X, Y, Z 축을 각각 빨간색, 초록색, 파란색으로 추가합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Adds the X, Y and Z axes of the transform matrix in red, green and blue respectively. </br>
+    /// 
+    pub fn axes(&mut self, transform: &glam::Mat4, size: f32) {
+        let origin = transform.transform_point3(glam::Vec3::ZERO);
+        let x = transform.transform_point3(glam::Vec3::X * size);
+        let y = transform.transform_point3(glam::Vec3::Y * size);
+        let z = transform.transform_point3(glam::Vec3::Z * size);
+        self.line(origin, x, Color::Rgb { red: 1.0, green: 0.0, blue: 0.0 });
+        self.line(origin, y, Color::Rgb { red: 0.0, green: 1.0, blue: 0.0 });
+        self.line(origin, z, Color::Rgb { red: 0.0, green: 0.0, blue: 1.0 });
+    }
+
+    /// #### 한국어 </br>
+    /// 카메라의 투영-뷰 행렬로부터 절두체를 추가합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Adds a frustum from the projection-view matrix of a camera. </br>
+    /// 
+    pub fn frustum(&mut self, proj_view: &glam::Mat4, color: Color) {
+        // (한국어) 정규화된 장치 좌표계의 깊이 범위는 [0, 1] 입니다.
+        // (English Translation) The depth range of normalized device coordinates is [0, 1].
+        let inv_proj_view = proj_view.inverse();
+        let corners = [
+            glam::vec3(-1.0, -1.0, 0.0), 
+            glam::vec3(1.0, -1.0, 0.0), 
+            glam::vec3(1.0, 1.0, 0.0), 
+            glam::vec3(-1.0, 1.0, 0.0), 
+            glam::vec3(-1.0, -1.0, 1.0), 
+            glam::vec3(1.0, -1.0, 1.0), 
+            glam::vec3(1.0, 1.0, 1.0), 
+            glam::vec3(-1.0, 1.0, 1.0), 
+        ].map(|ndc| inv_proj_view.project_point3(ndc));
+        self.box_edges(&corners, color);
+    }
+
+    /// #### 한국어 </br>
+    /// 앞면 네 점과 뒷면 네 점으로 이루어진 상자의 모서리 12개를 추가합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Adds the 12 edges of a box made of four front points and four back points. </br>
+    /// 
+    fn box_edges(&mut self, corners: &[glam::Vec3; 8], color: Color) {
+        for i in 0..4 {
+            self.line(corners[i], corners[(i + 1) % 4], color);
+            self.line(corners[i + 4], corners[(i + 1) % 4 + 4], color);
+            self.line(corners[i], corners[i + 4], color);
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 정점 버퍼를 해제합니다. 장치가 다시 생성된 경우 다음 업로드에서 새로운 버퍼가 생성됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Releases the vertex buffer. If the device was recreated, a new buffer is created on the next upload. </br>
+    /// 
+    #[inline]
+    pub fn release_buffer(&mut self) {
+        self.buffer = None;
+        self.num_vertices = 0;
+    }
+
+    /// #### 한국어 </br>
+    /// 수집된 도형들을 버텍스 버퍼에 기록합니다. </br>
+    /// 버퍼의 크기가 부족한 경우 더 큰 버퍼를 생성합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Writes the collected shapes to the vertex buffer. </br>
+    /// If the buffer is too small, a larger buffer is created. </br>
+    /// 
+    pub fn upload(&mut self, device: &wgpu::Device, queue: &wgpu::Queue) {
+        self.num_vertices = self.vertices.len() as u32;
+        if self.vertices.is_empty() {
+            return;
+        }
+
+        let size = mem::size_of_val(self.vertices.as_slice()) as wgpu::BufferAddress;
+        let capacity = self.buffer.as_ref().map_or(0, |buffer| buffer.size());
+        if capacity < size {
+            self.buffer = Some(device.create_buffer(
+                &wgpu::BufferDescriptor {
+                    label: Some("VertexBuffer(DebugDraw)"), 
+                    mapped_at_creation: false, 
+                    size: size.next_power_of_two(), 
+                    usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST, 
+                }, 
+            ));
+        }
+
+        if let Some(buffer) = self.buffer.as_ref() {
+            queue.write_buffer(buffer, 0, bytemuck::cast_slice(&self.vertices));
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 마지막으로 기록된 도형들을 그립니다. </br>
+    /// 선 목록 파이프라인과 카메라 바인드 그룹이 미리 설정되어 있어야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Draws the most recently uploaded shapes. </br>
+    /// The line list pipeline and the camera bind group must be set beforehand. </br>
+    /// 
+    pub fn draw<'a>(&'a self, rpass: &mut wgpu::RenderPass<'a>) {
+        if let Some(buffer) = self.buffer.as_ref().filter(|_| self.num_vertices > 0) {
+            rpass.set_vertex_buffer(0, buffer.slice(..));
+            rpass.draw(0..self.num_vertices, 0..1);
+        }
+    }
+}
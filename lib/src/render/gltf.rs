@@ -0,0 +1,391 @@
+use std::sync::Arc;
+use std::path::Path;
+use std::collections::HashMap;
+
+use crate::asset::Asset;
+use crate::asset::AssetError;
+use crate::item::animation::AnimationChannel;
+use crate::item::animation::AnimationClip;
+use crate::item::animation::ChannelKeyframes;
+use crate::item::animation::Interpolation;
+use crate::item::animation::Keyframes;
+use crate::item::skeleton::Joint;
+use crate::item::skeleton::JointPose;
+use crate::item::skeleton::Skeleton;
+use crate::render::mesh::ModelMesh;
+use crate::render::mesh_data::MeshData;
+use crate::render::uniform::MAX_SKIN_JOINTS;
+
+
+
+/// #### 한국어 </br>
+/// `glTF` 파일에서 읽은 CPU 측 데이터입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The CPU-side data read from a `glTF` file. </br>
+/// 
+#[derive(Debug, Clone)]
+pub struct GltfData {
+    pub meshes: Vec<MeshData>, 
+    pub skeleton: Option<Skeleton>, 
+    pub clips: Vec<AnimationClip>, 
+}
+
+/// #### 한국어 </br>
+/// `glTF`(`.gltf`, `.glb`) 형식의 바이트들을 메쉬와 골격, 애니메이션 클립으로 읽습니다. </br>
+/// 버퍼는 `GLB`의 이진 청크 또는 `base64` 데이터 URI에 포함되어 있어야 하며, 외부 파일은 지원하지 않습니다. </br>
+/// 메쉬를 가진 노드마다 메쉬 하나가 만들어지며, 스킨이 없는 메쉬는 노드의 전역 변환이 적용됩니다. </br>
+/// 첫 번째 스킨만 골격으로 읽으며, 애니메이션은 그 골격의 관절을 움직이는 채널만 읽습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Reads bytes in `glTF` (`.gltf`, `.glb`) format into meshes, a skeleton and animation clips. </br>
+/// Buffers must be embedded in the binary chunk of a `GLB` or in `base64` data URIs, and external files are not supported. </br>
+/// One mesh is created for each node with a mesh, and meshes without a skin have the global transform of the node applied. </br>
+/// Only the first skin is read as the skeleton, and only the animation channels that move its joints are read. </br>
+/// 
+pub fn parse(bytes: &[u8]) -> Result<GltfData, String> {
+    let gltf = ::gltf::Gltf::from_slice(bytes).map_err(|err| err.to_string())?;
+    let buffers = load_buffers(&gltf)?;
+
+    let num_nodes = gltf.nodes().len();
+    let mut parents = vec![None; num_nodes];
+    for node in gltf.nodes() {
+        for child in node.children() {
+            parents[child.index()] = Some(node.index());
+        }
+    }
+    let locals: Vec<glam::Mat4> = gltf.nodes()
+        .map(|node| glam::Mat4::from_cols_array_2d(&node.transform().matrix()))
+        .collect();
+    let global_transform = |index: usize| {
+        let mut transform = locals[index];
+        let mut parent = parents[index];
+        for _ in 0..num_nodes {
+            let Some(current) = parent else { break };
+            transform = locals[current] * transform;
+            parent = parents[current];
+        }
+        transform
+    };
+
+    let mut meshes = Vec::new();
+    for node in gltf.nodes() {
+        let Some(mesh) = node.mesh() else { continue };
+        let mut data = read_mesh(&mesh, &buffers)?;
+        if node.skin().is_none() {
+            data.transform(global_transform(node.index()));
+        }
+        meshes.push(data.with_label(mesh.name().unwrap_or("GltfMesh")));
+    }
+    if meshes.is_empty() {
+        return Err("the file has no meshes".to_string());
+    }
+
+    let Some(skin) = gltf.skins().next() else {
+        return Ok(GltfData { meshes, skeleton: None, clips: Vec::new() });
+    };
+
+    // (한국어) 관절의 부모는 관절인 가장 가까운 조상 노드입니다.
+    // (English Translation) The parent of a joint is the nearest ancestor node that is a joint.
+    let joint_nodes: Vec<usize> = skin.joints().map(|node| node.index()).collect();
+    let joint_of_node: HashMap<usize, usize> = joint_nodes.iter()
+        .enumerate()
+        .map(|(joint, &node)| (node, joint))
+        .collect();
+    let inverse_bind_matrices: Vec<glam::Mat4> = skin.reader(|buffer| buffers.get(buffer.index()).map(Vec::as_slice))
+        .read_inverse_bind_matrices()
+        .map(|matrices| matrices.map(|matrix| glam::Mat4::from_cols_array_2d(&matrix)).collect())
+        .unwrap_or_else(|| vec![glam::Mat4::IDENTITY; joint_nodes.len()]);
+    if inverse_bind_matrices.len() != joint_nodes.len() {
+        return Err(format!("expected {} inverse bind matrices, found {}", joint_nodes.len(), inverse_bind_matrices.len()));
+    }
+
+    let mut joints = Vec::with_capacity(joint_nodes.len());
+    let mut root_transform = None;
+    for (node, inverse_bind_matrix) in skin.joints().zip(inverse_bind_matrices) {
+        let mut parent = parents[node.index()];
+        while let Some(current) = parent.filter(|it| !joint_of_node.contains_key(it)) {
+            parent = parents[current];
+        }
+        let parent_joint = parent.map(|it| joint_of_node[&it]);
+        if parent_joint.is_none() && root_transform.is_none() {
+            root_transform = parents[node.index()].map(global_transform);
+        }
+
+        let (translation, rotation, scale) = node.transform().decomposed();
+        joints.push(Joint {
+            name: node.name().map(str::to_string).unwrap_or_else(|| format!("Joint{}", joints.len())), 
+            parent: parent_joint, 
+            inverse_bind_matrix, 
+            rest_pose: JointPose {
+                translation: translation.into(), 
+                rotation: glam::Quat::from_array(rotation), 
+                scale: scale.into(), 
+            }, 
+        });
+    }
+    let skeleton = Skeleton::new(joints)?
+        .with_root_transform(root_transform.unwrap_or(glam::Mat4::IDENTITY));
+
+    let clips = gltf.animations()
+        .map(|animation| read_clip(&animation, &buffers, &joint_of_node))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(GltfData { meshes, skeleton: Some(skeleton), clips })
+}
+
+/// #### 한국어 </br>
+/// 메쉬의 모든 삼각형 프리미티브를 하나의 메쉬 데이터로 합칩니다. 법선이 없다면 부드러운 법선을 계산합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Merges all triangle primitives of the mesh into one mesh data. Computes smooth normals if there are no normals. </br>
+/// 
+fn read_mesh(mesh: &::gltf::Mesh, buffers: &[Vec<u8>]) -> Result<MeshData, String> {
+    let mut primitives = Vec::new();
+    for primitive in mesh.primitives() {
+        if primitive.mode() != ::gltf::mesh::Mode::Triangles {
+            log::warn!("Skipping a primitive of mesh {} with unsupported mode {:?}", mesh.index(), primitive.mode());
+            continue;
+        }
+
+        let reader = primitive.reader(|buffer| buffers.get(buffer.index()).map(Vec::as_slice));
+        let positions: Vec<glam::Vec3> = reader.read_positions()
+            .ok_or_else(|| format!("a primitive of mesh {} has no positions", mesh.index()))?
+            .map(glam::Vec3::from)
+            .collect();
+        let indices: Vec<u32> = match reader.read_indices() {
+            Some(indices) => indices.into_u32().collect(), 
+            None => (0..positions.len() as u32).collect(), 
+        };
+
+        let mut data = MeshData::new(positions, Vec::new(), indices);
+        match reader.read_normals() {
+            Some(normals) => data.normals = normals.map(glam::Vec3::from).collect(), 
+            None => data.compute_smooth_normals(), 
+        };
+        if let Some(uvs) = reader.read_tex_coords(0) {
+            data.uvs = uvs.into_f32().map(glam::Vec2::from).collect();
+        }
+        if let Some(colors) = reader.read_colors(0) {
+            data.colors = colors.into_rgba_f32().map(glam::Vec4::from).collect();
+        }
+        if let (Some(joints), Some(weights)) = (reader.read_joints(0), reader.read_weights(0)) {
+            data.joints = joints.into_u16().collect();
+            data.weights = weights.into_f32().map(glam::Vec4::from).collect();
+        }
+        data.validate().map_err(|msg| format!("mesh {}: {}", mesh.index(), msg))?;
+        primitives.push(data);
+    }
+    Ok(MeshData::merge(primitives.iter()))
+}
+
+/// #### 한국어 </br>
+/// 골격의 관절을 움직이는 채널들로 애니메이션 클립을 만듭니다. 모프 타겟 가중치 채널은 무시됩니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Builds an animation clip from the channels that move the joints of the skeleton. Morph target weight channels are ignored. </br>
+/// 
+fn read_clip(
+    animation: &::gltf::Animation, 
+    buffers: &[Vec<u8>], 
+    joint_of_node: &HashMap<usize, usize>
+) -> Result<AnimationClip, String> {
+    use ::gltf::animation::util::ReadOutputs;
+
+    let mut channels = Vec::new();
+    for channel in animation.channels() {
+        let Some(&joint) = joint_of_node.get(&channel.target().node().index()) else {
+            continue;
+        };
+
+        let interpolation = match channel.sampler().interpolation() {
+            ::gltf::animation::Interpolation::Step => Interpolation::Step, 
+            ::gltf::animation::Interpolation::Linear => Interpolation::Linear, 
+            ::gltf::animation::Interpolation::CubicSpline => Interpolation::CubicSpline, 
+        };
+        let error = |msg: &str| format!("animation {} channel {}: {}", animation.index(), channel.index(), msg);
+
+        let reader = channel.reader(|buffer| buffers.get(buffer.index()).map(Vec::as_slice));
+        let times: Vec<f32> = reader.read_inputs()
+            .ok_or_else(|| error("missing keyframe times"))?
+            .collect();
+        let keyframes = match reader.read_outputs().ok_or_else(|| error("missing keyframe values"))? {
+            ReadOutputs::Translations(values) => ChannelKeyframes::Translation(
+                Keyframes::new(times, values.map(glam::Vec3::from).collect(), interpolation).map_err(|msg| error(&msg))?
+            ), 
+            ReadOutputs::Rotations(values) => ChannelKeyframes::Rotation(
+                Keyframes::new(times, values.into_f32().map(glam::Quat::from_array).collect(), interpolation).map_err(|msg| error(&msg))?
+            ), 
+            ReadOutputs::Scales(values) => ChannelKeyframes::Scale(
+                Keyframes::new(times, values.map(glam::Vec3::from).collect(), interpolation).map_err(|msg| error(&msg))?
+            ), 
+            ReadOutputs::MorphTargetWeights(_) => continue, 
+        };
+        channels.push(AnimationChannel { joint, keyframes });
+    }
+
+    let name = animation.name()
+        .map(str::to_string)
+        .unwrap_or_else(|| format!("Animation{}", animation.index()));
+    Ok(AnimationClip::new(name, channels))
+}
+
+/// #### 한국어 </br>
+/// 파일에 포함된 모든 버퍼의 바이트들을 읽습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Reads the bytes of all buffers embedded in the file. </br>
+/// 
+fn load_buffers(gltf: &::gltf::Gltf) -> Result<Vec<Vec<u8>>, String> {
+    gltf.buffers()
+        .map(|buffer| {
+            let data = match buffer.source() {
+                ::gltf::buffer::Source::Bin => gltf.blob.clone()
+                    .ok_or_else(|| "the binary chunk is missing".to_string())?, 
+                ::gltf::buffer::Source::Uri(uri) => match uri.strip_prefix("data:").and_then(|it| it.split_once(";base64,")) {
+                    Some((_, encoded)) => decode_base64(encoded)?, 
+                    None => return Err(format!("external buffer {} is not supported", uri)), 
+                }, 
+            };
+            if data.len() < buffer.length() {
+                return Err(format!("buffer {} is shorter than {} bytes", buffer.index(), buffer.length()));
+            }
+            Ok(data)
+        })
+        .collect()
+}
+
+/// #### 한국어 </br>
+/// `base64`(표준 또는 URL 안전) 문자열을 바이트들로 디코딩합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Decodes a `base64` (standard or URL-safe) string into bytes. </br>
+/// 
+fn decode_base64(text: &str) -> Result<Vec<u8>, String> {
+    // (한국어) 채움 문자는 생략할 수 있지만, 있다면 문자열의 끝에서 4글자 단위를 맞춰야 합니다.
+    // (English Translation) Padding may be omitted, but if present it must be at the end and complete a 4-character group.
+    let data = text.trim_end_matches('=');
+    let num_padding = text.len() - data.len();
+    if data.len() % 4 == 1 {
+        return Err("truncated base64 data".to_string());
+    }
+    if num_padding > 0 && (num_padding > 2 || text.len() % 4 != 0) {
+        return Err("invalid base64 padding".to_string());
+    }
+
+    let mut bytes = Vec::with_capacity(data.len() / 4 * 3 + 2);
+    let mut accumulator = 0u32;
+    let mut num_bits = 0;
+    for c in data.bytes() {
+        let value = match c {
+            b'A'..=b'Z' => c - b'A', 
+            b'a'..=b'z' => c - b'a' + 26, 
+            b'0'..=b'9' => c - b'0' + 52, 
+            b'+' | b'-' => 62, 
+            b'/' | b'_' => 63, 
+            _ => return Err(format!("invalid base64 character {:?}", c as char)), 
+        };
+        accumulator = (accumulator << 6) | value as u32;
+        num_bits += 6;
+        if num_bits >= 8 {
+            num_bits -= 8;
+            bytes.push((accumulator >> num_bits) as u8);
+            accumulator &= (1 << num_bits) - 1;
+        }
+    }
+    Ok(bytes)
+}
+
+
+
+/// #### 한국어 </br>
+/// `glTF` 파일에서 불러온 모델입니다. 메쉬들은 GPU에 업로드되며, 골격과 애니메이션 클립은 엔티티들이 복제하여 사용합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A model loaded from a `glTF` file. The meshes are uploaded to the GPU, and the skeleton and animation clips are cloned by entities. </br>
+/// 
+#[derive(Debug, Clone)]
+pub struct GltfModel {
+    meshes: Vec<ModelMesh>, 
+    skeleton: Option<Skeleton>, 
+    clips: Vec<Arc<AnimationClip>>, 
+}
+
+#[allow(dead_code)]
+impl GltfModel {
+    #[inline]
+    pub fn meshes(&self) -> &[ModelMesh] {
+        &self.meshes
+    }
+
+    #[inline]
+    pub fn skeleton(&self) -> Option<&Skeleton> {
+        self.skeleton.as_ref()
+    }
+
+    #[inline]
+    pub fn clips(&self) -> &[Arc<AnimationClip>] {
+        &self.clips
+    }
+
+    #[inline]
+    pub fn find_clip(&self, name: &str) -> Option<&Arc<AnimationClip>> {
+        self.clips.iter().find(|clip| clip.name() == name)
+    }
+}
+
+impl Asset for GltfModel {
+    type Data = GltfData;
+
+    fn decode(path: &Path, bytes: &[u8]) -> Result<Self::Data, AssetError> {
+        let data = parse(bytes).map_err(|msg| AssetError::Decode(path.to_path_buf(), msg))?;
+        if let Some(skeleton) = data.skeleton.as_ref().filter(|it| it.num_joints() > MAX_SKIN_JOINTS) {
+            return Err(AssetError::Decode(path.to_path_buf(), format!(
+                "the skeleton has {} joints, but at most {} joints can be skinned on the GPU", 
+                skeleton.num_joints(), 
+                MAX_SKIN_JOINTS
+            )));
+        }
+        Ok(data)
+    }
+
+    fn upload(data: Self::Data, device: &wgpu::Device, queue: &wgpu::Queue) -> Result<Self, AssetError> {
+        Ok(Self {
+            meshes: data.meshes.iter().map(|mesh| mesh.upload(device, queue)).collect(), 
+            skeleton: data.skeleton, 
+            clips: data.clips.into_iter().map(Arc::new).collect(), 
+        })
+    }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_base64_accepts_standard_and_url_safe_alphabets() {
+        assert_eq!(decode_base64("").unwrap(), b"");
+        assert_eq!(decode_base64("TWFu").unwrap(), b"Man");
+        assert_eq!(decode_base64("TWE=").unwrap(), b"Ma");
+        assert_eq!(decode_base64("TQ==").unwrap(), b"M");
+        assert_eq!(decode_base64("+/8=").unwrap(), [0xfb, 0xff]);
+        assert_eq!(decode_base64("-_8=").unwrap(), [0xfb, 0xff]);
+    }
+
+    #[test]
+    fn decode_base64_accepts_missing_padding() {
+        assert_eq!(decode_base64("TWE").unwrap(), b"Ma");
+        assert_eq!(decode_base64("TQ").unwrap(), b"M");
+    }
+
+    #[test]
+    fn decode_base64_rejects_malformed_input() {
+        assert!(decode_base64("TWF*").is_err());
+        assert!(decode_base64("TWFuT").is_err());
+        assert!(decode_base64("TQ=").is_err());
+        assert!(decode_base64("T===").is_err());
+        assert!(decode_base64("TQ==TQ==").is_err());
+        assert!(decode_base64("TWE==").is_err());
+    }
+}
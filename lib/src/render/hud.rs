@@ -0,0 +1,615 @@
+use std::mem;
+use std::sync::Arc;
+use std::any::TypeId;
+use std::collections::HashMap;
+use bytemuck::Pod;
+use bytemuck::Zeroable;
+
+use crate::error::AppError;
+use crate::item::color::Color;
+use crate::render::find_layout;
+
+pub const HUD_VERTEX_BUFFER_LAYOUT: wgpu::VertexBufferLayout = wgpu::VertexBufferLayout {
+    array_stride: mem::size_of::<HudVertex>() as wgpu::BufferAddress, 
+    step_mode: wgpu::VertexStepMode::Vertex, 
+    attributes: &[
+        wgpu::VertexAttribute {
+            format: wgpu::VertexFormat::Float32x2, 
+            offset: 0x00 as wgpu::BufferAddress, 
+            shader_location: 0, 
+        }, 
+        wgpu::VertexAttribute {
+            format: wgpu::VertexFormat::Float32x2, 
+            offset: 0x08 as wgpu::BufferAddress, 
+            shader_location: 1, 
+        }, 
+        wgpu::VertexAttribute {
+            format: wgpu::VertexFormat::Float32x4, 
+            offset: 0x10 as wgpu::BufferAddress, 
+            shader_location: 2, 
+        }, 
+    ], 
+};
+
+/// #### 한국어 </br>
+/// 텍스처를 샘플링하지 않고 단색으로 채워지는 사각형의 텍스처 좌표입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The texture coordinates of a quad filled with a solid color without sampling the texture. </br>
+/// 
+const SOLID_TEX_COORD: [f32; 2] = [-1.0, -1.0];
+
+
+
+/// #### 한국어 </br>
+/// 화면 공간 오버레이의 버텍스 입력 레이아웃 입니다. </br>
+/// 위치는 화면의 왼쪽 위를 원점으로 하는 픽셀 단위 좌표입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The vertex input layout for the screen space overlay. </br>
+/// The position is in pixels with the origin at the top left of the screen. </br>
+/// 
+#[repr(C)]
+#[derive(Pod, Zeroable)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HudVertex {
+    pub position: [f32; 2], 
+    pub tex_coord: [f32; 2], 
+    pub color: [f32; 4], 
+}
+
+
+
+/// #### 한국어 </br>
+/// 화면 공간 오버레이 유니폼 데이터의 레이아웃 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The layout of the screen space overlay uniform data. </br>
+/// 
+#[repr(C, align(16))]
+#[derive(Pod, Zeroable)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HudUniformLayout {
+    pub screen_size: glam::Vec2, 
+    pub _padding0: [u8; 8], 
+}
+
+impl HudUniformLayout {
+    pub fn layout(device: &wgpu::Device) -> wgpu::BindGroupLayout {
+        device.create_bind_group_layout(
+            &wgpu::BindGroupLayoutDescriptor {
+                label: Some("BindGroupLayout(HudUniformLayout)"), 
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0, 
+                        visibility: wgpu::ShaderStages::VERTEX, 
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Uniform, 
+                            has_dynamic_offset: false, 
+                            min_binding_size: None
+                        }, 
+                        count: None, 
+                    }, 
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1, 
+                        visibility: wgpu::ShaderStages::FRAGMENT, 
+                        ty: wgpu::BindingType::Texture {
+                            sample_type: wgpu::TextureSampleType::Float { filterable: true }, 
+                            view_dimension: wgpu::TextureViewDimension::D2, 
+                            multisampled: false, 
+                        }, 
+                        count: None, 
+                    }, 
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 2, 
+                        visibility: wgpu::ShaderStages::FRAGMENT, 
+                        ty: wgpu::BindingType::Sampler(
+                            wgpu::SamplerBindingType::Filtering
+                        ), 
+                        count: None, 
+                    }, 
+                ], 
+            }, 
+        )
+    }
+}
+
+impl Default for HudUniformLayout {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            screen_size: glam::Vec2::ONE, 
+            _padding0: [0; 8], 
+        }
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 고정 폭 비트맵 글꼴입니다. </br>
+/// 글리프들은 하나의 아틀라스 이미지에 문자 코드 순서대로 배치되어 있습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A monospace bitmap font. </br>
+/// Glyphs are arranged in a single atlas image in character code order. </br>
+/// 
+#[derive(Debug, Clone)]
+pub struct BitmapFont {
+    glyph_width: u32, 
+    glyph_height: u32, 
+    first_char: char, 
+    last_char: char, 
+    atlas_width: u32, 
+    atlas_height: u32, 
+    pixels: Vec<u8>, 
+}
+
+#[allow(dead_code)]
+impl BitmapFont {
+    /// #### 한국어 </br>
+    /// 내장된 기본 글꼴을 불러옵니다. (X11 misc-fixed 8x13, 퍼블릭 도메인) </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Loads the embedded default font. (X11 misc-fixed 8x13, public domain) </br>
+    /// 
+    pub fn default_font() -> Self {
+        Self::from_1bpp(
+            include_bytes!(concat!(env!("CARGO_MANIFEST_DIR"), "/assets/fonts/font_8x13.raw")), 
+            128, 
+            8, 
+            13, 
+            ' '
+        )
+    }
+
+    /// #### 한국어 </br>
+    /// 한 픽셀당 1비트로 압축된 아틀라스 이미지로부터 글꼴을 생성합니다. </br>
+    /// 각 행의 픽셀들은 최상위 비트부터 순서대로 저장되어 있어야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates a font from an atlas image packed with 1 bit per pixel. </br>
+    /// The pixels of each row must be stored in order starting from the most significant bit. </br>
+    /// 
+    pub fn from_1bpp(data: &[u8], atlas_width: u32, glyph_width: u32, glyph_height: u32, first_char: char) -> Self {
+        let bytes_per_row = (atlas_width as usize).div_ceil(8);
+        let atlas_height = (data.len() / bytes_per_row) as u32;
+        let mut pixels = Vec::with_capacity((atlas_width * atlas_height) as usize);
+        for y in 0..atlas_height as usize {
+            for x in 0..atlas_width as usize {
+                let bit = data[y * bytes_per_row + x / 8] >> (7 - x % 8) & 1;
+                pixels.push(if bit != 0 { u8::MAX } else { 0 });
+            }
+        }
+
+        let num_glyphs = (atlas_width / glyph_width) * (atlas_height / glyph_height);
+        let last_char = char::from_u32(first_char as u32 + num_glyphs - 1).unwrap_or(first_char);
+
+        Self {
+            glyph_width, 
+            glyph_height, 
+            first_char, 
+            last_char, 
+            atlas_width, 
+            atlas_height, 
+            pixels, 
+        }
+    }
+
+    #[inline]
+    pub fn glyph_width(&self) -> u32 {
+        self.glyph_width
+    }
+
+    #[inline]
+    pub fn glyph_height(&self) -> u32 {
+        self.glyph_height
+    }
+
+    /// #### 한국어 </br>
+    /// 문자의 아틀라스 내 텍스처 좌표 `(min, max)`를 반환합니다. </br>
+    /// 글꼴에 없는 문자는 `?`로 대체됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the texture coordinates `(min, max)` of the character in the atlas. </br>
+    /// Characters not in the font are replaced with `?`. </br>
+    /// 
+    pub fn tex_coords(&self, ch: char) -> (glam::Vec2, glam::Vec2) {
+        let ch = if (self.first_char..=self.last_char).contains(&ch) { ch } else { '?' };
+        let index = ch as u32 - self.first_char as u32;
+        let columns = self.atlas_width / self.glyph_width;
+        let x = (index % columns) * self.glyph_width;
+        let y = (index / columns) * self.glyph_height;
+
+        let size = glam::vec2(self.atlas_width as f32, self.atlas_height as f32);
+        let min = glam::vec2(x as f32, y as f32) / size;
+        let max = glam::vec2((x + self.glyph_width) as f32, (y + self.glyph_height) as f32) / size;
+        (min, max)
+    }
+
+    /// #### 한국어 </br>
+    /// 글꼴의 아틀라스 텍스처를 생성합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates the atlas texture of the font. </br>
+    /// 
+    pub fn create_texture(&self, device: &wgpu::Device, queue: &wgpu::Queue) -> wgpu::Texture {
+        let size = wgpu::Extent3d {
+            width: self.atlas_width, 
+            height: self.atlas_height, 
+            depth_or_array_layers: 1, 
+        };
+
+        let texture = device.create_texture(
+            &wgpu::TextureDescriptor {
+                label: Some("Texture(BitmapFont)"), 
+                size, 
+                format: wgpu::TextureFormat::R8Unorm, 
+                dimension: wgpu::TextureDimension::D2, 
+                mip_level_count: 1, 
+                sample_count: 1, 
+                usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST, 
+                view_formats: &[], 
+            }, 
+        );
+
+        queue.write_texture(
+            wgpu::ImageCopyTexture {
+                texture: &texture, 
+                mip_level: 0, 
+                origin: wgpu::Origin3d::ZERO, 
+                aspect: wgpu::TextureAspect::All, 
+            }, 
+            &self.pixels, 
+            wgpu::ImageDataLayout {
+                offset: 0, 
+                bytes_per_row: Some(self.atlas_width), 
+                rows_per_image: Some(self.atlas_height), 
+            }, 
+            size
+        );
+
+        texture
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 한 프레임 동안 화면 공간에 그려질 텍스트, 패널, 버튼들을 모으는 2차원 오버레이 계층입니다. </br>
+/// 장면은 `on_update`에서 요소들을 추가하고 `upload`를 호출하며, `on_draw`에서 `draw`를 호출합니다. </br>
+/// 모든 좌표는 화면의 배율이 적용되지 않은 논리 픽셀 단위입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A 2D overlay layer that gathers text, panels and buttons to be drawn in screen space for a single frame. </br>
+/// The scene adds elements and calls `upload` in `on_update`, and calls `draw` in `on_draw`. </br>
+/// All coordinates are in logical pixels without the scale factor of the screen applied. </br>
+/// 
+#[derive(Debug)]
+pub struct Hud {
+    font: BitmapFont, 
+    screen_size: glam::Vec2, 
+    scale_factor: f32, 
+
+    pointer_position: Option<glam::Vec2>, 
+    pointer_down: bool, 
+    pointer_released: bool, 
+
+    vertices: Vec<HudVertex>, 
+    buffer: Option<wgpu::Buffer>, 
+    num_vertices: u32, 
+
+    uniform_buffer: Arc<wgpu::Buffer>, 
+    bind_group: Arc<wgpu::BindGroup>, 
+}
+
+#[allow(dead_code)]
+impl Hud {
+    pub fn new(
+        font: BitmapFont, 
+        layouts: &HashMap<TypeId, wgpu::BindGroupLayout>, 
+        device: &wgpu::Device, 
+        queue: &wgpu::Queue
+    ) -> Result<Self, AppError> {
+        let uniform_buffer = device.create_buffer(
+            &wgpu::BufferDescriptor {
+                label: Some("UniformBuffer(HudUniformLayout)"), 
+                mapped_at_creation: false, 
+                size: mem::size_of::<HudUniformLayout>() as wgpu::BufferAddress, 
+                usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST, 
+            }, 
+        );
+
+        let texture_view = font.create_texture(device, queue)
+            .create_view(&wgpu::TextureViewDescriptor { ..Default::default() });
+
+        // (한국어) 글리프의 픽셀 경계를 유지하기 위해 최근접 필터링을 사용합니다.
+        // (English Translation) Nearest filtering is used to keep the pixel edges of the glyphs.
+        let sampler = device.create_sampler(
+            &wgpu::SamplerDescriptor {
+                label: Some("Sampler(BitmapFont)"), 
+                address_mode_u: wgpu::AddressMode::ClampToEdge, 
+                address_mode_v: wgpu::AddressMode::ClampToEdge, 
+                address_mode_w: wgpu::AddressMode::ClampToEdge, 
+                mag_filter: wgpu::FilterMode::Nearest, 
+                min_filter: wgpu::FilterMode::Nearest, 
+                mipmap_filter: wgpu::FilterMode::Nearest, 
+                ..Default::default()
+            }
+        );
+
+        let bind_group = device.create_bind_group(
+            &wgpu::BindGroupDescriptor {
+                label: Some("BindGroup(HudUniformLayout)"), 
+                layout: find_layout::<HudUniformLayout>(layouts)?, 
+                entries: &[
+                    wgpu::BindGroupEntry {
+                        binding: 0, 
+                        resource: wgpu::BindingResource::Buffer(
+                            uniform_buffer.as_entire_buffer_binding()
+                        ), 
+                    }, 
+                    wgpu::BindGroupEntry {
+                        binding: 1, 
+                        resource: wgpu::BindingResource::TextureView(&texture_view), 
+                    }, 
+                    wgpu::BindGroupEntry {
+                        binding: 2, 
+                        resource: wgpu::BindingResource::Sampler(&sampler), 
+                    }, 
+                ], 
+            }, 
+        );
+
+        Ok(Self {
+            font, 
+            screen_size: glam::Vec2::ONE, 
+            scale_factor: 1.0, 
+            pointer_position: None, 
+            pointer_down: false, 
+            pointer_released: false, 
+            vertices: Vec::new(), 
+            buffer: None, 
+            num_vertices: 0, 
+            uniform_buffer: uniform_buffer.into(), 
+            bind_group: bind_group.into(), 
+        })
+    }
+
+    #[inline]
+    pub fn font(&self) -> &BitmapFont {
+        &self.font
+    }
+
+    /// #### 한국어 </br>
+    /// 논리 픽셀 단위의 화면 크기를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the screen size in logical pixels. </br>
+    /// 
+    #[inline]
+    pub fn screen_size(&self) -> glam::Vec2 {
+        self.screen_size / self.scale_factor
+    }
+
+    #[inline]
+    pub fn scale_factor(&self) -> f32 {
+        self.scale_factor
+    }
+
+    /// #### 한국어 </br>
+    /// 물리 픽셀 단위의 화면 크기와 화면의 배율을 설정합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the screen size in physical pixels and the scale factor of the screen. </br>
+    /// 
+    #[inline]
+    pub fn set_screen_size(&mut self, width: u32, height: u32, scale_factor: f64) {
+        self.screen_size = glam::vec2(width.max(1) as f32, height.max(1) as f32);
+        self.scale_factor = (scale_factor as f32).max(1.0);
+    }
+
+    /// #### 한국어 </br>
+    /// 포인터(마우스 커서 또는 터치)의 물리 픽셀 단위 화면 위치를 갱신합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Updates the screen position of the pointer (mouse cursor or touch) in physical pixels. </br>
+    /// 
+    #[inline]
+    pub fn pointer_moved(&mut self, x: f32, y: f32) {
+        self.pointer_position = Some(glam::vec2(x, y) / self.scale_factor);
+    }
+
+    /// #### 한국어 </br>
+    /// 포인터의 눌림 상태를 갱신합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Updates the pressed state of the pointer. </br>
+    /// 
+    #[inline]
+    pub fn pointer_pressed(&mut self, pressed: bool) {
+        self.pointer_released |= self.pointer_down && !pressed;
+        self.pointer_down = pressed;
+    }
+
+    /// #### 한국어 </br>
+    /// 포인터가 화면을 벗어났을 때 호출합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Called when the pointer leaves the screen. </br>
+    /// 
+    #[inline]
+    pub fn pointer_left(&mut self) {
+        self.pointer_position = None;
+        self.pointer_down = false;
+    }
+
+    /// #### 한국어 </br>
+    /// 지금까지 추가된 요소들을 모두 지웁니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Clears all elements added so far. </br>
+    /// 
+    #[inline]
+    pub fn clear(&mut self) {
+        self.vertices.clear();
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.vertices.is_empty()
+    }
+
+    /// #### 한국어 </br>
+    /// 주어진 배율로 그려진 텍스트의 픽셀 크기를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the pixel size of the text drawn at the given scale. </br>
+    /// 
+    pub fn text_size(&self, text: &str, scale: f32) -> glam::Vec2 {
+        let columns = text.lines().map(|line| line.chars().count()).max().unwrap_or(0);
+        let rows = text.lines().count().max(1);
+        glam::vec2(
+            (columns as u32 * self.font.glyph_width) as f32 * scale, 
+            (rows as u32 * self.font.glyph_height) as f32 * scale
+        )
+    }
+
+    /// #### 한국어 </br>
+    /// 왼쪽 위 위치에서부터 텍스트를 추가합니다. `\n`으로 줄을 바꿀 수 있습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Adds text starting at the top left position. Lines can be broken with `\n`. </br>
+    /// 
+    pub fn text(&mut self, x: f32, y: f32, scale: f32, color: Color, text: &str) {
+        let glyph_size = glam::vec2(self.font.glyph_width as f32, self.font.glyph_height as f32) * scale;
+        for (row, line) in text.lines().enumerate() {
+            for (column, ch) in line.chars().enumerate() {
+                if ch == ' ' {
+                    continue;
+                }
+
+                let min = glam::vec2(x, y) + glyph_size * glam::vec2(column as f32, row as f32);
+                let (uv_min, uv_max) = self.font.tex_coords(ch);
+                self.quad(min, min + glyph_size, uv_min.to_array(), uv_max.to_array(), color);
+            }
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 단색으로 채워진 사각형 패널을 추가합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Adds a rectangular panel filled with a solid color. </br>
+    /// 
+    #[inline]
+    pub fn panel(&mut self, x: f32, y: f32, width: f32, height: f32, color: Color) {
+        self.quad(
+            glam::vec2(x, y), 
+            glam::vec2(x + width, y + height), 
+            SOLID_TEX_COORD, 
+            SOLID_TEX_COORD, 
+            color
+        );
+    }
+
+    /// #### 한국어 </br>
+    /// 가운데 정렬된 라벨을 가진 버튼을 추가합니다. </br>
+    /// 이번 프레임에 버튼 위에서 포인터가 떼어졌다면 `true`를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Adds a button with a centered label. </br>
+    /// Returns `true` if the pointer was released over the button in this frame. </br>
+    /// 
+    pub fn button(&mut self, x: f32, y: f32, width: f32, height: f32, label: &str) -> bool {
+        let hovered = self.pointer_position.is_some_and(|pos| {
+            x <= pos.x && pos.x < x + width && y <= pos.y && pos.y < y + height
+        });
+
+        let color = match (hovered, self.pointer_down) {
+            (true, true) => Color::Rgba { red: 0.1, green: 0.1, blue: 0.1, alpha: 0.85 }, 
+            (true, false) => Color::Rgba { red: 0.35, green: 0.35, blue: 0.35, alpha: 0.85 }, 
+            _ => Color::Rgba { red: 0.2, green: 0.2, blue: 0.2, alpha: 0.75 }, 
+        };
+        self.panel(x, y, width, height, color);
+
+        let scale = 1.0;
+        let size = self.text_size(label, scale);
+        let origin = (glam::vec2(x, y) + (glam::vec2(width, height) - size) * 0.5).round();
+        self.text(origin.x, origin.y, scale, Color::Rgb { red: 1.0, green: 1.0, blue: 1.0 }, label);
+
+        hovered && self.pointer_released
+    }
+
+    fn quad(&mut self, min: glam::Vec2, max: glam::Vec2, uv_min: [f32; 2], uv_max: [f32; 2], color: Color) {
+        let min = min * self.scale_factor;
+        let max = max * self.scale_factor;
+        let color = color.as_vec4().to_array();
+        let top_left = HudVertex { position: [min.x, min.y], tex_coord: [uv_min[0], uv_min[1]], color };
+        let top_right = HudVertex { position: [max.x, min.y], tex_coord: [uv_max[0], uv_min[1]], color };
+        let bottom_left = HudVertex { position: [min.x, max.y], tex_coord: [uv_min[0], uv_max[1]], color };
+        let bottom_right = HudVertex { position: [max.x, max.y], tex_coord: [uv_max[0], uv_max[1]], color };
+        self.vertices.extend_from_slice(&[
+            top_left, bottom_left, top_right, 
+            top_right, bottom_left, bottom_right, 
+        ]);
+    }
+
+    /// #### 한국어 </br>
+    /// 수집된 요소들과 화면 크기를 버퍼에 기록하고, 이번 프레임의 포인터 입력을 소비합니다. </br>
+    /// 버퍼의 크기가 부족한 경우 더 큰 버퍼를 생성합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Writes the collected elements and the screen size to the buffers, and consumes the pointer input of this frame. </br>
+    /// If the buffer is too small, a larger buffer is created. </br>
+    /// 
+    pub fn upload(&mut self, device: &wgpu::Device, queue: &wgpu::Queue) {
+        self.pointer_released = false;
+        self.num_vertices = self.vertices.len() as u32;
+        if self.vertices.is_empty() {
+            return;
+        }
+
+        queue.write_buffer(
+            &self.uniform_buffer, 
+            0, 
+            bytemuck::bytes_of(&HudUniformLayout {
+                screen_size: self.screen_size, 
+                ..Default::default()
+            })
+        );
+
+        let size = mem::size_of_val(self.vertices.as_slice()) as wgpu::BufferAddress;
+        let capacity = self.buffer.as_ref().map_or(0, |buffer| buffer.size());
+        if capacity < size {
+            self.buffer = Some(device.create_buffer(
+                &wgpu::BufferDescriptor {
+                    label: Some("VertexBuffer(Hud)"), 
+                    mapped_at_creation: false, 
+                    size: size.next_power_of_two(), 
+                    usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST, 
+                }, 
+            ));
+        }
+
+        if let Some(buffer) = self.buffer.as_ref() {
+            queue.write_buffer(buffer, 0, bytemuck::cast_slice(&self.vertices));
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 마지막으로 기록된 요소들을 그립니다. </br>
+    /// 오버레이 파이프라인이 미리 설정되어 있어야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Draws the most recently uploaded elements. </br>
+    /// The overlay pipeline must be set beforehand. </br>
+    /// 
+    pub fn draw<'a>(&'a self, rpass: &mut wgpu::RenderPass<'a>, index: u32) {
+        if let Some(buffer) = self.buffer.as_ref().filter(|_| self.num_vertices > 0) {
+            rpass.set_bind_group(index, &self.bind_group, &[]);
+            rpass.set_vertex_buffer(0, buffer.slice(..));
+            rpass.draw(0..self.num_vertices, 0..1);
+        }
+    }
+}
@@ -0,0 +1,332 @@
+use std::any::TypeId;
+
+use crate::render::shader::Shader;
+use crate::render::vertex::VertexFormat;
+
+
+
+/// #### 한국어 </br>
+/// 재질의 블렌드 방식입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The blend mode of the material. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BlendMode {
+    Opaque, 
+    Alpha, 
+    Additive, 
+    WeightedBlendedOIT, 
+}
+
+impl BlendMode {
+    /// #### 한국어 </br>
+    /// 블렌드 방식에 맞는 렌더 타겟들의 상태를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the states of render targets that match the blend mode. </br>
+    /// 
+    pub fn color_targets(&self, format: wgpu::TextureFormat) -> Vec<Option<wgpu::ColorTargetState>> {
+        match self {
+            Self::Opaque => vec![
+                Some(wgpu::ColorTargetState {
+                    format, 
+                    blend: None, 
+                    write_mask: wgpu::ColorWrites::ALL, 
+                }), 
+            ], 
+            Self::Alpha => vec![
+                Some(wgpu::ColorTargetState {
+                    format, 
+                    blend: Some(wgpu::BlendState::ALPHA_BLENDING), 
+                    write_mask: wgpu::ColorWrites::ALL, 
+                }), 
+            ], 
+            Self::Additive => vec![
+                Some(wgpu::ColorTargetState {
+                    format, 
+                    blend: Some(wgpu::BlendState {
+                        color: wgpu::BlendComponent {
+                            src_factor: wgpu::BlendFactor::SrcAlpha, 
+                            dst_factor: wgpu::BlendFactor::One, 
+                            operation: wgpu::BlendOperation::Add, 
+                        }, 
+                        alpha: wgpu::BlendComponent {
+                            src_factor: wgpu::BlendFactor::One, 
+                            dst_factor: wgpu::BlendFactor::One, 
+                            operation: wgpu::BlendOperation::Add, 
+                        }, 
+                    }), 
+                    write_mask: wgpu::ColorWrites::ALL, 
+                }), 
+            ], 
+            Self::WeightedBlendedOIT => vec![
+                // (한국어)
+                // 첫 번째 렌더 타겟: (RGB * 가중치, Alpha * 가중치)를 RGBA로 저장하하는 누적 값.
+                // 최소 `Rgba16Float`의 정밀도를 가져야 한다.
+                //
+                // (English Translation)
+                // First Render Target: Accumulated value (RGB * Weight, Alpha * Weight) stored as RGBA.
+                // It must have a precision of at least `Rgba16Float`.
+                //
+                Some(wgpu::ColorTargetState {
+                    format: wgpu::TextureFormat::Rgba16Float, 
+                    blend: Some(wgpu::BlendState {
+                        color: wgpu::BlendComponent {
+                            src_factor: wgpu::BlendFactor::One, 
+                            dst_factor: wgpu::BlendFactor::One, 
+                            operation: wgpu::BlendOperation::Add, 
+                        }, 
+                        alpha: wgpu::BlendComponent {
+                            src_factor: wgpu::BlendFactor::One, 
+                            dst_factor: wgpu::BlendFactor::One, 
+                            operation: wgpu::BlendOperation::Add, 
+                        }, 
+                    }), 
+                    write_mask: wgpu::ColorWrites::ALL, 
+                }), 
+                // (한국어)
+                // 두 번째 렌더 타겟: 이전의 색이 얼마만큼 노출이 될 수 있는지에 대한 노출 값.
+                // 최소 `R8`의 정밀도를 가져야 한다.
+                //
+                // (English Translation)
+                // Second Render Target: Revealage value of how much of the previous color can be exposed.
+                // It must have a precision of at least `R8`.
+                //
+                Some(wgpu::ColorTargetState {
+                    format: wgpu::TextureFormat::R8Unorm, 
+                    blend: Some(wgpu::BlendState {
+                        color: wgpu::BlendComponent {
+                            src_factor: wgpu::BlendFactor::Zero, 
+                            dst_factor: wgpu::BlendFactor::OneMinusSrc, 
+                            operation: wgpu::BlendOperation::Add, 
+                        }, 
+                        alpha: wgpu::BlendComponent {
+                            src_factor: wgpu::BlendFactor::Zero, 
+                            dst_factor: wgpu::BlendFactor::OneMinusSrc, 
+                            operation: wgpu::BlendOperation::Add, 
+                        }
+                    }), 
+                    write_mask: wgpu::ColorWrites::ALL, 
+                }), 
+            ], 
+        }
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 그래픽스 파이프라인을 생성하기 위한 재질의 서술입니다. </br>
+/// 같은 서술을 가진 재질은 같은 그래픽스 파이프라인을 공유합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A description of the material for creating a graphics pipeline. </br>
+/// Materials with the same description share the same graphics pipeline. </br>
+/// 
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Material {
+    pub label: String, 
+    pub vertex_shader: Shader, 
+    pub fragment_shader: Shader, 
+    pub vertex_layouts: Vec<wgpu::VertexBufferLayout<'static>>, 
+    pub vertex_format: Option<VertexFormat>, 
+    pub bind_group_layouts: Vec<TypeId>, 
+    pub topology: wgpu::PrimitiveTopology, 
+    pub front_face: wgpu::FrontFace, 
+    pub cull_mode: Option<wgpu::Face>, 
+    pub polygon_mode: wgpu::PolygonMode, 
+    pub blend_mode: BlendMode, 
+    pub depth_write_enabled: bool, 
+    pub depth_compare: wgpu::CompareFunction, 
+}
+
+impl From<Material> for MaterialBuilder {
+    #[inline]
+    fn from(value: Material) -> Self {
+        Self {
+            label: value.label, 
+            vertex_shader: value.vertex_shader, 
+            fragment_shader: value.fragment_shader, 
+            vertex_layouts: value.vertex_layouts, 
+            vertex_format: value.vertex_format, 
+            bind_group_layouts: value.bind_group_layouts, 
+            topology: value.topology, 
+            front_face: value.front_face, 
+            cull_mode: value.cull_mode, 
+            polygon_mode: value.polygon_mode, 
+            blend_mode: value.blend_mode, 
+            depth_write_enabled: value.depth_write_enabled, 
+            depth_compare: value.depth_compare, 
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl Material {
+    #[inline]
+    pub fn uses_shader(&self, name: &str) -> bool {
+        self.vertex_shader.name() == name || self.fragment_shader.name() == name
+    }
+
+    /// #### 한국어 </br>
+    /// 주어진 형식의 메쉬를 이 재질로 그릴 수 있는지 여부를 반환합니다. </br>
+    /// 메쉬는 재질의 버텍스 형식의 모든 속성을 가져야 하며, 버텍스 형식이 없는 재질은 모든 메쉬를 허용합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns whether a mesh of the given format can be drawn with this material. </br>
+    /// The mesh must have all attributes of the material's vertex format, and a material without a vertex format accepts every mesh. </br>
+    /// 
+    #[inline]
+    pub fn accepts(&self, format: VertexFormat) -> bool {
+        self.vertex_format.map_or(true, |required| format.contains_all(required))
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// `Material`을 생성하는 빌더입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The builder that generates the `Material`. </br>
+/// 
+#[derive(Debug, Clone)]
+pub struct MaterialBuilder {
+    pub label: String, 
+    pub vertex_shader: Shader, 
+    pub fragment_shader: Shader, 
+    pub vertex_layouts: Vec<wgpu::VertexBufferLayout<'static>>, 
+    pub vertex_format: Option<VertexFormat>, 
+    pub bind_group_layouts: Vec<TypeId>, 
+    pub topology: wgpu::PrimitiveTopology, 
+    pub front_face: wgpu::FrontFace, 
+    pub cull_mode: Option<wgpu::Face>, 
+    pub polygon_mode: wgpu::PolygonMode, 
+    pub blend_mode: BlendMode, 
+    pub depth_write_enabled: bool, 
+    pub depth_compare: wgpu::CompareFunction, 
+}
+
+#[allow(dead_code)]
+impl MaterialBuilder {
+    #[inline]
+    pub fn new(label: &str, vertex_shader: Shader, fragment_shader: Shader) -> Self {
+        Self {
+            label: label.to_string(), 
+            vertex_shader, 
+            fragment_shader, 
+            vertex_layouts: Vec::new(), 
+            vertex_format: None, 
+            bind_group_layouts: Vec::new(), 
+            topology: wgpu::PrimitiveTopology::TriangleList, 
+            front_face: wgpu::FrontFace::Ccw, 
+            cull_mode: Some(wgpu::Face::Back), 
+            polygon_mode: wgpu::PolygonMode::Fill, 
+            blend_mode: BlendMode::Opaque, 
+            depth_write_enabled: true, 
+            depth_compare: wgpu::CompareFunction::Less, 
+        }
+    }
+
+    #[inline]
+    pub fn add_vertex_layout(mut self, layout: wgpu::VertexBufferLayout<'static>) -> Self {
+        self.vertex_layouts.push(layout);
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 메쉬의 버텍스 형식을 설정하고, 버텍스 레이아웃을 그 형식의 레이아웃으로 교체합니다. </br>
+    /// 셰이더는 형식에 있는 속성만 `VertexAttribute::shader_location`의 위치에서 읽어야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the vertex format of meshes, and replaces the vertex layouts with the layout of that format. </br>
+    /// The shader must only read attributes in the format, at the locations of `VertexAttribute::shader_location`. </br>
+    /// 
+    #[inline]
+    pub fn set_vertex_format(mut self, format: VertexFormat) -> Self {
+        self.vertex_layouts = vec![format.buffer_layout()];
+        self.vertex_format = Some(format);
+        self
+    }
+
+    #[inline]
+    pub fn add_bind_group_layout<T: 'static>(mut self) -> Self {
+        self.bind_group_layouts.push(TypeId::of::<T>());
+        self
+    }
+
+    #[inline]
+    pub fn set_topology(mut self, topology: wgpu::PrimitiveTopology) -> Self {
+        self.topology = topology;
+        self
+    }
+
+    #[inline]
+    pub fn set_front_face(mut self, front_face: wgpu::FrontFace) -> Self {
+        self.front_face = front_face;
+        self
+    }
+
+    #[inline]
+    pub fn set_cull_mode(mut self, cull_mode: Option<wgpu::Face>) -> Self {
+        self.cull_mode = cull_mode;
+        self
+    }
+
+    #[inline]
+    pub fn set_polygon_mode(mut self, polygon_mode: wgpu::PolygonMode) -> Self {
+        self.polygon_mode = polygon_mode;
+        self
+    }
+
+    #[inline]
+    pub fn set_blend_mode(mut self, blend_mode: BlendMode) -> Self {
+        self.blend_mode = blend_mode;
+        self
+    }
+
+    #[inline]
+    pub fn set_depth_write_enabled(mut self, depth_write_enabled: bool) -> Self {
+        self.depth_write_enabled = depth_write_enabled;
+        self
+    }
+
+    #[inline]
+    pub fn set_depth_compare(mut self, depth_compare: wgpu::CompareFunction) -> Self {
+        self.depth_compare = depth_compare;
+        self
+    }
+
+    #[inline]
+    pub fn build(self) -> Material {
+        Material {
+            label: self.label, 
+            vertex_shader: self.vertex_shader, 
+            fragment_shader: self.fragment_shader, 
+            vertex_layouts: self.vertex_layouts, 
+            vertex_format: self.vertex_format, 
+            bind_group_layouts: self.bind_group_layouts, 
+            topology: self.topology, 
+            front_face: self.front_face, 
+            cull_mode: self.cull_mode, 
+            polygon_mode: self.polygon_mode, 
+            blend_mode: self.blend_mode, 
+            depth_write_enabled: self.depth_write_enabled, 
+            depth_compare: self.depth_compare, 
+        }
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// `PipelineCache`에 등록된 재질을 가리키는 핸들입니다. </br>
+/// 엔티티는 이 핸들을 컴포넌트로 가지고 그려질 재질을 참조합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A handle pointing to a material registered in the `PipelineCache`. </br>
+/// Entities have this handle as a component to refer to the material to be drawn with. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct MaterialHandle(pub(crate) usize);
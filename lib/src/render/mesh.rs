@@ -1,34 +1,43 @@
-use std::mem;
 use std::hash;
 use std::ops::Range;
 use std::sync::Arc;
+use std::sync::OnceLock;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::path::Path;
 use bytemuck::Pod;
 use bytemuck::Zeroable;
 
-pub const VERTEX_BUFFER_LAYOUT: wgpu::VertexBufferLayout = wgpu::VertexBufferLayout {
-    array_stride: mem::size_of::<VertexInputLayout>() as wgpu::BufferAddress, 
-    step_mode: wgpu::VertexStepMode::Vertex, 
-    attributes: &[
-        wgpu::VertexAttribute {
-            format: wgpu::VertexFormat::Float32x3, 
-            offset: 0x00 as wgpu::BufferAddress, 
-            shader_location: 0, 
-        }, 
-        wgpu::VertexAttribute {
-            format: wgpu::VertexFormat::Float32x3, 
-            offset: 0x0C as wgpu::BufferAddress, 
-            shader_location: 1, 
-        }, 
-    ],
-};
+use crate::asset::Asset;
+use crate::asset::AssetError;
+use crate::render::gltf;
+use crate::render::obj;
+use crate::render::mesh_data::MeshData;
+use crate::render::vertex::VertexFormat;
 
 
 
 /// #### 한국어 </br>
-/// 메쉬의 버텍스 입력 레이아웃 입니다. </br>
+/// 다음에 생성될 메쉬의 식별자입니다. </br>
 /// 
 /// #### English (Translation) </br>
-/// The vertex input layout for a mesh. </br>
+/// The identifier of the next mesh to be created. </br>
+/// 
+static NEXT_MESH_ID: AtomicU64 = AtomicU64::new(0);
+
+/// #### 한국어 </br>
+/// 갱신 가능한 버퍼의 사용 용도입니다. 버퍼가 커질 때 기존 내용을 복사하기 위해 `COPY_SRC`가 필요합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The usages of an updatable buffer. `COPY_SRC` is required to copy the existing contents when the buffer grows. </br>
+/// 
+const UPDATABLE_USAGES: wgpu::BufferUsages = wgpu::BufferUsages::COPY_DST.union(wgpu::BufferUsages::COPY_SRC);
+
+/// #### 한국어 </br>
+/// 메쉬의 버텍스 입력 레이아웃 입니다. `VertexFormat::POSITION_NORMAL` 형식과 같은 메모리 배치를 가집니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The vertex input layout for a mesh. Has the same memory layout as the `VertexFormat::POSITION_NORMAL` format. </br>
 /// 
 #[repr(C)]
 #[derive(Pod, Zeroable)]
@@ -48,6 +57,36 @@ impl Default for VertexInputLayout {
     }
 }
 
+/// #### 한국어 </br>
+/// 스키닝되는 메쉬의 버텍스 입력 레이아웃 입니다. `VertexFormat::POSITION_NORMAL_SKIN` 형식과 같은 메모리 배치를 가집니다. </br>
+/// 각 버텍스는 최대 네 개의 관절(`joints`)에 가중치(`weights`)만큼 영향을 받습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The vertex input layout for a skinned mesh. Has the same memory layout as the `VertexFormat::POSITION_NORMAL_SKIN` format. </br>
+/// Each vertex is influenced by up to four joints (`joints`) by their weights (`weights`). </br>
+/// 
+#[repr(C)]
+#[derive(Pod, Zeroable)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SkinnedVertexInputLayout {
+    pub position: glam::Vec3, 
+    pub normal: glam::Vec3, 
+    pub joints: [u16; 4], 
+    pub weights: glam::Vec4, 
+}
+
+impl Default for SkinnedVertexInputLayout {
+    #[inline]
+    fn default() -> Self {
+        Self { 
+            position: glam::Vec3::ZERO, 
+            normal: glam::Vec3::ZERO, 
+            joints: [0; 4], 
+            weights: glam::Vec4::X, 
+        }
+    }
+}
+
 
 
 /// #### 한국어 </br>
@@ -59,60 +98,133 @@ impl Default for VertexInputLayout {
 #[derive(Debug, Clone)]
 pub struct IndexBuffer {
     num_indices: u32, 
+    capacity: u32, 
     buffer: Arc<wgpu::Buffer>, 
     format: wgpu::IndexFormat,
+    label: Arc<str>, 
 }
 
 #[allow(dead_code)]
 impl IndexBuffer {
+    #[inline]
     pub fn from_uint16_indices(
         label: Option<&str>, 
         indices: &[u16], 
         device: &wgpu::Device, 
         queue: &wgpu::Queue
     ) -> Self {
-        let label = format!("IndexBuffer({})", label.unwrap_or("Unknown"));
-
-        let buffer = device.create_buffer(
-            &wgpu::BufferDescriptor {
-                label: Some(&label), 
-                mapped_at_creation: false, 
-                size: mem::size_of_val(indices) as wgpu::BufferAddress, 
-                usage: wgpu::BufferUsages::INDEX | wgpu::BufferUsages::COPY_DST, 
-            }, 
-        );
-        queue.write_buffer(&buffer, 0, bytemuck::cast_slice(indices));
-
-        return Self { 
-            num_indices: indices.len() as u32, 
-            buffer: buffer.into(), 
-            format: wgpu::IndexFormat::Uint16 
-        };
+        Self::from_bytes(label, bytemuck::cast_slice(indices), wgpu::IndexFormat::Uint16, device, queue)
     }
 
+    #[inline]
     pub fn from_uint32_indices(
         label: Option<&str>, 
         indices: &[u32], 
         device: &wgpu::Device, 
         queue: &wgpu::Queue
     ) -> Self {
-        let label = format!("IndexBuffer({})", label.unwrap_or("Unknown"));
-
-        let buffer = device.create_buffer(
-            &wgpu::BufferDescriptor {
-                label: Some(&label), 
-                mapped_at_creation: false, 
-                size: mem::size_of_val(indices) as wgpu::BufferAddress, 
-                usage: wgpu::BufferUsages::INDEX | wgpu::BufferUsages::COPY_DST, 
-            }, 
-        );
-        queue.write_buffer(&buffer, 0, bytemuck::cast_slice(indices));
+        Self::from_bytes(label, bytemuck::cast_slice(indices), wgpu::IndexFormat::Uint32, device, queue)
+    }
 
-        return Self { 
-            num_indices: indices.len() as u32, 
+    /// #### 한국어 </br>
+    /// 주어진 수의 인덱스를 담을 수 있는 빈 32비트 인덱스 버퍼를 생성합니다. </br>
+    /// 갱신 가능한 인덱스 버퍼는 임의의 범위를 4바이트 단위로 쓸 수 있도록 항상 32비트 인덱스를 사용합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates an empty 32-bit index buffer that can hold the given number of indices. </br>
+    /// Updatable index buffers always use 32-bit indices so that any range can be written in 4-byte units. </br>
+    /// 
+    pub fn with_capacity(label: Option<&str>, capacity: u32, device: &wgpu::Device) -> Self {
+        let label: Arc<str> = format!("IndexBuffer({})", label.unwrap_or("Unknown")).into();
+        let capacity = capacity.max(1);
+        let buffer = create_buffer(&label, capacity as u64 * 4, wgpu::BufferUsages::INDEX, device);
+        Self { 
+            num_indices: 0, 
+            capacity, 
             buffer: buffer.into(), 
-            format: wgpu::IndexFormat::Uint32 
-        };
+            format: wgpu::IndexFormat::Uint32, 
+            label, 
+        }
+    }
+
+    fn from_bytes(
+        label: Option<&str>, 
+        bytes: &[u8], 
+        format: wgpu::IndexFormat, 
+        device: &wgpu::Device, 
+        queue: &wgpu::Queue
+    ) -> Self {
+        let label: Arc<str> = format!("IndexBuffer({})", label.unwrap_or("Unknown")).into();
+
+        // (한국어) 버퍼 복사는 4바이트 단위여야 하므로 홀수 개의 16비트 인덱스는 크기를 올림합니다.
+        // (English Translation) Buffer copies must be in 4-byte units, so the size of an odd number of 16-bit indices is rounded up.
+        let size = (bytes.len() as u64).next_multiple_of(wgpu::COPY_BUFFER_ALIGNMENT);
+        let buffer = create_buffer(&label, size, wgpu::BufferUsages::INDEX, device);
+        let mut padded = bytes.to_vec();
+        padded.resize(size as usize, 0);
+        queue.write_buffer(&buffer, 0, &padded);
+
+        let num_indices = (bytes.len() as u64 / index_size(format)) as u32;
+        Self { 
+            num_indices, 
+            capacity: num_indices, 
+            buffer: buffer.into(), 
+            format, 
+            label, 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// `offset`번째 인덱스부터 주어진 인덱스들을 씁니다. 용량이 부족하면 버퍼를 더 크게 다시 할당하고 `true`를 반환합니다. </br>
+    /// 인덱스의 수는 쓴 범위의 끝까지 늘어납니다. </br>
+    /// 16비트 인덱스 버퍼이거나 `offset`이 현재 인덱스의 수를 넘어서면 아무것도 쓰지 않고 오류를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Writes the given indices starting at the `offset`-th index. If the capacity is not enough, reallocates a larger buffer and returns `true`. </br>
+    /// The number of indices grows to the end of the written range. </br>
+    /// Returns an error without writing anything if it is a 16-bit index buffer or `offset` is past the current number of indices. </br>
+    /// 
+    pub fn write(&mut self, offset: u32, indices: &[u32], device: &wgpu::Device, queue: &wgpu::Queue) -> Result<bool, String> {
+        if self.format != wgpu::IndexFormat::Uint32 {
+            return Err(format!("{} has 16-bit indices and cannot be updated", self.label));
+        }
+        let end = write_range_end(offset, indices.len(), self.num_indices)
+            .map_err(|msg| format!("{}: {}", self.label, msg))?;
+
+        let reallocated = self.reserve(end, device, queue);
+        queue.write_buffer(&self.buffer, offset as u64 * 4, bytemuck::cast_slice(indices));
+        self.num_indices = self.num_indices.max(end);
+        Ok(reallocated)
+    }
+
+    /// #### 한국어 </br>
+    /// 적어도 `capacity`개의 인덱스를 담을 수 있도록 합니다. 다시 할당한 경우 기존 인덱스를 복사하고 `true`를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Ensures the buffer can hold at least `capacity` indices. If reallocated, copies the existing indices and returns `true`. </br>
+    /// 
+    pub fn reserve(&mut self, capacity: u32, device: &wgpu::Device, queue: &wgpu::Queue) -> bool {
+        if capacity <= self.capacity {
+            return false;
+        }
+
+        let capacity = capacity.max(self.capacity.saturating_mul(2));
+        let used = (self.num_indices as u64 * index_size(self.format)).next_multiple_of(wgpu::COPY_BUFFER_ALIGNMENT);
+        let size = (capacity as u64 * index_size(self.format)).next_multiple_of(wgpu::COPY_BUFFER_ALIGNMENT);
+        self.buffer = grow_buffer(&self.buffer, used, size, &self.label, wgpu::BufferUsages::INDEX, device, queue).into();
+        self.capacity = capacity;
+        true
+    }
+
+    /// #### 한국어 </br>
+    /// 인덱스의 수를 줄입니다. 버퍼의 용량은 그대로 유지됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Shortens the number of indices. The capacity of the buffer is kept. </br>
+    /// 
+    #[inline]
+    pub fn truncate(&mut self, num_indices: u32) {
+        self.num_indices = self.num_indices.min(num_indices);
     }
 
     #[inline]
@@ -124,6 +236,16 @@ impl IndexBuffer {
     pub fn num_indices(&self) -> u32 {
         self.num_indices
     }
+
+    #[inline]
+    pub fn capacity(&self) -> u32 {
+        self.capacity
+    }
+
+    #[inline]
+    pub fn format(&self) -> wgpu::IndexFormat {
+        self.format
+    }
 }
 
 impl Eq for IndexBuffer { }
@@ -145,39 +267,188 @@ impl hash::Hash for IndexBuffer {
 
 
 /// #### 한국어 </br>
-/// 3차원 모델 메쉬의 버텍스 버퍼입니다. </br>
+/// 3차원 모델 메쉬의 버텍스 버퍼입니다. 버퍼에 담긴 버텍스의 형식을 함께 기록합니다. </br>
 /// 
 /// #### English (Translation) </br>
-/// The vertex buffer of the 3D model mesh. </br>
+/// The vertex buffer of the 3D model mesh. Records the format of the vertices in the buffer as well. </br>
 /// 
 #[derive(Debug, Clone)]
 pub struct VertexBuffer {
     num_vertices: u32, 
+    capacity: u32, 
     buffer: Arc<wgpu::Buffer>, 
+    format: VertexFormat, 
+    label: Arc<str>, 
 }
 
+#[allow(dead_code)]
 impl VertexBuffer {
+    #[inline]
     pub fn from_vertices(
         label: Option<&str>, 
         vertices: &[VertexInputLayout], 
         device: &wgpu::Device, 
         queue: &wgpu::Queue
     ) -> Self {
-        let label = format!("VertexBuffer({})", label.unwrap_or("Unknown"));
-
-        let buffer = device.create_buffer(
-            &wgpu::BufferDescriptor {
-                label: Some(&label), 
-                mapped_at_creation: false, 
-                size: mem::size_of_val(vertices) as wgpu::BufferAddress, 
-                usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST, 
-            }, 
-        );
-        queue.write_buffer(&buffer, 0, bytemuck::cast_slice(vertices));
+        Self::from_bytes(label, bytemuck::cast_slice(vertices), VertexFormat::POSITION_NORMAL, device, queue)
+    }
+
+    #[inline]
+    pub fn from_skinned_vertices(
+        label: Option<&str>, 
+        vertices: &[SkinnedVertexInputLayout], 
+        device: &wgpu::Device, 
+        queue: &wgpu::Queue
+    ) -> Self {
+        Self::from_bytes(label, bytemuck::cast_slice(vertices), VertexFormat::POSITION_NORMAL_SKIN, device, queue)
+    }
+
+    /// #### 한국어 </br>
+    /// 주어진 형식으로 교차 배치된 버텍스 바이트들로 버텍스 버퍼를 생성합니다. </br>
+    /// 바이트의 길이는 형식의 버텍스 크기의 배수여야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates a vertex buffer from vertex bytes interleaved in the given format. </br>
+    /// The length of the bytes must be a multiple of the vertex size of the format. </br>
+    /// 
+    pub fn from_bytes(
+        label: Option<&str>, 
+        bytes: &[u8], 
+        format: VertexFormat, 
+        device: &wgpu::Device, 
+        queue: &wgpu::Queue
+    ) -> Self {
+        assert!(bytes.len() as u64 % format.stride() == 0, "the vertex bytes do not match the format {}", format);
+        let label: Arc<str> = format!("VertexBuffer({})", label.unwrap_or("Unknown")).into();
+
+        let buffer = create_buffer(&label, bytes.len() as u64, wgpu::BufferUsages::VERTEX, device);
+        queue.write_buffer(&buffer, 0, bytes);
+
+        let num_vertices = (bytes.len() as u64 / format.stride()) as u32;
+        Self {
+            num_vertices, 
+            capacity: num_vertices, 
+            buffer: buffer.into(), 
+            format, 
+            label, 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 주어진 형식의 버텍스를 주어진 수만큼 담을 수 있는 빈 버텍스 버퍼를 생성합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates an empty vertex buffer that can hold the given number of vertices of the given format. </br>
+    /// 
+    pub fn with_capacity(label: Option<&str>, format: VertexFormat, capacity: u32, device: &wgpu::Device) -> Self {
+        let label: Arc<str> = format!("VertexBuffer({})", label.unwrap_or("Unknown")).into();
+        let capacity = capacity.max(1);
+        let buffer = create_buffer(&label, capacity as u64 * format.stride(), wgpu::BufferUsages::VERTEX, device);
+        Self {
+            num_vertices: 0, 
+            capacity, 
+            buffer: buffer.into(), 
+            format, 
+            label, 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// `offset`번째 버텍스부터 버퍼의 형식으로 교차 배치된 버텍스 바이트들을 씁니다. </br>
+    /// 용량이 부족하면 버퍼를 더 크게 다시 할당하고 `true`를 반환합니다. 버텍스의 수는 쓴 범위의 끝까지 늘어납니다. </br>
+    /// 바이트의 길이가 형식과 맞지 않거나 `offset`이 현재 버텍스의 수를 넘어서면 아무것도 쓰지 않고 오류를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Writes vertex bytes interleaved in the format of the buffer, starting at the `offset`-th vertex. </br>
+    /// If the capacity is not enough, reallocates a larger buffer and returns `true`. The number of vertices grows to the end of the written range. </br>
+    /// Returns an error without writing anything if the length of the bytes does not match the format or `offset` is past the current number of vertices. </br>
+    /// 
+    pub fn write(&mut self, offset: u32, bytes: &[u8], device: &wgpu::Device, queue: &wgpu::Queue) -> Result<bool, String> {
+        let stride = self.format.stride();
+        if bytes.len() as u64 % stride != 0 {
+            return Err(format!("{}: the vertex bytes do not match the format {}", self.label, self.format));
+        }
+        let end = write_range_end(offset, (bytes.len() as u64 / stride) as usize, self.num_vertices)
+            .map_err(|msg| format!("{}: {}", self.label, msg))?;
+
+        let reallocated = self.reserve(end, device, queue);
+        queue.write_buffer(&self.buffer, offset as u64 * stride, bytes);
+        self.num_vertices = self.num_vertices.max(end);
+        Ok(reallocated)
+    }
 
-        return Self {
-            num_vertices: vertices.len() as u32, 
+    /// #### 한국어 </br>
+    /// 적어도 `capacity`개의 버텍스를 담을 수 있도록 합니다. 다시 할당한 경우 기존 버텍스를 복사하고 `true`를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Ensures the buffer can hold at least `capacity` vertices. If reallocated, copies the existing vertices and returns `true`. </br>
+    /// 
+    pub fn reserve(&mut self, capacity: u32, device: &wgpu::Device, queue: &wgpu::Queue) -> bool {
+        if capacity <= self.capacity {
+            return false;
+        }
+
+        let capacity = capacity.max(self.capacity.saturating_mul(2));
+        let stride = self.format.stride();
+        let used = self.num_vertices as u64 * stride;
+        self.buffer = grow_buffer(&self.buffer, used, capacity as u64 * stride, &self.label, wgpu::BufferUsages::VERTEX, device, queue).into();
+        self.capacity = capacity;
+        true
+    }
+
+    /// #### 한국어 </br>
+    /// 버텍스의 수를 줄입니다. 버퍼의 용량은 그대로 유지됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Shortens the number of vertices. The capacity of the buffer is kept. </br>
+    /// 
+    #[inline]
+    pub fn truncate(&mut self, num_vertices: u32) {
+        self.num_vertices = self.num_vertices.min(num_vertices);
+    }
+
+    /// #### 한국어 </br>
+    /// 인덱스 목록을 따라 이 버퍼의 버텍스들을 새로운 버텍스 버퍼로 복사합니다. </br>
+    /// 연속된 인덱스들은 하나의 복사 명령으로 묶이며, 복사 명령은 즉시 제출됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Copies the vertices of this buffer into a new vertex buffer along the list of indices. </br>
+    /// Consecutive indices are grouped into a single copy command, and the copy commands are submitted immediately. </br>
+    /// 
+    pub fn gather(&self, indices: &[u32], device: &wgpu::Device, queue: &wgpu::Queue) -> Self {
+        debug_assert!(indices.iter().all(|&index| index < self.num_vertices), "{}: an index is out of range", self.label);
+        let label: Arc<str> = format!("{}(Unindexed)", self.label).into();
+        let stride = self.format.stride();
+        let num_vertices = indices.len() as u32;
+        let capacity = num_vertices.max(1);
+        let buffer = create_buffer(&label, capacity as u64 * stride, wgpu::BufferUsages::VERTEX, device);
+
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("CommandEncoder(GatherVertices)"), 
+        });
+        let mut start = 0;
+        while start < indices.len() {
+            let mut end = start + 1;
+            while end < indices.len() && indices[end - 1].checked_add(1) == Some(indices[end]) {
+                end += 1;
+            }
+            encoder.copy_buffer_to_buffer(
+                &self.buffer, 
+                indices[start] as u64 * stride, 
+                &buffer, 
+                start as u64 * stride, 
+                (end - start) as u64 * stride
+            );
+            start = end;
+        }
+        queue.submit(Some(encoder.finish()));
+
+        Self {
+            num_vertices, 
+            capacity, 
             buffer: buffer.into(), 
+            format: self.format, 
+            label, 
         }
     }
 
@@ -190,6 +461,16 @@ impl VertexBuffer {
     pub fn num_vertices(&self) -> u32 {
         self.num_vertices
     }
+
+    #[inline]
+    pub fn capacity(&self) -> u32 {
+        self.capacity
+    }
+
+    #[inline]
+    pub fn format(&self) -> VertexFormat {
+        self.format
+    }
 }
 
 impl Eq for VertexBuffer { }
@@ -210,23 +491,45 @@ impl hash::Hash for VertexBuffer {
 
 
 
+/// #### 한국어 </br>
+/// 인덱스를 풀어 놓은 버텍스 버퍼와 그 버퍼를 처음 필요할 때 생성하기 위한 인덱스들입니다. </br>
+/// 복제된 메쉬들이 공유하므로 버퍼는 한 번만 생성됩니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The vertex buffer with its indices unrolled, and the indices to create that buffer when it is first needed. </br>
+/// Shared by cloned meshes, so the buffer is created only once. </br>
+/// 
+#[derive(Debug)]
+struct UnindexedVertices {
+    indices: Vec<u32>, 
+    vertex_buffer: OnceLock<VertexBuffer>, 
+}
+
 /// #### 한국어 </br>
 /// 3차원 모델 메쉬 입니다. </br>
+/// 메쉬의 동일성은 생성될 때 부여된 식별자로만 결정되며, 복제된 메쉬는 같은 식별자를 공유합니다. </br>
+/// 복제본들이 항상 같은 버퍼를 가리키도록 내용을 바꿀 수 없으며, 매 프레임 갱신하는 메쉬는 `DynamicMesh`를 사용합니다. </br>
 /// 
 /// #### English (Translation) </br>
 /// The 3D model mesh. </br>
+/// The identity of a mesh is determined only by the identifier given on creation, and cloned meshes share the same identifier. </br>
+/// Its contents cannot be changed, so that clones always point to the same buffers. Meshes updated every frame use `DynamicMesh`. </br>
 /// 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone)]
 pub struct ModelMesh {
+    id: u64, 
     vertex_buffer: VertexBuffer, 
     index_buffer: Option<IndexBuffer>, 
+    unindexed_vertices: Option<Arc<UnindexedVertices>>, 
+    mesh_data: Option<Arc<MeshData>>, 
+    draw_count: Option<u32>, 
 }
 
 #[allow(dead_code)]
 impl ModelMesh {
     #[inline]
     pub fn new(vertex_buffer: VertexBuffer) -> Self {
-        Self { vertex_buffer, index_buffer: None }
+        Self::with_buffers(vertex_buffer, None)
     }
 
     #[inline]
@@ -234,7 +537,117 @@ impl ModelMesh {
         vertex_buffer: VertexBuffer, 
         index_buffer: IndexBuffer
     ) -> Self {
-        Self { vertex_buffer, index_buffer: Some(index_buffer) }
+        Self::with_buffers(vertex_buffer, Some(index_buffer))
+    }
+
+    fn with_buffers(vertex_buffer: VertexBuffer, index_buffer: Option<IndexBuffer>) -> Self {
+        Self { 
+            id: NEXT_MESH_ID.fetch_add(1, Ordering::Relaxed), 
+            vertex_buffer, 
+            index_buffer, 
+            unindexed_vertices: None, 
+            mesh_data: None, 
+            draw_count: None, 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 인덱스를 풀어 놓은 버텍스 버퍼를 만들 인덱스들을 설정합니다. 버퍼는 `prepare_unindexed`에서 처음 필요할 때 생성됩니다. </br>
+    /// 각 삼각형이 고유한 버텍스를 가져야 하는 그리기(예: 무게중심 좌표 와이어프레임)에 사용됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the indices to build the vertex buffer with its indices unrolled. The buffer is created in `prepare_unindexed` when it is first needed. </br>
+    /// Used for drawing where each triangle must have unique vertices (e.g. barycentric wireframe). </br>
+    /// 
+    #[inline]
+    pub fn with_unindexed_vertices(mut self, indices: Vec<u32>) -> Self {
+        self.unindexed_vertices = Some(Arc::new(UnindexedVertices { indices, vertex_buffer: OnceLock::new() }));
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 인덱스를 풀어 놓은 버텍스 버퍼가 아직 없다면 생성합니다. `draw_unindexed` 전에 호출해야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates the vertex buffer with its indices unrolled if it does not exist yet. Must be called before `draw_unindexed`. </br>
+    /// 
+    pub fn prepare_unindexed(&self, device: &wgpu::Device, queue: &wgpu::Queue) {
+        if let Some(unindexed) = &self.unindexed_vertices {
+            unindexed.vertex_buffer.get_or_init(|| self.vertex_buffer.gather(&unindexed.indices, device, queue));
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 메쉬와 함께 보관할 CPU 측 메쉬 데이터를 설정합니다. </br>
+    /// 피킹이나 충돌 처리처럼 GPU에 올린 후에도 버텍스가 필요한 경우에 사용됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the CPU-side mesh data to keep together with the mesh. </br>
+    /// Used when the vertices are needed even after uploading to the GPU, such as for picking or collision. </br>
+    /// 
+    #[inline]
+    pub fn with_mesh_data(mut self, mesh_data: Arc<MeshData>) -> Self {
+        self.mesh_data = Some(mesh_data);
+        self
+    }
+
+    #[inline]
+    pub fn mesh_data(&self) -> Option<&Arc<MeshData>> {
+        self.mesh_data.as_ref()
+    }
+
+    /// #### 한국어 </br>
+    /// 메쉬가 생성될 때 부여된 식별자를 반환합니다. 복제된 메쉬는 같은 식별자를 가집니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the identifier given when the mesh was created. Cloned meshes have the same identifier. </br>
+    /// 
+    #[inline]
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// #### 한국어 </br>
+    /// 메쉬의 버텍스 형식을 반환합니다. 이 메쉬를 그리는 파이프라인은 같은 형식의 버텍스 레이아웃을 가져야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the vertex format of the mesh. A pipeline drawing this mesh must have a vertex layout of the same format. </br>
+    /// 
+    #[inline]
+    pub fn format(&self) -> VertexFormat {
+        self.vertex_buffer.format()
+    }
+
+    #[inline]
+    pub fn vertex_buffer(&self) -> &VertexBuffer {
+        &self.vertex_buffer
+    }
+
+    #[inline]
+    pub fn index_buffer(&self) -> Option<&IndexBuffer> {
+        self.index_buffer.as_ref()
+    }
+
+    /// #### 한국어 </br>
+    /// 그릴 버텍스(인덱스 버퍼가 있다면 인덱스)의 수를 제한합니다. `None`이면 버퍼의 모든 내용을 그립니다. </br>
+    /// 버퍼의 길이보다 큰 값은 버퍼의 길이로 제한됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Limits the number of vertices (indices if there is an index buffer) to draw. If `None`, draws the entire contents of the buffer. </br>
+    /// Values larger than the length of the buffer are clamped to the length of the buffer. </br>
+    /// 
+    #[inline]
+    pub fn set_draw_count(&mut self, draw_count: Option<u32>) {
+        self.draw_count = draw_count;
+    }
+
+    #[inline]
+    pub fn draw_count(&self) -> u32 {
+        let len = match &self.index_buffer {
+            Some(index_buffer) => index_buffer.num_indices(), 
+            None => self.vertex_buffer.num_vertices(), 
+        };
+        self.draw_count.map_or(len, |count| count.min(len))
     }
 
     #[inline]
@@ -246,12 +659,270 @@ impl ModelMesh {
     }
 
     pub fn draw<'a>(&'a self, rpass: &mut wgpu::RenderPass<'a>, instances: Range<u32>) {
-        if let Some(index_buffer) = &self.index_buffer {
-            rpass.draw_indexed(0..index_buffer.num_indices(), 0, instances);
-        } else {
-            rpass.draw(0..self.vertex_buffer.num_vertices(), instances);
+        match &self.index_buffer {
+            Some(_) => rpass.draw_indexed(0..self.draw_count(), 0, instances), 
+            None => rpass.draw(0..self.draw_count(), instances), 
         }
     }
+
+    /// #### 한국어 </br>
+    /// 인덱스 버퍼 없이 메쉬를 바인드하고 그립니다. </br>
+    /// 인덱스를 풀어 놓은 버텍스 버퍼가 준비되지 않은 경우, 인덱스 버퍼가 없는 메쉬만 그려집니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Binds and draws the mesh without an index buffer. </br>
+    /// If the unindexed vertex buffer has not been prepared, only meshes without an index buffer are drawn. </br>
+    /// 
+    pub fn draw_unindexed<'a>(&'a self, rpass: &mut wgpu::RenderPass<'a>, slot: u32, instances: Range<u32>) {
+        let unindexed = self.unindexed_vertices.as_ref().and_then(|unindexed| unindexed.vertex_buffer.get());
+        let vertex_buffer = match (unindexed, &self.index_buffer) {
+            (Some(vertex_buffer), _) => vertex_buffer, 
+            (None, None) => &self.vertex_buffer, 
+            (None, Some(_)) => return, 
+        };
+        vertex_buffer.bind(rpass, slot);
+        rpass.draw(0..vertex_buffer.num_vertices(), instances);
+    }
+}
+
+impl Eq for ModelMesh { }
+
+impl PartialEq<Self> for ModelMesh {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl hash::Hash for ModelMesh {
+    #[inline]
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+
+/// #### 한국어 </br>
+/// 메쉬 파일을 작업 스레드에서 디코딩하고 메인 스레드에서 업로드합니다. </br>
+/// 현재는 `Wavefront OBJ`(`.obj`) 형식을 지원합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Decodes a mesh file on a worker thread and uploads it on the main thread. </br>
+/// Currently supports the `Wavefront OBJ` (`.obj`) format. </br>
+/// 
+impl Asset for ModelMesh {
+    type Data = MeshData;
+
+    fn decode(path: &Path, bytes: &[u8]) -> Result<Self::Data, AssetError> {
+        let extension = path.extension().and_then(|ext| ext.to_str());
+        match extension {
+            Some("obj") => {
+                let text = std::str::from_utf8(bytes)
+                    .map_err(|err| AssetError::Decode(path.to_path_buf(), err.to_string()))?;
+                obj::parse(text)
+                    .map_err(|msg| AssetError::Decode(path.to_path_buf(), msg))
+            }, 
+            Some("gltf" | "glb") => {
+                // (한국어) 모든 메쉬를 하나로 합칩니다. 골격과 애니메이션이 필요하다면 `GltfModel`을 사용합니다.
+                // (English Translation) Merges all meshes into one. Use `GltfModel` if the skeleton and animations are needed.
+                gltf::parse(bytes)
+                    .map(|data| MeshData::merge(data.meshes.iter()))
+                    .map_err(|msg| AssetError::Decode(path.to_path_buf(), msg))
+            }, 
+            _ => Err(AssetError::Decode(path.to_path_buf(), "unsupported mesh format".to_string())), 
+        }
+    }
+
+    fn upload(data: Self::Data, device: &wgpu::Device, queue: &wgpu::Queue) -> Result<Self, AssetError> {
+        Ok(data.with_label("AssetMesh").upload(device, queue))
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 매 프레임 내용을 갱신할 수 있는 3차원 모델 메쉬입니다. </br>
+/// 쓰기는 버퍼를 다시 할당하거나 버텍스와 인덱스의 수를 바꾸므로, 복제본이 이전 상태를 가리키지 않도록 복제할 수 없습니다. </br>
+/// 그릴 때는 `mesh`로 현재 상태의 메쉬를 빌려 사용합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A 3D model mesh whose contents can be updated every frame. </br>
+/// Writes may reallocate its buffers or change the number of vertices and indices, so it cannot be cloned, which keeps copies from pointing to a previous state. </br>
+/// When drawing, borrow the mesh in its current state with `mesh`. </br>
+/// 
+#[derive(Debug)]
+pub struct DynamicMesh {
+    mesh: ModelMesh, 
+}
+
+#[allow(dead_code)]
+impl DynamicMesh {
+    /// #### 한국어 </br>
+    /// 매 프레임 갱신할 수 있는 빈 메쉬를 생성합니다. </br>
+    /// `index_capacity`가 주어지면 32비트 인덱스 버퍼를 함께 생성합니다. 용량은 쓰기에 따라 자동으로 늘어납니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates an empty mesh that can be updated every frame. </br>
+    /// If `index_capacity` is given, a 32-bit index buffer is created as well. The capacities grow automatically on writes. </br>
+    /// 
+    pub fn new(
+        label: Option<&str>, 
+        format: VertexFormat, 
+        vertex_capacity: u32, 
+        index_capacity: Option<u32>, 
+        device: &wgpu::Device
+    ) -> Self {
+        let vertex_buffer = VertexBuffer::with_capacity(label, format, vertex_capacity, device);
+        let index_buffer = index_capacity.map(|capacity| IndexBuffer::with_capacity(label, capacity, device));
+        Self { mesh: ModelMesh::with_buffers(vertex_buffer, index_buffer) }
+    }
+
+    #[inline]
+    pub fn mesh(&self) -> &ModelMesh {
+        &self.mesh
+    }
+
+    #[inline]
+    pub fn format(&self) -> VertexFormat {
+        self.mesh.format()
+    }
+
+    #[inline]
+    pub fn index_buffer(&self) -> Option<&IndexBuffer> {
+        self.mesh.index_buffer()
+    }
+
+    /// #### 한국어 </br>
+    /// `offset`번째 버텍스부터 메쉬의 형식으로 교차 배치된 버텍스 바이트들을 씁니다. 버퍼가 다시 할당되었다면 `true`를 반환합니다. </br>
+    /// 내용이 바뀌므로 인덱스를 풀어 놓은 버텍스 버퍼와 CPU 측 메쉬 데이터는 제거됩니다. </br>
+    /// 오류가 발생한 경우 메쉬는 바뀌지 않습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Writes vertex bytes interleaved in the format of the mesh, starting at the `offset`-th vertex. Returns `true` if the buffer was reallocated. </br>
+    /// Since the contents change, the unindexed vertex buffer and the CPU-side mesh data are removed. </br>
+    /// If an error occurs, the mesh is left unchanged. </br>
+    /// 
+    pub fn write_vertices(&mut self, offset: u32, bytes: &[u8], device: &wgpu::Device, queue: &wgpu::Queue) -> Result<bool, String> {
+        let reallocated = self.mesh.vertex_buffer.write(offset, bytes, device, queue)?;
+        self.mesh.unindexed_vertices = None;
+        self.mesh.mesh_data = None;
+        Ok(reallocated)
+    }
+
+    /// #### 한국어 </br>
+    /// `offset`번째 인덱스부터 인덱스들을 씁니다. 버퍼가 다시 할당되었다면 `true`를 반환합니다. </br>
+    /// 메쉬에 32비트 인덱스 버퍼가 없다면 오류를 반환하며, 오류가 발생한 경우 메쉬는 바뀌지 않습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Writes indices starting at the `offset`-th index. Returns `true` if the buffer was reallocated. </br>
+    /// Returns an error if the mesh has no 32-bit index buffer, and if an error occurs, the mesh is left unchanged. </br>
+    /// 
+    pub fn write_indices(&mut self, offset: u32, indices: &[u32], device: &wgpu::Device, queue: &wgpu::Queue) -> Result<bool, String> {
+        let index_buffer = self.mesh.index_buffer.as_mut()
+            .ok_or_else(|| "the mesh has no index buffer".to_string())?;
+        let reallocated = index_buffer.write(offset, indices, device, queue)?;
+        self.mesh.unindexed_vertices = None;
+        self.mesh.mesh_data = None;
+        Ok(reallocated)
+    }
+
+    /// #### 한국어 </br>
+    /// 버텍스와 인덱스의 수를 줄입니다. 버퍼의 용량은 그대로 유지됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Shortens the number of vertices and indices. The capacities of the buffers are kept. </br>
+    /// 
+    pub fn truncate(&mut self, num_vertices: u32, num_indices: u32) {
+        self.mesh.vertex_buffer.truncate(num_vertices);
+        if let Some(index_buffer) = self.mesh.index_buffer.as_mut() {
+            index_buffer.truncate(num_indices);
+        }
+    }
+}
+
+
+
+
+/// #### 한국어 </br>
+/// `len`개의 요소를 `offset`부터 쓸 때의 범위의 끝을 반환합니다. </br>
+/// 쓰기가 현재 요소의 수(`num_elements`) 뒤에 빈 공간을 남기거나 범위가 `u32`를 넘어서면 오류를 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns the end of the range when writing `len` elements starting at `offset`. </br>
+/// Returns an error if the write would leave a gap after the current number of elements (`num_elements`) or the range exceeds `u32`. </br>
+/// 
+fn write_range_end(offset: u32, len: usize, num_elements: u32) -> Result<u32, String> {
+    if offset > num_elements {
+        return Err(format!("the write at {} would leave a gap after the existing {} elements", offset, num_elements));
+    }
+    u32::try_from(len).ok()
+        .and_then(|len| offset.checked_add(len))
+        .ok_or_else(|| format!("the write of {} elements at {} is out of range", len, offset))
+}
+
+#[inline]
+fn index_size(format: wgpu::IndexFormat) -> u64 {
+    match format {
+        wgpu::IndexFormat::Uint16 => 2, 
+        wgpu::IndexFormat::Uint32 => 4, 
+    }
+}
+
+/// #### 한국어 </br>
+/// 갱신 가능한 버퍼를 생성합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates an updatable buffer. </br>
+/// 
+fn create_buffer(label: &str, size: u64, usage: wgpu::BufferUsages, device: &wgpu::Device) -> wgpu::Buffer {
+    device.create_buffer(
+        &wgpu::BufferDescriptor {
+            label: Some(label), 
+            mapped_at_creation: false, 
+            size, 
+            usage: usage | UPDATABLE_USAGES, 
+        }, 
+    )
+}
+
+/// #### 한국어 </br>
+/// 더 큰 버퍼를 생성하고 기존 버퍼의 앞쪽 `used` 바이트를 복사합니다. </br>
+/// 복사 명령은 즉시 제출되므로, 이후의 `write_buffer`는 복사가 끝난 후에 적용됩니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates a larger buffer and copies the first `used` bytes of the old buffer. </br>
+/// The copy command is submitted immediately, so subsequent `write_buffer` calls are applied after the copy. </br>
+/// 
+fn grow_buffer(
+    old: &wgpu::Buffer, 
+    used: u64, 
+    size: u64, 
+    label: &str, 
+    usage: wgpu::BufferUsages, 
+    device: &wgpu::Device, 
+    queue: &wgpu::Queue
+) -> wgpu::Buffer {
+    let buffer = create_buffer(label, size, usage, device);
+    if used > 0 {
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("CommandEncoder(GrowBuffer)"), 
+        });
+        encoder.copy_buffer_to_buffer(old, 0, &buffer, 0, used);
+        queue.submit(Some(encoder.finish()));
+    }
+    buffer
+}
+
+/// #### 한국어 </br>
+/// 인덱스 목록을 따라 버텍스들을 풀어 놓습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Unrolls the vertices along the list of indices. </br>
+/// 
+pub fn unindex_vertices<T: Copy, I: Copy + Into<u32>>(vertices: &[T], indices: &[I]) -> Vec<T> {
+    indices.iter()
+        .map(|index| vertices[(*index).into() as usize])
+        .collect()
 }
 
 
@@ -276,24 +947,14 @@ pub fn create_plane_mesh(
 
     let hw = 0.5 * w;
     let hh = 0.5 * h;
-    let vertices = [
-        VertexInputLayout { position: (-hw,  0.0, -hh).into(), normal: ( 0.0,  1.0,  0.0).into(), ..Default::default() },
-        VertexInputLayout { position: (-hw,  0.0,  hh).into(), normal: ( 0.0,  1.0,  0.0).into(), ..Default::default() },
-        VertexInputLayout { position: ( hw,  0.0, -hh).into(), normal: ( 0.0,  1.0,  0.0).into(), ..Default::default() },
-
-        VertexInputLayout { position: ( hw,  0.0, -hh).into(), normal: ( 0.0,  1.0,  0.0).into(), ..Default::default() },
-        VertexInputLayout { position: (-hw,  0.0,  hh).into(), normal: ( 0.0,  1.0,  0.0).into(), ..Default::default() },
-        VertexInputLayout { position: ( hw,  0.0,  hh).into(), normal: ( 0.0,  1.0,  0.0).into(), ..Default::default() },
-    ];
-
-    let vertex_buffer = VertexBuffer::from_vertices(
-        Some("PlaneMesh"), 
-        &vertices, 
-        device, 
-        queue
-    );
-
-    return ModelMesh::new(vertex_buffer);
+    MeshData::new(
+        vec![(-hw, 0.0, -hh).into(), (-hw, 0.0, hh).into(), (hw, 0.0, hh).into(), (hw, 0.0, -hh).into()], 
+        vec![glam::Vec3::Y; 4], 
+        vec![0, 1, 2, 2, 3, 0]
+    )
+    .with_uvs(vec![(0.0, 0.0).into(), (0.0, 1.0).into(), (1.0, 1.0).into(), (1.0, 0.0).into()])
+    .with_label("PlaneMesh")
+    .upload(device, queue)
 }
 
 
@@ -346,30 +1007,33 @@ pub fn create_cube_mesh(
         VertexInputLayout { position: (-hx, -hy, -hz).into(), normal: ( 0.0, -1.0,  0.0).into() },
         VertexInputLayout { position: ( hx, -hy, -hz).into(), normal: ( 0.0, -1.0,  0.0).into() },
     ];
-    let vertex_buffer = VertexBuffer::from_vertices(
-        Some("CubeMesh"), 
-        &vertices, 
-        device, 
-        queue
-    );
-
-    let indices = [
-        0, 1, 2, 2, 3, 0,
-        4, 5, 6, 6, 7, 4, 
-        8, 9, 10, 10, 11, 8, 
-        12, 13, 14, 14, 15, 12, 
-        16, 17, 18, 18, 19, 16, 
-        20, 21, 22, 22, 23, 20, 
-    ];
-    let index_buffer = IndexBuffer::from_uint16_indices(
-        Some("CubeMesh"), 
-        &indices, 
-        device, 
-        queue
-    );
-
-    return ModelMesh::new_with_index_buffer(
-        vertex_buffer, 
-        index_buffer
-    );
-}
\ No newline at end of file
+
+    let indices = (0..6u32)
+        .flat_map(|face| [0, 1, 2, 2, 3, 0].map(|index| 4 * face + index))
+        .collect();
+    let uvs = (0..6)
+        .flat_map(|_| [(0.0, 1.0), (1.0, 1.0), (1.0, 0.0), (0.0, 0.0)].map(glam::Vec2::from))
+        .collect();
+    MeshData::from_vertices(&vertices, indices)
+        .with_uvs(uvs)
+        .with_label("CubeMesh")
+        .upload(device, queue)
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn write_range_must_not_leave_a_gap_or_overflow() {
+        assert_eq!(write_range_end(0, 6, 0), Ok(6));
+        assert_eq!(write_range_end(4, 6, 4), Ok(10));
+        assert_eq!(write_range_end(2, 1, 8), Ok(3));
+        assert_eq!(write_range_end(3, 0, 3), Ok(3));
+        assert!(write_range_end(5, 1, 4).is_err());
+        assert!(write_range_end(u32::MAX, 1, u32::MAX).is_err());
+        assert!(write_range_end(1, u32::MAX as usize, 1).is_err());
+    }
+}
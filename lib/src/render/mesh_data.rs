@@ -0,0 +1,991 @@
+use std::sync::Arc;
+use std::collections::HashMap;
+
+use crate::render::mesh::IndexBuffer;
+use crate::render::mesh::DynamicMesh;
+use crate::render::mesh::ModelMesh;
+use crate::render::mesh::VertexBuffer;
+use crate::render::mesh::VertexInputLayout;
+use crate::render::vertex::VertexAttribute;
+use crate::render::vertex::VertexFormat;
+
+
+
+/// #### 한국어 </br>
+/// 버텍스 캐시 최적화에서 가정하는 `post-transform` 캐시의 크기입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The size of the `post-transform` cache assumed by the vertex cache optimization. </br>
+/// 
+pub const VERTEX_CACHE_SIZE: usize = 32;
+
+const CACHE_DECAY_POWER: f32 = 1.5;
+const LAST_TRIANGLE_SCORE: f32 = 0.75;
+const VALENCE_BOOST_SCALE: f32 = 2.0;
+const VALENCE_BOOST_POWER: f32 = 0.5;
+
+
+
+/// #### 한국어 </br>
+/// 메쉬를 감싸는 축 정렬 경계 상자입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The axis-aligned bounding box enclosing a mesh. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bounds {
+    pub min: glam::Vec3, 
+    pub max: glam::Vec3, 
+}
+
+#[allow(dead_code)]
+impl Bounds {
+    #[inline]
+    pub fn center(&self) -> glam::Vec3 {
+        0.5 * (self.min + self.max)
+    }
+
+    #[inline]
+    pub fn size(&self) -> glam::Vec3 {
+        self.max - self.min
+    }
+
+    /// #### 한국어 </br>
+    /// 중심에서 꼭짓점까지의 거리로, 경계 상자를 감싸는 구의 반지름입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// The distance from the center to a corner, which is the radius of the sphere enclosing the box. </br>
+    /// 
+    #[inline]
+    pub fn radius(&self) -> f32 {
+        0.5 * self.size().length()
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// CPU 측 메쉬 데이터입니다. 버텍스 속성들은 각각의 배열로 보관되며, 모든 배열은 같은 길이를 가집니다. </br>
+/// 텍스처 좌표(`uvs`), 탄젠트(`tangents`), 색상(`colors`), 관절 인덱스(`joints`)와 가중치(`weights`)는 선택 사항이며, 없는 경우 빈 배열입니다. </br>
+/// 탄젠트의 `w`는 종법선의 방향(`±1`)입니다. </br>
+/// `with_retain(true)`로 설정하면 `upload`가 이 데이터의 복사본을 `ModelMesh`와 함께 보관합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// CPU-side mesh data. Vertex attributes are kept in separate arrays, all of which have the same length. </br>
+/// Texture coordinates (`uvs`), tangents (`tangents`), colors (`colors`), joint indices (`joints`) and weights (`weights`) are optional, and are empty arrays when absent. </br>
+/// The `w` of a tangent is the direction of the bitangent (`±1`). </br>
+/// When set with `with_retain(true)`, `upload` keeps a copy of this data together with the `ModelMesh`. </br>
+/// 
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct MeshData {
+    pub positions: Vec<glam::Vec3>, 
+    pub normals: Vec<glam::Vec3>, 
+    pub uvs: Vec<glam::Vec2>, 
+    pub tangents: Vec<glam::Vec4>, 
+    pub colors: Vec<glam::Vec4>, 
+    pub joints: Vec<[u16; 4]>, 
+    pub weights: Vec<glam::Vec4>, 
+    pub indices: Vec<u32>, 
+    label: Option<String>, 
+    retain: bool, 
+    vertex_format: Option<VertexFormat>, 
+}
+
+#[allow(dead_code)]
+impl MeshData {
+    #[inline]
+    pub fn new(positions: Vec<glam::Vec3>, normals: Vec<glam::Vec3>, indices: Vec<u32>) -> Self {
+        Self { positions, normals, indices, ..Default::default() }
+    }
+
+    /// #### 한국어 </br>
+    /// 버텍스 입력 레이아웃의 배열과 인덱스로 메쉬 데이터를 생성합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates mesh data from an array of vertex input layouts and indices. </br>
+    /// 
+    pub fn from_vertices(vertices: &[VertexInputLayout], indices: Vec<u32>) -> Self {
+        Self::new(
+            vertices.iter().map(|vertex| vertex.position).collect(), 
+            vertices.iter().map(|vertex| vertex.normal).collect(), 
+            indices
+        )
+    }
+
+    #[inline]
+    pub fn with_uvs(mut self, uvs: Vec<glam::Vec2>) -> Self {
+        self.uvs = uvs;
+        self
+    }
+
+    #[inline]
+    pub fn with_colors(mut self, colors: Vec<glam::Vec4>) -> Self {
+        self.colors = colors;
+        self
+    }
+
+    #[inline]
+    pub fn with_skin(mut self, joints: Vec<[u16; 4]>, weights: Vec<glam::Vec4>) -> Self {
+        self.joints = joints;
+        self.weights = weights;
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 업로드할 버텍스 형식을 제한합니다. 데이터에 없는 속성은 무시되며, 설정하지 않으면 데이터의 모든 속성이 업로드됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Restricts the vertex format to upload. Attributes absent from the data are ignored, and if not set, all attributes of the data are uploaded. </br>
+    /// 
+    #[inline]
+    pub fn with_vertex_format(mut self, format: VertexFormat) -> Self {
+        self.vertex_format = Some(format);
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 업로드된 GPU 버퍼들의 이름을 설정합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the label of the uploaded GPU buffers. </br>
+    /// 
+    #[inline]
+    pub fn with_label<T: Into<String>>(mut self, label: T) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// 업로드할 때 이 데이터의 복사본을 `ModelMesh`와 함께 보관할지 여부를 설정합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets whether a copy of this data is kept together with the `ModelMesh` when uploading. </br>
+    /// 
+    #[inline]
+    pub fn with_retain(mut self, retain: bool) -> Self {
+        self.retain = retain;
+        self
+    }
+
+    #[inline]
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_deref()
+    }
+
+    #[inline]
+    pub fn num_vertices(&self) -> usize {
+        self.positions.len()
+    }
+
+    #[inline]
+    pub fn num_triangles(&self) -> usize {
+        self.indices.len() / 3
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.indices.is_empty()
+    }
+
+    #[inline]
+    pub fn has_uvs(&self) -> bool {
+        !self.uvs.is_empty()
+    }
+
+    #[inline]
+    pub fn has_tangents(&self) -> bool {
+        !self.tangents.is_empty()
+    }
+
+    #[inline]
+    pub fn has_colors(&self) -> bool {
+        !self.colors.is_empty()
+    }
+
+    #[inline]
+    pub fn has_skin(&self) -> bool {
+        !self.joints.is_empty() && !self.weights.is_empty()
+    }
+
+    /// #### 한국어 </br>
+    /// 데이터가 가진 속성들의 버텍스 형식을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the vertex format of the attributes the data has. </br>
+    /// 
+    pub fn format(&self) -> VertexFormat {
+        let optional = [
+            (VertexAttribute::Normal, !self.normals.is_empty()), 
+            (VertexAttribute::Uv, self.has_uvs()), 
+            (VertexAttribute::Tangent, self.has_tangents()), 
+            (VertexAttribute::Color, self.has_colors()), 
+            (VertexAttribute::Joints, self.has_skin()), 
+            (VertexAttribute::Weights, self.has_skin()), 
+        ];
+        optional.into_iter()
+            .filter(|(_, present)| *present)
+            .fold(VertexFormat::POSITION, |format, (attribute, _)| format.with(attribute))
+    }
+
+    /// #### 한국어 </br>
+    /// `upload`가 사용할 버텍스 형식을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the vertex format `upload` will use. </br>
+    /// 
+    #[inline]
+    pub fn upload_format(&self) -> VertexFormat {
+        match self.vertex_format {
+            Some(format) => format.intersection(self.format()).with(VertexAttribute::Position), 
+            None => self.format(), 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 모든 인덱스를 16비트로 나타낼 수 있는지 여부를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns whether all indices can be represented in 16 bits. </br>
+    /// 
+    #[inline]
+    pub fn fits_uint16_indices(&self) -> bool {
+        self.num_vertices() <= u16::MAX as usize + 1
+    }
+
+    /// #### 한국어 </br>
+    /// 속성 배열들의 길이와 인덱스의 범위가 올바른지 검사합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Checks that the lengths of the attribute arrays and the range of the indices are valid. </br>
+    /// 
+    pub fn validate(&self) -> Result<(), String> {
+        let num_vertices = self.num_vertices();
+        if self.normals.len() != num_vertices {
+            return Err(format!("expected {} normals, found {}", num_vertices, self.normals.len()));
+        }
+        if self.has_uvs() && self.uvs.len() != num_vertices {
+            return Err(format!("expected {} uvs, found {}", num_vertices, self.uvs.len()));
+        }
+        if self.has_tangents() && self.tangents.len() != num_vertices {
+            return Err(format!("expected {} tangents, found {}", num_vertices, self.tangents.len()));
+        }
+        if self.has_colors() && self.colors.len() != num_vertices {
+            return Err(format!("expected {} colors, found {}", num_vertices, self.colors.len()));
+        }
+        if self.joints.len() != self.weights.len() {
+            return Err(format!("found {} joints but {} weights", self.joints.len(), self.weights.len()));
+        }
+        if self.has_skin() && self.joints.len() != num_vertices {
+            return Err(format!("expected {} joints, found {}", num_vertices, self.joints.len()));
+        }
+        if self.indices.len() % 3 != 0 {
+            return Err(format!("the number of indices {} is not a multiple of 3", self.indices.len()));
+        }
+        if let Some(index) = self.indices.iter().find(|&&index| index as usize >= num_vertices) {
+            return Err(format!("index {} is out of range for {} vertices", index, num_vertices));
+        }
+        Ok(())
+    }
+
+    /// #### 한국어 </br>
+    /// 위치와 법선을 버텍스 입력 레이아웃의 배열로 합칩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Interleaves the positions and normals into an array of vertex input layouts. </br>
+    /// 
+    pub fn vertices(&self) -> Vec<VertexInputLayout> {
+        self.positions.iter()
+            .zip(self.normals.iter())
+            .map(|(&position, &normal)| VertexInputLayout { position, normal })
+            .collect()
+    }
+
+    /// #### 한국어 </br>
+    /// 주어진 형식으로 버텍스 속성들을 교차 배치한 바이트들을 반환합니다. 데이터에 없는 속성이 형식에 있다면 오류를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the bytes of the vertex attributes interleaved in the given format. Returns an error if the format has an attribute absent from the data. </br>
+    /// 
+    pub fn vertex_bytes(&self, format: VertexFormat) -> Result<Vec<u8>, String> {
+        if !self.format().contains_all(format) {
+            return Err(format!("the mesh has {}, which does not contain {}", self.format(), format));
+        }
+
+        let stride = format.stride() as usize;
+        let mut bytes = vec![0u8; stride * self.num_vertices()];
+        for attribute in format.attributes() {
+            let offset = format.offset_of(attribute).unwrap_or_default() as usize;
+            let source: &[u8] = match attribute {
+                VertexAttribute::Position => bytemuck::cast_slice(&self.positions), 
+                VertexAttribute::Normal => bytemuck::cast_slice(&self.normals), 
+                VertexAttribute::Uv => bytemuck::cast_slice(&self.uvs), 
+                VertexAttribute::Tangent => bytemuck::cast_slice(&self.tangents), 
+                VertexAttribute::Color => bytemuck::cast_slice(&self.colors), 
+                VertexAttribute::Joints => bytemuck::cast_slice(&self.joints), 
+                VertexAttribute::Weights => bytemuck::cast_slice(&self.weights), 
+            };
+            let size = attribute.size() as usize;
+            for (vertex, value) in bytes.chunks_exact_mut(stride).zip(source.chunks_exact(size)) {
+                vertex[offset..offset + size].copy_from_slice(value);
+            }
+        }
+        Ok(bytes)
+    }
+
+    /// #### 한국어 </br>
+    /// 메쉬를 감싸는 경계 상자를 계산합니다. 버텍스가 없다면 `None`을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Computes the bounding box enclosing the mesh. Returns `None` if there are no vertices. </br>
+    /// 
+    pub fn bounds(&self) -> Option<Bounds> {
+        let first = *self.positions.first()?;
+        let bounds = self.positions.iter()
+            .fold(Bounds { min: first, max: first }, |bounds, &position| Bounds {
+                min: bounds.min.min(position), 
+                max: bounds.max.max(position), 
+            });
+        Some(bounds)
+    }
+
+    /// #### 한국어 </br>
+    /// 모든 삼각형이 고유한 버텍스를 가지도록 버텍스들을 풀어 놓고, 각 버텍스의 법선을 면 법선으로 설정합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Unrolls the vertices so that every triangle has unique vertices, and sets the normal of each vertex to the face normal. </br>
+    /// 
+    pub fn compute_flat_normals(&mut self) {
+        self.unweld();
+        for triangle in self.indices.chunks_exact(3) {
+            let [a, b, c] = [triangle[0], triangle[1], triangle[2]].map(|index| self.positions[index as usize]);
+            let normal = (b - a).cross(c - a).normalize_or_zero();
+            for &index in triangle {
+                self.normals[index as usize] = normal;
+            }
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 각 버텍스를 공유하는 면들의 법선을 넓이로 가중 평균하여 부드러운 법선을 계산합니다. </br>
+    /// 인덱스로 공유되는 버텍스만 평균되므로, 같은 위치의 분리된 버텍스들을 합치려면 먼저 `weld`를 호출해야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Computes smooth normals by averaging the normals of the faces sharing each vertex, weighted by area. </br>
+    /// Only vertices shared by index are averaged, so `weld` must be called first to merge separate vertices at the same position. </br>
+    /// 
+    pub fn compute_smooth_normals(&mut self) {
+        let mut normals = vec![glam::Vec3::ZERO; self.num_vertices()];
+        for triangle in self.indices.chunks_exact(3) {
+            let [a, b, c] = [triangle[0], triangle[1], triangle[2]].map(|index| self.positions[index as usize]);
+            let face_normal = (b - a).cross(c - a);
+            for &index in triangle {
+                normals[index as usize] += face_normal;
+            }
+        }
+        self.normals = normals.into_iter()
+            .map(|normal| normal.normalize_or_zero())
+            .collect();
+    }
+
+    /// #### 한국어 </br>
+    /// 위치, 법선, 텍스처 좌표로부터 탄젠트를 생성합니다. 텍스처 좌표가 없다면 오류를 반환합니다. </br>
+    /// 텍스처 좌표가 퇴화한 버텍스는 법선에 수직인 임의의 탄젠트를 가집니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Generates tangents from the positions, normals and texture coordinates. Returns an error if there are no texture coordinates. </br>
+    /// Vertices with degenerate texture coordinates get an arbitrary tangent perpendicular to the normal. </br>
+    /// 
+    pub fn generate_tangents(&mut self) -> Result<(), String> {
+        if !self.has_uvs() {
+            return Err("tangents require texture coordinates".to_string());
+        }
+        self.validate()?;
+
+        let mut tangents = vec![glam::Vec3::ZERO; self.num_vertices()];
+        let mut bitangents = vec![glam::Vec3::ZERO; self.num_vertices()];
+        for triangle in self.indices.chunks_exact(3) {
+            let [i0, i1, i2] = [triangle[0], triangle[1], triangle[2]].map(|index| index as usize);
+            let edge1 = self.positions[i1] - self.positions[i0];
+            let edge2 = self.positions[i2] - self.positions[i0];
+            let delta_uv1 = self.uvs[i1] - self.uvs[i0];
+            let delta_uv2 = self.uvs[i2] - self.uvs[i0];
+            let det = delta_uv1.x * delta_uv2.y - delta_uv2.x * delta_uv1.y;
+            if det.abs() <= f32::EPSILON {
+                continue;
+            }
+
+            let tangent = (edge1 * delta_uv2.y - edge2 * delta_uv1.y) / det;
+            let bitangent = (edge2 * delta_uv1.x - edge1 * delta_uv2.x) / det;
+            for index in [i0, i1, i2] {
+                tangents[index] += tangent;
+                bitangents[index] += bitangent;
+            }
+        }
+
+        // (한국어) 그람-슈미트 과정으로 탄젠트를 법선에 수직으로 만들고, 종법선의 방향을 `w`에 저장합니다.
+        // (English Translation) Makes the tangent perpendicular to the normal with the Gram-Schmidt process, and stores the direction of the bitangent in `w`.
+        self.tangents = self.normals.iter()
+            .zip(tangents.iter().zip(bitangents.iter()))
+            .map(|(&normal, (&tangent, &bitangent))| {
+                let mut tangent = (tangent - normal * normal.dot(tangent)).normalize_or_zero();
+                if tangent == glam::Vec3::ZERO {
+                    tangent = normal.any_orthonormal_vector();
+                }
+                let handedness = match normal.cross(tangent).dot(bitangent) < 0.0 {
+                    true => -1.0, 
+                    false => 1.0, 
+                };
+                tangent.extend(handedness)
+            })
+            .collect();
+        Ok(())
+    }
+
+    /// #### 한국어 </br>
+    /// 모든 속성이 `epsilon` 이내로 같은 버텍스들을 하나로 합치고, 그 결과 퇴화한 삼각형을 제거합니다. </br>
+    /// 제거된 버텍스의 수를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Merges vertices whose attributes are all equal within `epsilon` into one, and removes triangles that became degenerate as a result. </br>
+    /// Returns the number of removed vertices. </br>
+    /// 
+    pub fn weld(&mut self, epsilon: f32) -> usize {
+        assert!(epsilon > 0.0);
+
+        let quantize = |value: f32| (value / epsilon).round() as i64;
+        let mut unique: HashMap<([i64; 20], [u16; 4]), u32> = HashMap::with_capacity(self.num_vertices());
+        let mut remap = Vec::with_capacity(self.num_vertices());
+        let mut kept = Vec::new();
+        for index in 0..self.num_vertices() {
+            let position = self.positions[index];
+            let normal = self.normals[index];
+            let uv = self.uvs.get(index).copied().unwrap_or_default();
+            let tangent = self.tangents.get(index).copied().unwrap_or_default();
+            let color = self.colors.get(index).copied().unwrap_or_default();
+            let weight = self.weights.get(index).copied().unwrap_or_default();
+            let joint = self.joints.get(index).copied().unwrap_or_default();
+            let key = ([
+                position.x, position.y, position.z, 
+                normal.x, normal.y, normal.z, 
+                uv.x, uv.y, 
+                tangent.x, tangent.y, tangent.z, tangent.w, 
+                color.x, color.y, color.z, color.w, 
+                weight.x, weight.y, weight.z, weight.w, 
+            ].map(quantize), joint);
+
+            let new_index = *unique.entry(key).or_insert_with(|| {
+                kept.push(index);
+                (kept.len() - 1) as u32
+            });
+            remap.push(new_index);
+        }
+
+        let num_removed = self.num_vertices() - kept.len();
+        self.reorder_vertices(&kept);
+        self.indices = self.indices.chunks_exact(3)
+            .map(|triangle| [triangle[0], triangle[1], triangle[2]].map(|index| remap[index as usize]))
+            .filter(|[a, b, c]| a != b && b != c && c != a)
+            .flatten()
+            .collect();
+        num_removed
+    }
+
+    /// #### 한국어 </br>
+    /// 메쉬를 주어진 행렬로 변환합니다. 법선은 역전치 행렬로 변환되며, 행렬이 좌우를 뒤집는다면 삼각형의 감기 방향도 뒤집힙니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Transforms the mesh by the given matrix. Normals are transformed by the inverse transpose,
+    /// and if the matrix mirrors the mesh, the winding of the triangles is also flipped. </br>
+    /// 
+    pub fn transform(&mut self, matrix: glam::Mat4) {
+        let linear = glam::Mat3::from_mat4(matrix);
+        let normal_matrix = linear.inverse().transpose();
+        let is_mirrored = linear.determinant() < 0.0;
+
+        for position in self.positions.iter_mut() {
+            *position = matrix.transform_point3(*position);
+        }
+        for normal in self.normals.iter_mut() {
+            *normal = (normal_matrix * *normal).normalize_or_zero();
+        }
+        for tangent in self.tangents.iter_mut() {
+            let direction = (linear * tangent.truncate()).normalize_or_zero();
+            let handedness = match is_mirrored {
+                true => -tangent.w, 
+                false => tangent.w, 
+            };
+            *tangent = direction.extend(handedness);
+        }
+        if is_mirrored {
+            for triangle in self.indices.chunks_exact_mut(3) {
+                triangle.swap(1, 2);
+            }
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 다른 메쉬를 이 메쉬 뒤에 덧붙입니다. 두 메쉬 모두에 있는 선택 속성만 유지됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Appends another mesh after this mesh. Only optional attributes present in both meshes are kept. </br>
+    /// 
+    pub fn append(&mut self, other: &MeshData) {
+        fn append_stream<T: Copy>(target: &mut Vec<T>, source: &[T], is_first: bool) {
+            match (is_first, target.is_empty() || source.is_empty()) {
+                (true, _) => *target = source.to_vec(), 
+                (false, true) => target.clear(), 
+                (false, false) => target.extend_from_slice(source), 
+            };
+        }
+
+        let is_first = self.num_vertices() == 0;
+        append_stream(&mut self.uvs, &other.uvs, is_first);
+        append_stream(&mut self.tangents, &other.tangents, is_first);
+        append_stream(&mut self.colors, &other.colors, is_first);
+        append_stream(&mut self.joints, &other.joints, is_first);
+        append_stream(&mut self.weights, &other.weights, is_first);
+
+        let offset = self.num_vertices() as u32;
+        self.positions.extend_from_slice(&other.positions);
+        self.normals.extend_from_slice(&other.normals);
+        self.indices.extend(other.indices.iter().map(|&index| index + offset));
+    }
+
+    /// #### 한국어 </br>
+    /// 여러 메쉬를 하나의 메쉬로 합칩니다. 모든 메쉬에 있는 선택 속성만 유지됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Merges several meshes into a single mesh. Only optional attributes present in all meshes are kept. </br>
+    /// 
+    pub fn merge<'a, I: IntoIterator<Item = &'a MeshData>>(meshes: I) -> MeshData {
+        let mut merged = MeshData::default();
+        for mesh in meshes {
+            merged.append(mesh);
+        }
+        merged
+    }
+
+    /// #### 한국어 </br>
+    /// 버텍스 캐시 적중률이 높아지도록 삼각형의 순서를 바꾸고(Forsyth 알고리즘),
+    /// 버텍스들을 처음 사용되는 순서대로 재배치합니다. 사용되지 않는 버텍스는 제거됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Reorders the triangles so that the vertex cache hit rate improves (Forsyth's algorithm),
+    /// and rearranges the vertices in the order they are first used. Unused vertices are removed. </br>
+    /// 
+    pub fn optimize_vertex_cache(&mut self) {
+        self.indices = optimize_triangle_order(&self.indices, self.num_vertices());
+
+        let mut remap = vec![u32::MAX; self.num_vertices()];
+        let mut order = Vec::with_capacity(self.num_vertices());
+        for index in self.indices.iter_mut() {
+            if remap[*index as usize] == u32::MAX {
+                remap[*index as usize] = order.len() as u32;
+                order.push(*index as usize);
+            }
+            *index = remap[*index as usize];
+        }
+        self.reorder_vertices(&order);
+    }
+
+    /// #### 한국어 </br>
+    /// 주어진 크기의 `FIFO` 버텍스 캐시를 가정했을 때 삼각형 당 평균 캐시 실패 수(ACMR)를 계산합니다. </br>
+    /// 값이 작을수록 좋으며, 최적값은 약 `0.5`입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Computes the average cache miss ratio (ACMR) per triangle, assuming a `FIFO` vertex cache of the given size. </br>
+    /// Lower is better, and the optimum is about `0.5`. </br>
+    /// 
+    pub fn average_cache_miss_ratio(&self, cache_size: usize) -> f32 {
+        if self.indices.is_empty() {
+            return 0.0;
+        }
+
+        let mut cache = std::collections::VecDeque::with_capacity(cache_size);
+        let mut num_misses = 0;
+        for &index in self.indices.iter() {
+            if !cache.contains(&index) {
+                num_misses += 1;
+                if cache.len() == cache_size {
+                    cache.pop_front();
+                }
+                cache.push_back(index);
+            }
+        }
+        num_misses as f32 / self.num_triangles() as f32
+    }
+
+    /// #### 한국어 </br>
+    /// 메쉬를 `upload_format`의 형식으로 주어진 장치에 업로드합니다. 버텍스 수에 따라 16비트 또는 32비트 인덱스를 사용합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Uploads the mesh to the given device in the format of `upload_format`. Uses 16-bit or 32-bit indices depending on the number of vertices. </br>
+    /// 
+    pub fn upload(&self, device: &wgpu::Device, queue: &wgpu::Queue) -> ModelMesh {
+        debug_assert!(self.validate().is_ok(), "{:?}", self.validate());
+
+        let label = self.label.as_deref();
+        let format = self.upload_format();
+        let bytes = self.vertex_bytes(format).unwrap_or_default();
+        let vertex_buffer = VertexBuffer::from_bytes(label, &bytes, format, device, queue);
+        let index_buffer = match self.fits_uint16_indices() {
+            true => {
+                let indices: Vec<u16> = self.indices.iter().map(|&index| index as u16).collect();
+                IndexBuffer::from_uint16_indices(label, &indices, device, queue)
+            }, 
+            false => IndexBuffer::from_uint32_indices(label, &self.indices, device, queue), 
+        };
+
+        // (한국어) 
+        // 선으로 다각형을 그릴 수 없는 장치는 무게중심 좌표로 와이어프레임을 그리므로 인덱스를 보관합니다.
+        // 인덱스를 풀어 놓은 버텍스 버퍼는 그 와이어프레임을 처음 그릴 때 생성됩니다.
+        // 
+        // (English Translation) 
+        // Devices that cannot draw polygons as lines draw the wireframe with barycentric coordinates, so the indices are kept.
+        // The vertex buffer with its indices unrolled is created when that wireframe is first drawn.
+        // 
+        let mesh = ModelMesh::new_with_index_buffer(vertex_buffer, index_buffer);
+        let mesh = match device.features().contains(wgpu::Features::POLYGON_MODE_LINE) {
+            true => mesh, 
+            false => mesh.with_unindexed_vertices(self.indices.clone()), 
+        };
+        match self.retain {
+            true => mesh.with_mesh_data(Arc::new(self.clone())), 
+            false => mesh, 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 메쉬 데이터로 `DynamicMesh::new`으로 생성된 메쉬의 내용을 교체합니다. 버퍼가 다시 할당되었다면 `true`를 반환합니다. </br>
+    /// 버텍스는 메쉬의 형식으로 쓰이므로, 메쉬 데이터가 그 형식의 모든 속성을 가지고 있어야 합니다. </br>
+    /// 메쉬의 동일성은 유지되므로 매 프레임 변형되는 지오메트리에 사용할 수 있습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Replaces the contents of a mesh created with `DynamicMesh::new` with the mesh data. Returns `true` if a buffer was reallocated. </br>
+    /// The vertices are written in the format of the mesh, so the mesh data must have all attributes of that format. </br>
+    /// The identity of the mesh is kept, so it can be used for geometry that deforms every frame. </br>
+    /// 
+    pub fn update(&self, mesh: &mut DynamicMesh, device: &wgpu::Device, queue: &wgpu::Queue) -> Result<bool, String> {
+        self.validate()?;
+        match mesh.index_buffer().map(|index_buffer| index_buffer.format()) {
+            Some(wgpu::IndexFormat::Uint32) => { }, 
+            Some(wgpu::IndexFormat::Uint16) => return Err("the mesh has 16-bit indices and cannot be updated".to_string()), 
+            None => return Err("the mesh has no index buffer".to_string()), 
+        };
+
+        let bytes = self.vertex_bytes(mesh.format())?;
+        let mut reallocated = mesh.write_vertices(0, &bytes, device, queue)?;
+        reallocated |= mesh.write_indices(0, &self.indices, device, queue)?;
+        mesh.truncate(self.num_vertices() as u32, self.indices.len() as u32);
+        Ok(reallocated)
+    }
+
+    /// #### 한국어 </br>
+    /// 인덱스 순서대로 모든 삼각형이 고유한 버텍스를 가지도록 버텍스들을 풀어 놓습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Unrolls the vertices in index order so that every triangle has unique vertices. </br>
+    /// 
+    fn unweld(&mut self) {
+        let order: Vec<usize> = self.indices.iter().map(|&index| index as usize).collect();
+        self.reorder_vertices(&order);
+        self.indices = (0..order.len() as u32).collect();
+    }
+
+    /// #### 한국어 </br>
+    /// 모든 속성 배열을 `order`의 버텍스 번호 순서로 다시 만듭니다. 인덱스는 바꾸지 않습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Rebuilds all attribute arrays in the order of the vertex numbers in `order`. Indices are not changed. </br>
+    /// 
+    fn reorder_vertices(&mut self, order: &[usize]) {
+        fn gather<T: Copy>(values: &[T], order: &[usize]) -> Vec<T> {
+            match values.is_empty() {
+                true => Vec::new(), 
+                false => order.iter().map(|&index| values[index]).collect(), 
+            }
+        }
+
+        self.positions = gather(&self.positions, order);
+        self.normals = gather(&self.normals, order);
+        self.uvs = gather(&self.uvs, order);
+        self.tangents = gather(&self.tangents, order);
+        self.colors = gather(&self.colors, order);
+        self.joints = gather(&self.joints, order);
+        self.weights = gather(&self.weights, order);
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 버텍스의 캐시 위치와 남은 삼각형 수로 점수를 계산합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Computes the score of a vertex from its cache position and the number of remaining triangles. </br>
+/// 
+fn vertex_score(cache_position: Option<usize>, num_remaining: usize) -> f32 {
+    if num_remaining == 0 {
+        return -1.0;
+    }
+
+    let cache_score = match cache_position {
+        None => 0.0, 
+        Some(position) if position < 3 => LAST_TRIANGLE_SCORE, 
+        Some(position) => {
+            let scaler = 1.0 / (VERTEX_CACHE_SIZE - 3) as f32;
+            (1.0 - (position - 3) as f32 * scaler).powf(CACHE_DECAY_POWER)
+        }, 
+    };
+    let valence_boost = VALENCE_BOOST_SCALE * (num_remaining as f32).powf(-VALENCE_BOOST_POWER);
+    cache_score + valence_boost
+}
+
+/// #### 한국어 </br>
+/// Tom Forsyth의 선형 시간 버텍스 캐시 최적화 알고리즘으로 삼각형의 순서를 정합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Orders the triangles with Tom Forsyth's linear-speed vertex cache optimization algorithm. </br>
+/// 
+fn optimize_triangle_order(indices: &[u32], num_vertices: usize) -> Vec<u32> {
+    let num_triangles = indices.len() / 3;
+    let mut vertex_triangles: Vec<Vec<usize>> = vec![Vec::new(); num_vertices];
+    for (triangle, chunk) in indices.chunks_exact(3).enumerate() {
+        for &index in chunk {
+            vertex_triangles[index as usize].push(triangle);
+        }
+    }
+
+    let mut cache_positions: Vec<Option<usize>> = vec![None; num_vertices];
+    let mut vertex_scores: Vec<f32> = vertex_triangles.iter()
+        .map(|triangles| vertex_score(None, triangles.len()))
+        .collect();
+    let mut triangle_scores: Vec<f32> = indices.chunks_exact(3)
+        .map(|chunk| chunk.iter().map(|&index| vertex_scores[index as usize]).sum())
+        .collect();
+    let mut emitted = vec![false; num_triangles];
+
+    let mut output = Vec::with_capacity(indices.len());
+    let mut cache: Vec<u32> = Vec::with_capacity(VERTEX_CACHE_SIZE + 3);
+    let mut best_triangle = None;
+    let mut next_unemitted = 0;
+    for _ in 0..num_triangles {
+        // (한국어) 캐시 근처에 후보가 없다면 아직 출력되지 않은 첫 번째 삼각형에서 다시 시작합니다.
+        // (English Translation) If there is no candidate near the cache, restarts from the first triangle not yet emitted.
+        let triangle = match best_triangle.take() {
+            Some(triangle) => triangle, 
+            None => {
+                while emitted[next_unemitted] {
+                    next_unemitted += 1;
+                }
+                next_unemitted
+            }, 
+        };
+
+        emitted[triangle] = true;
+        let chunk = &indices[triangle * 3..triangle * 3 + 3];
+        output.extend_from_slice(chunk);
+
+        for &index in chunk {
+            vertex_triangles[index as usize].retain(|&it| it != triangle);
+            cache.retain(|&it| it != index);
+            cache.insert(0, index);
+        }
+
+        // (한국어) 캐시 밖으로 밀려난 버텍스들도 점수를 갱신해야 합니다.
+        // (English Translation) Vertices pushed out of the cache also need their scores updated.
+        let evicted: Vec<u32> = cache.drain(VERTEX_CACHE_SIZE.min(cache.len())..).collect();
+        for &index in evicted.iter() {
+            cache_positions[index as usize] = None;
+        }
+        for (position, &index) in cache.iter().enumerate() {
+            cache_positions[index as usize] = Some(position);
+        }
+
+        let mut best_score = f32::MIN;
+        for &index in cache.iter().chain(evicted.iter()) {
+            let index = index as usize;
+            let new_score = vertex_score(cache_positions[index], vertex_triangles[index].len());
+            let delta = new_score - vertex_scores[index];
+            vertex_scores[index] = new_score;
+            for &adjacent in vertex_triangles[index].iter() {
+                triangle_scores[adjacent] += delta;
+            }
+        }
+        for &index in cache.iter() {
+            for &adjacent in vertex_triangles[index as usize].iter() {
+                if triangle_scores[adjacent] > best_score {
+                    best_score = triangle_scores[adjacent];
+                    best_triangle = Some(adjacent);
+                }
+            }
+        }
+    }
+    output
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::render::shape;
+
+    fn assert_near(actual: glam::Vec3, expected: glam::Vec3) {
+        assert!(actual.abs_diff_eq(expected, 1.0e-5), "expected {}, found {}", expected, actual);
+    }
+
+    fn triangle(offset: f32) -> MeshData {
+        MeshData::new(
+            vec![glam::Vec3::new(offset, 0.0, 0.0), glam::Vec3::new(offset + 1.0, 0.0, 0.0), glam::Vec3::new(offset, 1.0, 0.0)], 
+            vec![glam::Vec3::Z; 3], 
+            vec![0, 1, 2]
+        )
+    }
+
+    #[test]
+    fn from_vertices_keeps_positions_normals_and_indices() {
+        let vertices = [
+            VertexInputLayout { position: glam::Vec3::X, normal: glam::Vec3::Y }, 
+            VertexInputLayout { position: glam::Vec3::Y, normal: glam::Vec3::Z }, 
+            VertexInputLayout { position: glam::Vec3::Z, normal: glam::Vec3::X }, 
+        ];
+        let mesh = MeshData::from_vertices(&vertices, vec![0, 2, 1]);
+
+        assert_eq!(mesh.positions, [glam::Vec3::X, glam::Vec3::Y, glam::Vec3::Z]);
+        assert_eq!(mesh.normals, [glam::Vec3::Y, glam::Vec3::Z, glam::Vec3::X]);
+        assert_eq!(mesh.indices, [0, 2, 1]);
+        assert_eq!(mesh.format(), VertexFormat::POSITION.with(VertexAttribute::Normal));
+        assert_eq!(mesh.vertices(), vertices);
+    }
+
+    #[test]
+    fn merge_offsets_indices_and_keeps_common_attributes() {
+        let uvs = vec![glam::Vec2::ZERO, glam::Vec2::X, glam::Vec2::Y];
+        let colors = vec![glam::Vec4::ONE; 3];
+        let first = triangle(0.0).with_uvs(uvs.clone()).with_colors(colors.clone());
+        let second = triangle(2.0).with_uvs(uvs.clone());
+
+        let merged = MeshData::merge([&first, &second]);
+        assert_eq!(merged.num_vertices(), 6);
+        assert_eq!(merged.indices, [0, 1, 2, 3, 4, 5]);
+        assert_eq!(merged.positions[3], glam::Vec3::new(2.0, 0.0, 0.0));
+        assert_eq!(merged.uvs.len(), 6);
+        assert!(!merged.has_colors());
+        assert_eq!(merged.validate(), Ok(()));
+
+        let merged = MeshData::merge([&second, &triangle(4.0)]);
+        assert!(!merged.has_uvs());
+        assert_eq!(merged.indices, [0, 1, 2, 3, 4, 5]);
+        assert_eq!(MeshData::merge([]), MeshData::default());
+    }
+
+    #[test]
+    fn validate_reports_mismatched_lengths_and_indices() {
+        assert_eq!(triangle(0.0).validate(), Ok(()));
+
+        let mut mesh = triangle(0.0);
+        mesh.normals.pop();
+        assert!(mesh.validate().is_err());
+
+        let mesh = triangle(0.0).with_uvs(vec![glam::Vec2::ZERO]);
+        assert!(mesh.validate().is_err());
+
+        let mut mesh = triangle(0.0);
+        mesh.indices.push(0);
+        assert!(mesh.validate().is_err());
+
+        let mut mesh = triangle(0.0);
+        mesh.indices[2] = 3;
+        assert!(mesh.validate().is_err());
+    }
+
+    #[test]
+    fn flat_normals_unweld_and_follow_the_faces() {
+        let mut mesh = shape::grid(2.0, 2.0, 2, 2);
+        mesh.normals.iter_mut().for_each(|normal| *normal = glam::Vec3::ZERO);
+        mesh.compute_flat_normals();
+
+        assert_eq!(mesh.num_vertices(), 24);
+        assert_eq!(mesh.indices, (0..24).collect::<Vec<u32>>());
+        assert_eq!(mesh.uvs.len(), 24);
+        assert!(mesh.normals.iter().all(|&normal| normal == glam::Vec3::Y));
+
+        assert_eq!(mesh.weld(1.0e-4), 15);
+        assert_eq!(mesh.num_vertices(), 9);
+        assert_eq!(mesh.num_triangles(), 8);
+    }
+
+    #[test]
+    fn smooth_normals_average_the_adjacent_faces() {
+        let mut mesh = shape::icosphere(1.0, 1);
+        mesh.normals.iter_mut().for_each(|normal| *normal = glam::Vec3::ZERO);
+        mesh.compute_smooth_normals();
+
+        for (&position, &normal) in mesh.positions.iter().zip(mesh.normals.iter()) {
+            assert!((normal.length() - 1.0).abs() < 1.0e-5);
+            assert!(normal.dot(position) > 0.99, "normal {} does not point away from {}", normal, position);
+        }
+    }
+
+    #[test]
+    fn tangents_follow_the_texture_coordinates() {
+        let mut mesh = shape::grid(2.0, 2.0, 2, 2);
+        mesh.generate_tangents().unwrap();
+        assert_eq!(mesh.tangents.len(), mesh.num_vertices());
+        for &tangent in mesh.tangents.iter() {
+            assert_near(tangent.truncate(), glam::Vec3::X);
+            assert_eq!(tangent.w, -1.0);
+        }
+
+        let mut mesh = shape::uv_sphere(1.0, 8, 4);
+        mesh.generate_tangents().unwrap();
+        for (&normal, &tangent) in mesh.normals.iter().zip(mesh.tangents.iter()) {
+            assert!((tangent.truncate().length() - 1.0).abs() < 1.0e-5);
+            assert!(normal.dot(tangent.truncate()).abs() < 1.0e-5);
+            assert_eq!(tangent.w.abs(), 1.0);
+        }
+
+        assert!(triangle(0.0).generate_tangents().is_err());
+    }
+
+    #[test]
+    fn mirroring_transform_keeps_front_faces() {
+        let mut mesh = shape::grid(1.0, 1.0, 1, 1);
+        mesh.generate_tangents().unwrap();
+        mesh.transform(glam::Mat4::from_scale(glam::Vec3::new(1.0, -2.0, 1.0)));
+
+        assert!(mesh.normals.iter().all(|&normal| normal == glam::Vec3::NEG_Y));
+        assert!(mesh.tangents.iter().all(|&tangent| tangent.w == 1.0));
+        for triangle in mesh.indices.chunks_exact(3) {
+            let [a, b, c] = [0, 1, 2].map(|i| mesh.positions[triangle[i] as usize]);
+            assert!((b - a).cross(c - a).dot(glam::Vec3::NEG_Y) > 0.0);
+        }
+    }
+
+    #[test]
+    fn vertex_cache_optimization_keeps_triangles() {
+        let mut mesh = shape::uv_sphere(1.0, 32, 16);
+        let before = mesh.average_cache_miss_ratio(VERTEX_CACHE_SIZE);
+        let mut triangles: Vec<_> = mesh.indices.chunks_exact(3)
+            .map(|triangle| [0, 1, 2].map(|i| mesh.positions[triangle[i] as usize].to_array().map(f32::to_bits)))
+            .collect();
+
+        mesh.optimize_vertex_cache();
+        assert_eq!(mesh.validate(), Ok(()));
+        assert!(mesh.average_cache_miss_ratio(VERTEX_CACHE_SIZE) <= before);
+
+        let mut optimized: Vec<_> = mesh.indices.chunks_exact(3)
+            .map(|triangle| [0, 1, 2].map(|i| mesh.positions[triangle[i] as usize].to_array().map(f32::to_bits)))
+            .collect();
+        let canonical = |triangle: &mut [[u32; 3]; 3]| {
+            let first = (0..3).min_by_key(|&i| triangle[i]).unwrap();
+            triangle.rotate_left(first);
+        };
+        triangles.iter_mut().for_each(canonical);
+        optimized.iter_mut().for_each(canonical);
+        triangles.sort();
+        optimized.sort();
+        assert_eq!(triangles, optimized);
+    }
+}
@@ -1,4 +1,36 @@
+pub mod debug_draw;
+pub mod gltf;
+pub mod hud;
+pub mod material;
 pub mod mesh;
+pub mod mesh_data;
+pub mod obj;
 pub mod pipeline;
+pub mod profiler;
+pub mod registry;
+pub mod shape;
+pub mod shader;
 pub mod texture;
 pub mod uniform;
+pub mod vertex;
+
+use std::any::TypeId;
+use std::collections::HashMap;
+
+use crate::error::AppError;
+
+
+
+/// #### 한국어 </br>
+/// 주어진 타입의 바인드 그룹 레이아웃을 찾습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Finds the bind group layout of the given type. </br>
+/// 
+#[inline]
+pub fn find_layout<T: 'static>(
+    layouts: &HashMap<TypeId, wgpu::BindGroupLayout>
+) -> Result<&wgpu::BindGroupLayout, AppError> {
+    layouts.get(&TypeId::of::<T>())
+        .ok_or_else(|| AppError::LayoutNotFound(std::any::type_name::<T>().to_string()))
+}
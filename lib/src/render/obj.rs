@@ -0,0 +1,216 @@
+use std::collections::HashMap;
+
+use crate::render::mesh_data::MeshData;
+
+
+
+/// #### 한국어 </br>
+/// `Wavefront OBJ` 형식의 문자열을 메쉬 데이터로 읽습니다. </br>
+/// 위치(`v`)와 텍스처 좌표(`vt`), 법선(`vn`), 면(`f`)만 사용하며, 다각형 면은 부채꼴로 삼각형 분할됩니다. </br>
+/// 법선이 없는 면의 버텍스는 인접한 면들의 법선을 평균하여 부드러운 법선을 가집니다. </br>
+/// 텍스처 좌표는 원점이 왼쪽 위가 되도록 `v`를 뒤집으며, 일부 버텍스에만 있다면 나머지는 `(0, 0)`을 가집니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Parses a string in `Wavefront OBJ` format into mesh data. </br>
+/// Only positions (`v`), texture coordinates (`vt`), normals (`vn`) and faces (`f`) are used, and polygon faces are fan-triangulated. </br>
+/// The vertices of faces without normals get smooth normals by averaging the normals of adjacent faces. </br>
+/// Texture coordinates have `v` flipped so that the origin is at the top left, and if only some vertices have them, the rest get `(0, 0)`. </br>
+/// 
+pub fn parse(text: &str) -> Result<MeshData, String> {
+    let mut positions = Vec::new();
+    let mut texcoords = Vec::new();
+    let mut normals = Vec::new();
+    let mut mesh = MeshData::default();
+    let mut uvs: Vec<Option<glam::Vec2>> = Vec::new();
+
+    // (한국어) 같은 (위치, 텍스처 좌표, 법선) 조합은 하나의 버텍스로 합칩니다.
+    // (English Translation) The same (position, texture coordinate, normal) combination is merged into one vertex.
+    let mut vertex_indices: HashMap<(usize, Option<usize>, Option<usize>), u32> = HashMap::new();
+    let mut smooth_vertices = Vec::new();
+
+    for (line_number, line) in text.lines().enumerate() {
+        let line = line.split('#').next().unwrap_or("").trim();
+        let mut tokens = line.split_whitespace();
+        let error = |msg: &str| format!("line {}: {}", line_number + 1, msg);
+        match tokens.next() {
+            Some("v") => positions.push(parse_vec3(&mut tokens).ok_or_else(|| error("invalid position"))?), 
+            Some("vt") => texcoords.push(parse_vec2(&mut tokens).ok_or_else(|| error("invalid texture coordinate"))?), 
+            Some("vn") => normals.push(parse_vec3(&mut tokens).ok_or_else(|| error("invalid normal"))?), 
+            Some("f") => {
+                let mut face = Vec::new();
+                for token in tokens {
+                    let mut parts = token.split('/');
+                    let position = parts.next()
+                        .and_then(|it| resolve_index(it, positions.len()))
+                        .ok_or_else(|| error("invalid position index"))?;
+                    let texcoord = match parts.next() {
+                        Some(it) if !it.is_empty() => Some(
+                            resolve_index(it, texcoords.len()).ok_or_else(|| error("invalid texture coordinate index"))?
+                        ), 
+                        _ => None, 
+                    };
+                    let normal = match parts.next() {
+                        Some(it) if !it.is_empty() => Some(
+                            resolve_index(it, normals.len()).ok_or_else(|| error("invalid normal index"))?
+                        ), 
+                        _ => None, 
+                    };
+
+                    let index = *vertex_indices.entry((position, texcoord, normal)).or_insert_with(|| {
+                        mesh.positions.push(positions[position]);
+                        mesh.normals.push(normal.map(|it| normals[it]).unwrap_or(glam::Vec3::ZERO));
+                        uvs.push(texcoord.map(|it| texcoords[it]));
+                        if normal.is_none() {
+                            smooth_vertices.push(mesh.positions.len() - 1);
+                        }
+                        (mesh.positions.len() - 1) as u32
+                    });
+                    face.push(index);
+                }
+
+                if face.len() < 3 {
+                    return Err(error("a face must have at least 3 vertices"));
+                }
+                for i in 1..(face.len() - 1) {
+                    mesh.indices.extend_from_slice(&[face[0], face[i], face[i + 1]]);
+                }
+            }, 
+            _ => { /* (한국어) 지원하지 않는 항목은 무시합니다. (English Translation) Unsupported entries are ignored. */ }, 
+        };
+    }
+
+    if mesh.indices.is_empty() {
+        return Err("the file has no faces".to_string());
+    }
+
+    if uvs.iter().any(|uv| uv.is_some()) {
+        mesh.uvs = uvs.into_iter()
+            .map(|uv| uv.unwrap_or_default())
+            .collect();
+    }
+
+    // (한국어) 법선이 없는 버텍스들에 면 법선을 누적한 후 정규화합니다.
+    // (English Translation) Accumulates face normals into the vertices without normals, then normalizes them.
+    if !smooth_vertices.is_empty() {
+        for triangle in mesh.indices.chunks_exact(3) {
+            let a = mesh.positions[triangle[0] as usize];
+            let b = mesh.positions[triangle[1] as usize];
+            let c = mesh.positions[triangle[2] as usize];
+            let face_normal = (b - a).cross(c - a);
+            for &index in triangle {
+                if smooth_vertices.binary_search(&(index as usize)).is_ok() {
+                    mesh.normals[index as usize] += face_normal;
+                }
+            }
+        }
+        for &index in smooth_vertices.iter() {
+            let normal = &mut mesh.normals[index];
+            *normal = normal.normalize_or_zero();
+        }
+    }
+
+    Ok(mesh)
+}
+
+fn parse_vec2<'a, I: Iterator<Item = &'a str>>(tokens: &mut I) -> Option<glam::Vec2> {
+    let u: f32 = tokens.next()?.parse().ok()?;
+    let v: f32 = tokens.next()?.parse().ok()?;
+    Some(glam::Vec2::new(u, 1.0 - v))
+}
+
+fn parse_vec3<'a, I: Iterator<Item = &'a str>>(tokens: &mut I) -> Option<glam::Vec3> {
+    let x = tokens.next()?.parse().ok()?;
+    let y = tokens.next()?.parse().ok()?;
+    let z = tokens.next()?.parse().ok()?;
+    Some(glam::Vec3::new(x, y, z))
+}
+
+/// #### 한국어 </br>
+/// `OBJ`의 1부터 시작하는 인덱스(음수는 끝에서부터의 상대 인덱스)를 0부터 시작하는 인덱스로 변환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Converts a 1-based `OBJ` index (negative values are relative to the end) into a 0-based index. </br>
+/// 
+fn resolve_index(token: &str, len: usize) -> Option<usize> {
+    let index: i64 = token.parse().ok()?;
+    let index = match index {
+        0 => return None, 
+        i if i > 0 => i as usize - 1, 
+        i => len.checked_sub(i.unsigned_abs() as usize)?, 
+    };
+    (index < len).then_some(index)
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_positions_texcoords_and_normals() {
+        let mesh = parse("
+            # A unit quad facing +z.
+            v 0 0 0
+            v 1 0 0
+            v 1 1 0
+            v 0 1 0
+            vt 0 0
+            vt 1 0
+            vt 1 1
+            vt 0 1
+            vn 0 0 1
+            o quad
+            f 1/1/1 2/2/1 3/3/1 4/4/1
+        ").unwrap();
+
+        assert_eq!(mesh.num_vertices(), 4);
+        assert_eq!(mesh.indices, [0, 1, 2, 0, 2, 3]);
+        assert_eq!(mesh.positions[2], glam::Vec3::new(1.0, 1.0, 0.0));
+        assert_eq!(mesh.uvs, [glam::Vec2::new(0.0, 1.0), glam::Vec2::new(1.0, 1.0), glam::Vec2::new(1.0, 0.0), glam::Vec2::new(0.0, 0.0)]);
+        assert!(mesh.normals.iter().all(|&normal| normal == glam::Vec3::Z));
+        assert_eq!(mesh.validate(), Ok(()));
+    }
+
+    #[test]
+    fn shares_vertices_and_smooths_missing_normals() {
+        let mesh = parse("
+            v 0 0 0
+            v 1 0 0
+            v 0 1 0
+            v 0 0 1
+            f 1 3 2
+            f -4 -3 -1
+        ").unwrap();
+
+        assert_eq!(mesh.num_vertices(), 4);
+        assert_eq!(mesh.indices, [0, 1, 2, 0, 2, 3]);
+        assert!(!mesh.has_uvs());
+        assert_eq!(mesh.normals[1], glam::Vec3::NEG_Z);
+        assert_eq!(mesh.normals[3], glam::Vec3::NEG_Y);
+        assert!((mesh.normals[0] - glam::Vec3::new(0.0, -1.0, -1.0).normalize()).length() < 1.0e-6);
+    }
+
+    #[test]
+    fn fills_missing_texcoords_with_zero() {
+        let mesh = parse("
+            v 0 0 0
+            v 1 0 0
+            v 0 1 0
+            vt 0.5 0.25
+            f 1/1 2 3
+        ").unwrap();
+
+        assert_eq!(mesh.uvs, [glam::Vec2::new(0.5, 0.75), glam::Vec2::ZERO, glam::Vec2::ZERO]);
+    }
+
+    #[test]
+    fn reports_invalid_input() {
+        assert!(parse("v 0 0 0\nv 1 0 0\nv 0 1 0\n").unwrap_err().contains("no faces"));
+        assert!(parse("v 0 0\n").unwrap_err().starts_with("line 1"));
+        assert!(parse("v 0 0 0\nv 1 0 0\nf 1 2\n").unwrap_err().starts_with("line 3"));
+        assert!(parse("v 0 0 0\nv 1 0 0\nv 0 1 0\nf 1 2 4\n").unwrap_err().contains("position index"));
+        assert!(parse("v 0 0 0\nv 1 0 0\nv 0 1 0\nf 0 1 2\n").is_err());
+        assert!(parse("v 0 0 0\nv 1 0 0\nv 0 1 0\nf 1//1 2 3\n").unwrap_err().contains("normal index"));
+    }
+}
@@ -1,384 +1,823 @@
-use std::hash;
 use std::sync::Arc;
 use std::any::TypeId;
 use std::collections::HashMap;
 
-use crate::app::RENDER_TARGET_FORMAT;
-use crate::render::mesh::VERTEX_BUFFER_LAYOUT;
+use crate::error::AppError;
+use crate::render::debug_draw::DEBUG_DRAW_VERTEX_BUFFER_LAYOUT;
+use crate::render::hud::HUD_VERTEX_BUFFER_LAYOUT;
+use crate::render::hud::HudUniformLayout;
+use crate::render::material::BlendMode;
+use crate::render::material::Material;
+use crate::render::material::MaterialBuilder;
+use crate::render::material::MaterialHandle;
+use crate::render::shader::Shader;
+use crate::render::shader::ShaderError;
+use crate::render::shader::ShaderSource;
 use crate::render::texture::WeightedBlendedOIT;
 use crate::render::uniform::CameraUniformLayout;
 use crate::render::uniform::EntityUniformLayout;
+use crate::render::uniform::SkinUniformLayout;
+use crate::render::vertex::VertexFormat;
 
 
 
-pub trait GraphicsPipeline {
-    fn bind<'a>(&'a self, rpass: &mut wgpu::RenderPass<'a>);
-}
-
-
 /// #### 한국어 </br>
-/// 단일 색상의 오브젝트를 그리는 그래픽스 파이프라인입니다. </br>
+/// 재질의 서술을 키로 하여 그래픽스 파이프라인을 생성하고 재사용하는 캐시입니다. </br>
+/// 모든 파이프라인은 `color_format` 형식의 렌더 타겟에 그려집니다. </br>
+/// 메쉬가 재질보다 많은 버텍스 속성을 가진 경우, 메쉬의 형식에 맞는 레이아웃을 가진 변형 재질이 선택됩니다(`select`). </br>
 /// 
 /// #### English (Translation) </br>
-/// A graphics pipeline that draws object with a single color. </br>
+/// A cache that creates and reuses graphics pipelines keyed by the material description. </br>
+/// All pipelines draw to a render target of the `color_format` format. </br>
+/// If a mesh has more vertex attributes than the material, a variant material with a layout matching the mesh's format is selected (`select`). </br>
 /// 
-#[derive(Debug, Clone)]
-pub struct ColoredPipeline {
-    inner: Arc<wgpu::RenderPipeline>
+#[derive(Debug)]
+pub struct PipelineCache {
+    color_format: wgpu::TextureFormat, 
+    materials: Vec<Material>, 
+    pipelines: HashMap<Material, Arc<wgpu::RenderPipeline>>, 
+    variants: HashMap<(MaterialHandle, VertexFormat), MaterialHandle>, 
 }
 
-impl ColoredPipeline {
-    pub fn new(
+#[allow(dead_code)]
+impl PipelineCache {
+    #[inline]
+    pub fn new(color_format: wgpu::TextureFormat) -> Self {
+        Self {
+            color_format, 
+            materials: Vec::new(), 
+            pipelines: HashMap::new(), 
+            variants: HashMap::new(), 
+        }
+    }
+
+    #[inline]
+    pub fn color_format(&self) -> wgpu::TextureFormat {
+        self.color_format
+    }
+
+    /// #### 한국어 </br>
+    /// 렌더 타겟의 형식을 변경합니다. 형식이 바뀐 경우 모든 그래픽스 파이프라인을 다시 생성하고 `true`를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Changes the format of the render target. If the format changed, recreates all graphics pipelines and returns `true`. </br>
+    /// 
+    pub fn set_color_format(
+        &mut self, 
+        color_format: wgpu::TextureFormat, 
         device: &wgpu::Device, 
         layouts: &HashMap<TypeId, wgpu::BindGroupLayout>
-    ) -> Self {
-        let bind_group_layouts = &[
-            layouts.get(&TypeId::of::<CameraUniformLayout>())
-                .expect("CameraUniformLayout not found!"),
-            layouts.get(&TypeId::of::<EntityUniformLayout>())
-                .expect("EntityUniformLayout not found!"),
-        ];
-
-        let vertex_shader = device.create_shader_module(
-            wgpu::include_spirv!(concat!(env!("CARGO_MANIFEST_DIR"), "/shaders/color.vs.spv"))
-        );
-
-        let fragment_shader = device.create_shader_module(
-            wgpu::include_spirv!(concat!(env!("CARGO_MANIFEST_DIR"), "/shaders/color.fs.spv"))
-        );
-
-        let pipeline_layout = device.create_pipeline_layout(
-            &wgpu::PipelineLayoutDescriptor {
-                label: Some("PipelineLayout(ColoredPipeline)"), 
-                bind_group_layouts, 
-                push_constant_ranges: &[],
-            },
-        );
-
-        let pipeline = device.create_render_pipeline(
-            &wgpu::RenderPipelineDescriptor {
-                label: Some("RenderPipeline(ColoredPipeline)"), 
-                layout: Some(&pipeline_layout), 
-                vertex: wgpu::VertexState {
-                    module: &vertex_shader, 
-                    entry_point: "main", 
-                    buffers: &[VERTEX_BUFFER_LAYOUT], 
-                }, 
-                primitive: wgpu::PrimitiveState {
-                    topology: wgpu::PrimitiveTopology::TriangleList, 
-                    front_face: wgpu::FrontFace::Ccw, 
-                    cull_mode: Some(wgpu::Face::Back), 
-                    polygon_mode: wgpu::PolygonMode::Fill, 
-                    ..Default::default()
-                },
-                depth_stencil: Some(wgpu::DepthStencilState {
-                    format: wgpu::TextureFormat::Depth32Float, 
-                    depth_write_enabled: true, 
-                    depth_compare: wgpu::CompareFunction::Less, 
-                    stencil: wgpu::StencilState::default(), 
-                    bias: wgpu::DepthBiasState::default(), 
-                }), 
-                multisample: wgpu::MultisampleState::default(), 
-                fragment: Some(wgpu::FragmentState {
-                    module: &fragment_shader, 
-                    entry_point: "main", 
-                    targets: &[
-                        Some(wgpu::ColorTargetState {
-                            format: RENDER_TARGET_FORMAT, 
-                            blend: None, 
-                            write_mask: wgpu::ColorWrites::ALL,
-                        }),
-                    ],
-                }),
-                multiview: None,
-            },
-        );
-
-        return Self {
-            inner: pipeline.into(), 
-        };
+    ) -> Result<bool, AppError> {
+        if self.color_format == color_format {
+            return Ok(false);
+        }
+
+        self.color_format = color_format;
+        self.pipelines.clear();
+        self.prepare(device, layouts)?;
+        Ok(true)
+    }
+
+    /// #### 한국어 </br>
+    /// 재질을 등록하고 핸들을 반환합니다. 이미 같은 재질이 등록되어 있다면 그 핸들을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Registers the material and returns a handle. If the same material is already registered, returns its handle. </br>
+    /// 
+    pub fn register(&mut self, material: Material) -> MaterialHandle {
+        if let Some(index) = self.materials.iter().position(|it| *it == material) {
+            return MaterialHandle(index);
+        }
+        self.materials.push(material);
+        MaterialHandle(self.materials.len() - 1)
     }
-}
 
-impl GraphicsPipeline for ColoredPipeline {
     #[inline]
-    fn bind<'a>(&'a self, rpass: &mut wgpu::RenderPass<'a>) {
-        rpass.set_pipeline(&self.inner)
+    pub fn material(&self, handle: MaterialHandle) -> Option<&Material> {
+        self.materials.get(handle.0)
     }
-}
 
-impl Eq for ColoredPipeline { }
+    /// #### 한국어 </br>
+    /// 주어진 형식의 메쉬를 그릴 재질을 선택합니다. </br>
+    /// 형식이 재질과 같다면 그 재질을, 재질의 모든 속성을 포함한다면 메쉬의 레이아웃을 가진 변형 재질을 등록하여 반환합니다. </br>
+    /// 메쉬에 재질이 요구하는 속성이 없다면 `AppError::VertexFormatMismatch`를 반환합니다. </br>
+    /// 변형 재질의 파이프라인은 `prepare` 또는 `get_or_create`에서 생성됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Selects the material to draw a mesh of the given format. </br>
+    /// Returns the material itself if the format is the same, or registers and returns a variant material with the mesh's layout if the format contains all attributes of the material. </br>
+    /// Returns `AppError::VertexFormatMismatch` if the mesh lacks an attribute required by the material. </br>
+    /// The pipeline of a variant material is created in `prepare` or `get_or_create`. </br>
+    /// 
+    pub fn select(&mut self, handle: MaterialHandle, format: VertexFormat) -> Result<MaterialHandle, AppError> {
+        let Some(material) = self.materials.get(handle.0) else {
+            return Ok(handle);
+        };
+
+        let required = match material.vertex_format {
+            Some(required) if required != format => required, 
+            _ => return Ok(handle), 
+        };
+        if !format.contains_all(required) {
+            return Err(AppError::VertexFormatMismatch { 
+                material: material.label.clone(), 
+                expected: required, 
+                found: format, 
+            });
+        }
+        if let Some(variant) = self.variants.get(&(handle, format)) {
+            return Ok(*variant);
+        }
+
+        let variant = MaterialBuilder {
+            label: format!("{}[{}]", material.label, format), 
+            ..MaterialBuilder::from(material.clone())
+        }
+        .set_vertex_format(format)
+        .build();
+        let variant = self.register(variant);
+        self.variants.insert((handle, format), variant);
+        Ok(variant)
+    }
+
+    /// #### 한국어 </br>
+    /// 주어진 형식의 메쉬를 그릴 미리 생성된 그래픽스 파이프라인을 반환합니다. </br>
+    /// 형식이 맞지 않거나 변형 재질이 아직 선택되지 않은 경우 `None`을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the previously created graphics pipeline to draw a mesh of the given format. </br>
+    /// Returns `None` if the format does not match or the variant material has not been selected yet. </br>
+    /// 
+    pub fn pipeline_for(&self, handle: MaterialHandle, format: VertexFormat) -> Option<&wgpu::RenderPipeline> {
+        let material = self.materials.get(handle.0)?;
+        match material.vertex_format {
+            Some(required) if required != format => self.variants.get(&(handle, format))
+                .and_then(|variant| self.pipeline(*variant)), 
+            _ => self.pipeline(handle), 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 재질의 그래픽스 파이프라인을 반환합니다. 캐시에 없다면 새로 생성합니다. </br>
+    /// 등록되지 않은 핸들인 경우 `None`을 반환하며, 검증 오류가 발생한 경우 파이프라인을 캐시에 추가하지 않습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the graphics pipeline of the material. If it is not in the cache, creates a new one. </br>
+    /// Returns `None` if the handle is not registered, and the pipeline is not cached if a validation error occurs. </br>
+    /// 
+    pub fn get_or_create(
+        &mut self, 
+        handle: MaterialHandle, 
+        device: &wgpu::Device, 
+        layouts: &HashMap<TypeId, wgpu::BindGroupLayout>
+    ) -> Result<Option<Arc<wgpu::RenderPipeline>>, AppError> {
+        let Some(material) = self.materials.get(handle.0) else {
+            return Ok(None);
+        };
+
+        if let Some(pipeline) = self.pipelines.get(material) {
+            return Ok(Some(pipeline.clone()));
+        }
+
+        device.push_error_scope(wgpu::ErrorFilter::Validation);
+        let pipeline = create_render_pipeline(material, self.color_format, device, layouts);
+        if let Some(error) = pollster::block_on(device.pop_error_scope()) {
+            return Err(AppError::Pipeline(error.to_string()));
+        }
+
+        let pipeline: Arc<wgpu::RenderPipeline> = pipeline?.into();
+        self.pipelines.insert(material.clone(), pipeline.clone());
+        Ok(Some(pipeline))
+    }
 
-impl PartialEq<Self> for ColoredPipeline {
+    /// #### 한국어 </br>
+    /// 등록된 모든 재질의 그래픽스 파이프라인을 미리 생성합니다. </br>
+    /// 검증 오류가 발생한 경우 새로 생성한 파이프라인들을 하나도 캐시에 추가하지 않습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates graphics pipelines for all registered materials in advance. </br>
+    /// If a validation error occurs, none of the newly created pipelines are added to the cache. </br>
+    /// 
+    pub fn prepare(
+        &mut self, 
+        device: &wgpu::Device, 
+        layouts: &HashMap<TypeId, wgpu::BindGroupLayout>
+    ) -> Result<(), AppError> {
+        let mut missing: Vec<&Material> = Vec::new();
+        for material in self.materials.iter() {
+            if !self.pipelines.contains_key(material) && !missing.contains(&material) {
+                missing.push(material);
+            }
+        }
+
+        // (한국어) 
+        // 파이프라인 생성 중 발생한 검증 오류를 포착하여 반환합니다.
+        // 오류가 발생한 파이프라인은 유효하지 않으므로, 오류 범위가 깨끗하게 끝난 후에만 캐시에 추가합니다.
+        // 
+        // (English Translation) 
+        // Captures and returns validation errors that occur during pipeline creation.
+        // Pipelines created with errors are invalid, so they are added to the cache only after the error scope ends cleanly.
+        // 
+        device.push_error_scope(wgpu::ErrorFilter::Validation);
+        let created: Vec<_> = missing.into_iter()
+            .map(|material| create_render_pipeline(material, self.color_format, device, layouts)
+                .map(|pipeline| (material.clone(), pipeline)))
+            .collect();
+        if let Some(error) = pollster::block_on(device.pop_error_scope()) {
+            return Err(AppError::Pipeline(error.to_string()));
+        }
+
+        let created: Vec<_> = created.into_iter().collect::<Result<_, _>>()?;
+        for (material, pipeline) in created {
+            self.pipelines.insert(material, pipeline.into());
+        }
+        Ok(())
+    }
+
+    /// #### 한국어 </br>
+    /// 미리 생성된 재질의 그래픽스 파이프라인을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the previously created graphics pipeline of the material. </br>
+    /// 
     #[inline]
-    fn eq(&self, other: &Self) -> bool {
-        self.inner.global_id().eq(&other.inner.global_id())
+    pub fn pipeline(&self, handle: MaterialHandle) -> Option<&wgpu::RenderPipeline> {
+        self.materials.get(handle.0)
+            .and_then(|material| self.pipelines.get(material))
+            .map(|pipeline| pipeline.as_ref())
+    }
+
+    /// #### 한국어 </br>
+    /// 같은 이름의 셰이더를 사용하는 모든 재질의 셰이더를 교체하고 그래픽스 파이프라인을 다시 생성합니다. </br>
+    /// 파이프라인 생성에 실패한 경우 기존의 재질과 파이프라인을 그대로 유지합니다. </br>
+    /// 교체된 재질의 수를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Replaces the shaders of all materials that use a shader with the same name and recreates the graphics pipelines. </br>
+    /// If pipeline creation fails, the existing materials and pipelines are kept. </br>
+    /// Returns the number of replaced materials. </br>
+    /// 
+    pub fn replace_shaders(
+        &mut self, 
+        shaders: &[Shader], 
+        device: &wgpu::Device, 
+        layouts: &HashMap<TypeId, wgpu::BindGroupLayout>
+    ) -> Result<usize, AppError> {
+        let mut replaced = Vec::new();
+
+        device.push_error_scope(wgpu::ErrorFilter::Validation);
+        for (index, material) in self.materials.iter().enumerate() {
+            if !shaders.iter().any(|shader| material.uses_shader(shader.name())) {
+                continue;
+            }
+
+            let mut material = material.clone();
+            for shader in shaders.iter() {
+                if material.vertex_shader.name() == shader.name() {
+                    material.vertex_shader = shader.clone();
+                }
+                if material.fragment_shader.name() == shader.name() {
+                    material.fragment_shader = shader.clone();
+                }
+            }
+
+            let pipeline = create_render_pipeline(&material, self.color_format, device, layouts);
+            replaced.push((index, material, pipeline));
+        }
+
+        if let Some(error) = pollster::block_on(device.pop_error_scope()) {
+            return Err(ShaderError::from(error).into());
+        }
+
+        let replaced: Vec<_> = replaced.into_iter()
+            .map(|(index, material, pipeline)| pipeline.map(|pipeline| (index, material, pipeline)))
+            .collect::<Result<_, _>>()?;
+
+        let num_replaced = replaced.len();
+        for (index, material, pipeline) in replaced {
+            let old = std::mem::replace(&mut self.materials[index], material.clone());
+            if !self.materials.contains(&old) {
+                self.pipelines.remove(&old);
+            }
+            self.pipelines.insert(material, pipeline.into());
+        }
+
+        Ok(num_replaced)
+    }
+
+    /// #### 한국어 </br>
+    /// 새로운 장치에서 등록된 모든 재질의 셰이더와 그래픽스 파이프라인을 다시 생성합니다. </br>
+    /// 재질의 핸들은 그대로 유지되므로, 엔티티가 가진 핸들은 계속 유효합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Recreates the shaders and graphics pipelines of all registered materials on a new device. </br>
+    /// Material handles are kept, so the handles held by entities remain valid. </br>
+    /// 
+    pub fn recreate(
+        &mut self, 
+        device: &wgpu::Device, 
+        layouts: &HashMap<TypeId, wgpu::BindGroupLayout>
+    ) -> Result<(), AppError> {
+        // (한국어) 여러 재질이 공유하는 셰이더는 한 번만 다시 생성합니다.
+        // (English Translation) Shaders shared by several materials are recreated only once.
+        let mut shaders: HashMap<Shader, Shader> = HashMap::new();
+        let mut recreate_shader = |shader: &Shader| -> Result<Shader, ShaderError> {
+            if let Some(recreated) = shaders.get(shader) {
+                return Ok(recreated.clone());
+            }
+            let recreated = shader.recreate(device)?;
+            shaders.insert(shader.clone(), recreated.clone());
+            Ok(recreated)
+        };
+
+        let mut materials = Vec::with_capacity(self.materials.len());
+        for material in self.materials.iter() {
+            let mut material = material.clone();
+            material.vertex_shader = recreate_shader(&material.vertex_shader)?;
+            material.fragment_shader = recreate_shader(&material.fragment_shader)?;
+            materials.push(material);
+        }
+
+        self.materials = materials;
+        self.pipelines.clear();
+        self.prepare(device, layouts)
     }
 }
 
-impl hash::Hash for ColoredPipeline {
-    #[inline]
-    fn hash<H: hash::Hasher>(&self, state: &mut H) {
-        self.inner.global_id().hash(state)
+/// #### 한국어 </br>
+/// 재질의 서술로부터 `color_format` 형식의 렌더 타겟에 그리는 그래픽스 파이프라인을 생성합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates a graphics pipeline that draws to a render target of the `color_format` format from the material description. </br>
+/// 
+pub fn create_render_pipeline(
+    material: &Material, 
+    color_format: wgpu::TextureFormat, 
+    device: &wgpu::Device, 
+    layouts: &HashMap<TypeId, wgpu::BindGroupLayout>
+) -> Result<wgpu::RenderPipeline, AppError> {
+    if let Some(format) = material.vertex_format {
+        if material.vertex_layouts != [format.buffer_layout()] {
+            return Err(AppError::Pipeline(format!(
+                "the vertex layouts of {} do not match its vertex format {}", 
+                material.label, 
+                format
+            )));
+        }
     }
+
+    let bind_group_layouts: Vec<&wgpu::BindGroupLayout> = material.bind_group_layouts
+        .iter()
+        .map(|type_id| layouts.get(type_id).ok_or_else(|| {
+            AppError::LayoutNotFound(format!("{:?} (required by {})", type_id, material.label))
+        }))
+        .collect::<Result<_, _>>()?;
+
+    let pipeline_layout = device.create_pipeline_layout(
+        &wgpu::PipelineLayoutDescriptor {
+            label: Some(&format!("PipelineLayout({})", material.label)), 
+            bind_group_layouts: &bind_group_layouts, 
+            push_constant_ranges: &[], 
+        }, 
+    );
+
+    let targets = material.blend_mode.color_targets(color_format);
+
+    let pipeline = device.create_render_pipeline(
+        &wgpu::RenderPipelineDescriptor {
+            label: Some(&format!("RenderPipeline({})", material.label)), 
+            layout: Some(&pipeline_layout), 
+            vertex: wgpu::VertexState {
+                module: material.vertex_shader.module(), 
+                entry_point: "main", 
+                buffers: &material.vertex_layouts, 
+            }, 
+            primitive: wgpu::PrimitiveState {
+                topology: material.topology, 
+                strip_index_format: material.topology.is_strip().then_some(wgpu::IndexFormat::Uint16), 
+                front_face: material.front_face, 
+                cull_mode: material.cull_mode, 
+                polygon_mode: material.polygon_mode, 
+                ..Default::default()
+            }, 
+            depth_stencil: Some(wgpu::DepthStencilState {
+                format: wgpu::TextureFormat::Depth32Float, 
+                depth_write_enabled: material.depth_write_enabled, 
+                depth_compare: material.depth_compare, 
+                stencil: wgpu::StencilState::default(), 
+                bias: wgpu::DepthBiasState::default(), 
+            }), 
+            multisample: wgpu::MultisampleState::default(), 
+            fragment: Some(wgpu::FragmentState {
+                module: material.fragment_shader.module(), 
+                entry_point: "main", 
+                targets: &targets, 
+            }), 
+            multiview: None, 
+        }, 
+    );
+    Ok(pipeline)
 }
 
 
 
+//-------------------------------------------------------------------------------------------
+
+
+
 /// #### 한국어 </br>
-/// 투명한 오브젝트의 누적값과 노출값을 계산하여 저장하는 그래픽스 파이프라인 입니다. </br>
+/// 단일 색상의 오브젝트를 그리는 재질을 생성합니다. </br>
 /// 
 /// #### English (Translation) </br>
-/// A graphics pipeline that computes and stores the accumulated and revealed values of transparent objects. </br>
+/// Creates a material that draws object with a single color. </br>
 /// 
-#[derive(Debug, Clone)]
-pub struct TransparentPipeline {
-    inner: Arc<wgpu::RenderPipeline>, 
+pub fn create_colored_material(device: &wgpu::Device) -> Result<Material, ShaderError> {
+    let vertex_shader = Shader::from_source(
+        "color.vs", 
+        &ShaderSource::SpirV(include_bytes!(concat!(env!("CARGO_MANIFEST_DIR"), "/shaders/color.vs.spv")).into()), 
+        device
+    )?;
+
+    let fragment_shader = Shader::from_source(
+        "color.fs", 
+        &ShaderSource::SpirV(include_bytes!(concat!(env!("CARGO_MANIFEST_DIR"), "/shaders/color.fs.spv")).into()), 
+        device
+    )?;
+
+    let material = MaterialBuilder::new("ColoredMaterial", vertex_shader, fragment_shader)
+        .set_vertex_format(VertexFormat::POSITION_NORMAL)
+        .add_bind_group_layout::<CameraUniformLayout>()
+        .add_bind_group_layout::<EntityUniformLayout>()
+        .set_blend_mode(BlendMode::Opaque)
+        .build();
+    Ok(material)
 }
 
-impl TransparentPipeline {
-    pub fn new(
-        device: &wgpu::Device, 
-        layouts: &HashMap<TypeId, wgpu::BindGroupLayout>
-    ) -> Self {
-        let bind_group_layouts = &[
-            layouts.get(&TypeId::of::<CameraUniformLayout>())
-                .expect("CameraUniformLayout not found!"), 
-            layouts.get(&TypeId::of::<EntityUniformLayout>())
-                .expect("EntityUniformLayout not found!"),
-        ];
-
-        let vertex_shader = device.create_shader_module(
-            wgpu::include_spirv!(concat!(env!("CARGO_MANIFEST_DIR"), "/shaders/transparent.vs.spv"))
-        );
-
-        let fragment_shader = device.create_shader_module(
-            wgpu::include_spirv!(concat!(env!("CARGO_MANIFEST_DIR"), "/shaders/transparent.fs.spv"))
-        );
-
-        let pipeline_layout = device.create_pipeline_layout(
-            &wgpu::PipelineLayoutDescriptor {
-                label: Some("PipelineLayout(TransparentPipeline)"), 
-                bind_group_layouts, 
-                push_constant_ranges: &[], 
-            }, 
-        );
-
-        let pipeline = device.create_render_pipeline(
-            &wgpu::RenderPipelineDescriptor {
-                label: Some("RenderPipeline(TransparentPipeline)"), 
-                layout: Some(&pipeline_layout), 
-                vertex: wgpu::VertexState {
-                    module: &vertex_shader, 
-                    entry_point: "main", 
-                    buffers: &[VERTEX_BUFFER_LAYOUT], 
-                }, 
-                primitive: wgpu::PrimitiveState {
-                    topology: wgpu::PrimitiveTopology::TriangleList, 
-                    front_face: wgpu::FrontFace::Ccw, 
-                    cull_mode: Some(wgpu::Face::Back), 
-                    polygon_mode: wgpu::PolygonMode::Fill, 
-                    ..Default::default()
-                }, 
-                depth_stencil: Some(wgpu::DepthStencilState {
-                    format: wgpu::TextureFormat::Depth32Float, 
-                    depth_write_enabled: false, 
-                    depth_compare: wgpu::CompareFunction::Less, 
-                    stencil: wgpu::StencilState::default(), 
-                    bias: wgpu::DepthBiasState::default(), 
-                }), 
-                multisample: wgpu::MultisampleState::default(), 
-                fragment: Some(wgpu::FragmentState {
-                    module: &fragment_shader, 
-                    entry_point: "main", 
-                    targets: &[
-                        // (한국어) 
-                        // 첫 번째 렌더 타겟: (RGB * 가중치, Alpha * 가중치)를 RGBA로 저장하하는 누적 값.
-                        // 최소 `Rgba16Float`의 정밀도를 가져야 한다.
-                        // 
-                        // (English Translation)
-                        // First Render Target: Accumulated value (RGB * Weight, Alpha * Weight) stored as RGBA.
-                        // It must have a precision of at least `Rgba16Float`.
-                        //
-                        Some(wgpu::ColorTargetState {
-                            format: wgpu::TextureFormat::Rgba16Float, 
-                            blend: Some(wgpu::BlendState {
-                                color: wgpu::BlendComponent {
-                                    src_factor: wgpu::BlendFactor::One, 
-                                    dst_factor: wgpu::BlendFactor::One, 
-                                    operation: wgpu::BlendOperation::Add, 
-                                }, 
-                                alpha: wgpu::BlendComponent {
-                                    src_factor: wgpu::BlendFactor::One, 
-                                    dst_factor: wgpu::BlendFactor::One, 
-                                    operation: wgpu::BlendOperation::Add, 
-                                }, 
-                            }), 
-                            write_mask: wgpu::ColorWrites::ALL, 
-                        }), 
-                        // (한국어)
-                        // 두 번째 렌더 타겟: 이전의 색이 얼마만큼 노출이 될 수 있는지에 대한 노출 값.
-                        // 최소 `R8`의 정밀도를 가져야 한다.
-                        // 
-                        // (English Translation)
-                        // Second Render Target: Revealage value of how much of the previous color can be exposed. 
-                        // It must have a precision of at least `R8`.
-                        // 
-                        Some(wgpu::ColorTargetState {
-                            format: wgpu::TextureFormat::R8Unorm, 
-                            blend: Some(wgpu::BlendState {
-                                color: wgpu::BlendComponent {
-                                    src_factor: wgpu::BlendFactor::Zero, 
-                                    dst_factor: wgpu::BlendFactor::OneMinusSrc, 
-                                    operation: wgpu::BlendOperation::Add, 
-                                },
-                                alpha: wgpu::BlendComponent {
-                                    src_factor: wgpu::BlendFactor::Zero, 
-                                    dst_factor: wgpu::BlendFactor::OneMinusSrc, 
-                                    operation: wgpu::BlendOperation::Add, 
-                                }
-                            }),
-                            write_mask: wgpu::ColorWrites::ALL,
-                        }),
-                    ],
-                }),
-                multiview: None,
-            },
-        );
-
-        return Self {
-            inner: pipeline.into(), 
-        };
-    }
+/// #### 한국어 </br>
+/// 관절들의 스키닝 행렬로 변형되는 단일 색상의 오브젝트를 그리는 재질을 생성합니다. </br>
+/// `ColoredMaterial`의 스키닝 변형으로, 세 번째 바인드 그룹에 `SkinUniformLayout`을 사용합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates a material that draws object with a single color, deformed by the skinning matrices of joints. </br>
+/// A skinned variant of `ColoredMaterial` that uses `SkinUniformLayout` for the third bind group. </br>
+/// 
+pub fn create_skinned_material(device: &wgpu::Device) -> Result<Material, ShaderError> {
+    use wgpu::naga::ShaderStage;
+
+    let vertex_shader = Shader::from_source(
+        "skinned.vs", 
+        &ShaderSource::Glsl {
+            code: include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/shaders/skinned.vs.glsl")).into(), 
+            stage: ShaderStage::Vertex, 
+        }, 
+        device
+    )?;
+
+    let fragment_shader = Shader::from_source(
+        "skinned.fs", 
+        &ShaderSource::Glsl {
+            code: include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/shaders/skinned.fs.glsl")).into(), 
+            stage: ShaderStage::Fragment, 
+        }, 
+        device
+    )?;
+
+    let material = MaterialBuilder::new("SkinnedMaterial", vertex_shader, fragment_shader)
+        .set_vertex_format(VertexFormat::POSITION_NORMAL_SKIN)
+        .add_bind_group_layout::<CameraUniformLayout>()
+        .add_bind_group_layout::<EntityUniformLayout>()
+        .add_bind_group_layout::<SkinUniformLayout>()
+        .set_blend_mode(BlendMode::Opaque)
+        .build();
+    Ok(material)
 }
 
-impl GraphicsPipeline for TransparentPipeline {
-    #[inline]
-    fn bind<'a>(&'a self, rpass: &mut wgpu::RenderPass<'a>) {
-        rpass.set_pipeline(&self.inner)
-    }
+/// #### 한국어 </br>
+/// 투명한 오브젝트의 누적값과 노출값을 계산하여 저장하는 재질을 생성합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates a material that computes and stores the accumulated and revealed values of transparent objects. </br>
+/// 
+pub fn create_transparent_material(device: &wgpu::Device) -> Result<Material, ShaderError> {
+    let vertex_shader = Shader::from_source(
+        "transparent.vs", 
+        &ShaderSource::SpirV(include_bytes!(concat!(env!("CARGO_MANIFEST_DIR"), "/shaders/transparent.vs.spv")).into()), 
+        device
+    )?;
+
+    let fragment_shader = Shader::from_source(
+        "transparent.fs", 
+        &ShaderSource::SpirV(include_bytes!(concat!(env!("CARGO_MANIFEST_DIR"), "/shaders/transparent.fs.spv")).into()), 
+        device
+    )?;
+
+    let material = MaterialBuilder::new("TransparentMaterial", vertex_shader, fragment_shader)
+        .set_vertex_format(VertexFormat::POSITION_NORMAL)
+        .add_bind_group_layout::<CameraUniformLayout>()
+        .add_bind_group_layout::<EntityUniformLayout>()
+        .set_blend_mode(BlendMode::WeightedBlendedOIT)
+        .set_depth_write_enabled(false)
+        .build();
+    Ok(material)
 }
 
-impl Eq for TransparentPipeline { }
+/// #### 한국어 </br>
+/// 불투명한 물체와 투명한 물체를 혼합하는 재질을 생성합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates a material that blends opaque and transparent objects. </br>
+/// 
+pub fn create_composite_material(device: &wgpu::Device) -> Result<Material, ShaderError> {
+    let vertex_shader = Shader::from_source(
+        "composite.vs", 
+        &ShaderSource::SpirV(include_bytes!(concat!(env!("CARGO_MANIFEST_DIR"), "/shaders/composite.vs.spv")).into()), 
+        device
+    )?;
+
+    let fragment_shader = Shader::from_source(
+        "composite.fs", 
+        &ShaderSource::SpirV(include_bytes!(concat!(env!("CARGO_MANIFEST_DIR"), "/shaders/composite.fs.spv")).into()), 
+        device
+    )?;
+
+    let material = MaterialBuilder::new("CompositeMaterial", vertex_shader, fragment_shader)
+        .add_bind_group_layout::<WeightedBlendedOIT>()
+        .set_topology(wgpu::PrimitiveTopology::TriangleStrip)
+        .set_front_face(wgpu::FrontFace::Cw)
+        .set_blend_mode(BlendMode::Alpha)
+        .build();
+    Ok(material)
+}
 
-impl PartialEq<Self> for TransparentPipeline {
-    #[inline]
-    fn eq(&self, other: &Self) -> bool {
-        self.inner.global_id().eq(&other.inner.global_id())
-    }
+/// #### 한국어 </br>
+/// `DebugDraw`에 수집된 선들을 그리는 재질을 생성합니다. </br>
+/// 선들은 깊이 테스트를 거치지만 깊이 버퍼에 기록되지는 않습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates a material that draws the lines collected in `DebugDraw`. </br>
+/// Lines are depth tested but are not written to the depth buffer. </br>
+/// 
+pub fn create_debug_draw_material(device: &wgpu::Device) -> Result<Material, ShaderError> {
+    use wgpu::naga::ShaderStage;
+
+    let vertex_shader = Shader::from_source(
+        "debug_draw.vs", 
+        &ShaderSource::Glsl {
+            code: include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/shaders/debug_draw.vs.glsl")).into(), 
+            stage: ShaderStage::Vertex, 
+        }, 
+        device
+    )?;
+
+    let fragment_shader = Shader::from_source(
+        "debug_draw.fs", 
+        &ShaderSource::Glsl {
+            code: include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/shaders/debug_draw.fs.glsl")).into(), 
+            stage: ShaderStage::Fragment, 
+        }, 
+        device
+    )?;
+
+    let material = MaterialBuilder::new("DebugDrawMaterial", vertex_shader, fragment_shader)
+        .add_vertex_layout(DEBUG_DRAW_VERTEX_BUFFER_LAYOUT)
+        .add_bind_group_layout::<CameraUniformLayout>()
+        .set_topology(wgpu::PrimitiveTopology::LineList)
+        .set_cull_mode(None)
+        .set_blend_mode(BlendMode::Alpha)
+        .set_depth_write_enabled(false)
+        .set_depth_compare(wgpu::CompareFunction::LessEqual)
+        .build();
+    Ok(material)
 }
 
-impl hash::Hash for TransparentPipeline {
-    #[inline]
-    fn hash<H: hash::Hasher>(&self, state: &mut H) {
-        self.inner.global_id().hash(state)
-    }
+/// #### 한국어 </br>
+/// 화면 공간 오버레이의 텍스트와 패널을 그리는 재질을 생성합니다. </br>
+/// 오버레이는 깊이 버퍼와 상관없이 항상 장면 위에 그려집니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates a material that draws the text and panels of the screen space overlay. </br>
+/// The overlay is always drawn on top of the scene regardless of the depth buffer. </br>
+/// 
+pub fn create_hud_material(device: &wgpu::Device) -> Result<Material, ShaderError> {
+    use wgpu::naga::ShaderStage;
+
+    let vertex_shader = Shader::from_source(
+        "hud.vs", 
+        &ShaderSource::Glsl {
+            code: include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/shaders/hud.vs.glsl")).into(), 
+            stage: ShaderStage::Vertex, 
+        }, 
+        device
+    )?;
+
+    let fragment_shader = Shader::from_source(
+        "hud.fs", 
+        &ShaderSource::Glsl {
+            code: include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/shaders/hud.fs.glsl")).into(), 
+            stage: ShaderStage::Fragment, 
+        }, 
+        device
+    )?;
+
+    let material = MaterialBuilder::new("HudMaterial", vertex_shader, fragment_shader)
+        .add_vertex_layout(HUD_VERTEX_BUFFER_LAYOUT)
+        .add_bind_group_layout::<HudUniformLayout>()
+        .set_cull_mode(None)
+        .set_blend_mode(BlendMode::Alpha)
+        .set_depth_write_enabled(false)
+        .set_depth_compare(wgpu::CompareFunction::Always)
+        .build();
+    Ok(material)
 }
 
 
 
 /// #### 한국어 </br>
-/// 불투명한 물체와 투명한 물체를 혼합하는 그래픽스 파이프라인 입니다. </br>
+/// 디버깅을 위한 장면의 시각화 방식입니다. </br>
 /// 
 /// #### English (Translation) </br>
-/// A graphics pipeline that blends opaque and transparent objects. </br>
+/// The visualization mode of the scene for debugging. </br>
 /// 
-#[derive(Debug, Clone)]
-pub struct CompositePipeline {
-    inner: Arc<wgpu::RenderPipeline>, 
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DebugViewMode {
+    #[default]
+    Shaded, 
+    Wireframe, 
+    Normals, 
+    Depth, 
+    Overdraw, 
 }
 
-impl CompositePipeline {
-    pub fn new(
-        device: &wgpu::Device, 
-        layouts: &HashMap<TypeId, wgpu::BindGroupLayout>, 
-    ) -> Self {
-        let bind_group_layouts = &[
-            layouts.get(&TypeId::of::<WeightedBlendedOIT>())
-                .expect("WeightedBlendedOIT not found!"), 
-        ];
-
-        let vertex_shader = device.create_shader_module(
-            wgpu::include_spirv!(concat!(env!("CARGO_MANIFEST_DIR"), "/shaders/composite.vs.spv"))
-        );
-
-        let fragment_shader = device.create_shader_module(
-            wgpu::include_spirv!(concat!(env!("CARGO_MANIFEST_DIR"), "/shaders/composite.fs.spv"))
-        );
-
-        let pipeline_layout = device.create_pipeline_layout(
-            &wgpu::PipelineLayoutDescriptor {
-                label: Some("PipelineLayout(CompositePipeline)"), 
-                bind_group_layouts, 
-                push_constant_ranges: &[],
-            },
-        );
-
-        let pipeline = device.create_render_pipeline(
-            &wgpu::RenderPipelineDescriptor {
-                label: Some("RenderPipeline(CompositePipeline)"), 
-                layout: Some(&pipeline_layout), 
-                vertex: wgpu::VertexState {
-                    module: &vertex_shader, 
-                    entry_point: "main", 
-                    buffers: &[],
-                },
-                primitive: wgpu::PrimitiveState {
-                    topology: wgpu::PrimitiveTopology::TriangleStrip, 
-                    strip_index_format: Some(wgpu::IndexFormat::Uint16), 
-                    front_face: wgpu::FrontFace::Cw, 
-                    cull_mode: Some(wgpu::Face::Back), 
-                    polygon_mode: wgpu::PolygonMode::Fill, 
-                    ..Default::default()
-                }, 
-                depth_stencil: Some(wgpu::DepthStencilState {
-                    format: wgpu::TextureFormat::Depth32Float, 
-                    depth_write_enabled: true, 
-                    depth_compare: wgpu::CompareFunction::Less, 
-                    stencil: wgpu::StencilState::default(), 
-                    bias: wgpu::DepthBiasState::default(), 
-                }), 
-                multisample: wgpu::MultisampleState::default(), 
-                fragment: Some(wgpu::FragmentState {
-                    module: &fragment_shader, 
-                    entry_point: "main", 
-                    targets: &[
-                        Some(wgpu::ColorTargetState {
-                            format: RENDER_TARGET_FORMAT, 
-                            blend: Some(wgpu::BlendState::ALPHA_BLENDING), 
-                            write_mask: wgpu::ColorWrites::ALL,
-                        }),
-                    ],
-                }),
-                multiview: None,
-            },
-        );
-
-        return Self {
-            inner: pipeline.into(), 
-        };
+impl DebugViewMode {
+    /// #### 한국어 </br>
+    /// 다음 시각화 방식을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the next visualization mode. </br>
+    /// 
+    #[inline]
+    pub fn next(&self) -> Self {
+        match self {
+            Self::Shaded => Self::Wireframe, 
+            Self::Wireframe => Self::Normals, 
+            Self::Normals => Self::Depth, 
+            Self::Depth => Self::Overdraw, 
+            Self::Overdraw => Self::Shaded, 
+        }
     }
-}
 
-impl GraphicsPipeline for CompositePipeline {
+    /// #### 한국어 </br>
+    /// 렌더 타겟을 지울 때 사용할 색상을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the color to use when clearing the render target. </br>
+    /// 
     #[inline]
-    fn bind<'a>(&'a self, rpass: &mut wgpu::RenderPass<'a>) {
-        rpass.set_pipeline(&self.inner)
+    pub fn clear_color(&self) -> wgpu::Color {
+        match self {
+            Self::Shaded | Self::Wireframe => wgpu::Color::WHITE, 
+            Self::Normals | Self::Depth | Self::Overdraw => wgpu::Color::BLACK, 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 와이어프레임을 무게중심 좌표로 그려야 하는지 여부를 반환합니다. </br>
+    /// `PolygonMode::Line`을 지원하지 않는 장치(GLES 또는 일부 Vulkan 장치)에서 사용됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns whether the wireframe should be drawn with barycentric coordinates. </br>
+    /// Used on devices that do not support `PolygonMode::Line` (GLES or some Vulkan devices). </br>
+    /// 
+    #[inline]
+    pub fn uses_barycentric_wireframe(&self, features: wgpu::Features) -> bool {
+        *self == Self::Wireframe && !features.contains(wgpu::Features::POLYGON_MODE_LINE)
     }
 }
 
-impl Eq for CompositePipeline { }
 
-impl PartialEq<Self> for CompositePipeline {
-    #[inline]
-    fn eq(&self, other: &Self) -> bool {
-        self.inner.global_id().eq(&other.inner.global_id())
+
+/// #### 한국어 </br>
+/// 디버그 시각화 재질에 사용되는 셰이더들의 집합입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A set of shaders used for debug visualization materials. </br>
+/// 
+#[derive(Debug, Clone)]
+pub struct DebugShaders {
+    vertex: Shader, 
+    line: Shader, 
+    barycentric: Shader, 
+    normal: Shader, 
+    depth: Shader, 
+    overdraw: Shader, 
+}
+
+impl DebugShaders {
+    pub fn new(device: &wgpu::Device) -> Result<Self, ShaderError> {
+        use wgpu::naga::ShaderStage;
+
+        let create_shader = |name: &str, code: &'static str, stage: ShaderStage| {
+            Shader::from_source(name, &ShaderSource::Glsl { code: code.into(), stage }, device)
+        };
+
+        Ok(Self {
+            vertex: create_shader(
+                "debug.vs", 
+                include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/shaders/debug.vs.glsl")), 
+                ShaderStage::Vertex
+            )?, 
+            line: create_shader(
+                "debug_line.fs", 
+                include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/shaders/debug_line.fs.glsl")), 
+                ShaderStage::Fragment
+            )?, 
+            barycentric: create_shader(
+                "debug_barycentric.fs", 
+                include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/shaders/debug_barycentric.fs.glsl")), 
+                ShaderStage::Fragment
+            )?, 
+            normal: create_shader(
+                "debug_normal.fs", 
+                include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/shaders/debug_normal.fs.glsl")), 
+                ShaderStage::Fragment
+            )?, 
+            depth: create_shader(
+                "debug_depth.fs", 
+                include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/shaders/debug_depth.fs.glsl")), 
+                ShaderStage::Fragment
+            )?, 
+            overdraw: create_shader(
+                "debug_overdraw.fs", 
+                include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/shaders/debug_overdraw.fs.glsl")), 
+                ShaderStage::Fragment
+            )?, 
+        })
     }
 }
 
-impl hash::Hash for CompositePipeline {
-    #[inline]
-    fn hash<H: hash::Hasher>(&self, state: &mut H) {
-        self.inner.global_id().hash(state)
+/// #### 한국어 </br>
+/// 주어진 재질의 디버그 시각화 변형 재질을 생성합니다. </br>
+/// 재질의 버텍스 형식에 위치와 법선이 없거나 `DebugViewMode::Shaded`인 경우 `None`을 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates a debug visualization variant of the given material. </br>
+/// Returns `None` if the material's vertex format has no positions and normals or the mode is `DebugViewMode::Shaded`. </br>
+/// 
+pub fn create_debug_material(
+    material: &Material, 
+    mode: DebugViewMode, 
+    shaders: &DebugShaders, 
+    features: wgpu::Features
+) -> Option<Material> {
+    if !material.vertex_format.is_some_and(|format| format.contains_all(VertexFormat::POSITION_NORMAL)) {
+        return None;
     }
+
+    let builder = MaterialBuilder::from(material.clone())
+        .set_vertex_format(VertexFormat::POSITION_NORMAL);
+    let builder = MaterialBuilder {
+        label: format!("{}({:?})", material.label, mode), 
+        vertex_shader: shaders.vertex.clone(), 
+        bind_group_layouts: vec![
+            TypeId::of::<CameraUniformLayout>(), 
+            TypeId::of::<EntityUniformLayout>(), 
+        ], 
+        blend_mode: BlendMode::Opaque, 
+        depth_write_enabled: true, 
+        depth_compare: wgpu::CompareFunction::Less, 
+        ..builder
+    };
+
+    let builder = match mode {
+        DebugViewMode::Shaded => return None, 
+        DebugViewMode::Wireframe if mode.uses_barycentric_wireframe(features) => MaterialBuilder {
+            fragment_shader: shaders.barycentric.clone(), 
+            cull_mode: None, 
+            ..builder
+        }, 
+        DebugViewMode::Wireframe => MaterialBuilder {
+            fragment_shader: shaders.line.clone(), 
+            polygon_mode: wgpu::PolygonMode::Line, 
+            cull_mode: None, 
+            ..builder
+        }, 
+        DebugViewMode::Normals => MaterialBuilder {
+            fragment_shader: shaders.normal.clone(), 
+            ..builder
+        }, 
+        DebugViewMode::Depth => MaterialBuilder {
+            fragment_shader: shaders.depth.clone(), 
+            ..builder
+        }, 
+        DebugViewMode::Overdraw => MaterialBuilder {
+            fragment_shader: shaders.overdraw.clone(), 
+            blend_mode: BlendMode::Additive, 
+            depth_write_enabled: false, 
+            depth_compare: wgpu::CompareFunction::Always, 
+            cull_mode: None, 
+            ..builder
+        }, 
+    };
+
+    Some(builder.build())
 }
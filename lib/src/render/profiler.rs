@@ -0,0 +1,431 @@
+use std::io;
+use std::io::Write;
+use std::fs::File;
+use std::path::Path;
+use std::sync::Arc;
+use std::sync::atomic::AtomicU8;
+use std::sync::atomic::AtomicU32;
+use std::sync::atomic::Ordering;
+use std::collections::VecDeque;
+
+
+
+/// #### 한국어 </br>
+/// 결과를 기다리는 동안 동시에 사용할 수 있는 읽기용 버퍼의 수입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The number of readback buffers that can be used at the same time while waiting for results. </br>
+/// 
+const NUM_READBACKS: usize = 3;
+
+/// #### 한국어 </br>
+/// 패스별 평균 시간을 계산할 때 사용하는 최근 샘플의 수입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The number of recent samples used to calculate the average time per pass. </br>
+/// 
+const NUM_SAMPLES: usize = 60;
+
+/// #### 한국어 </br>
+/// `CSV`로 내보내기 위해 보관하는 최대 프레임 기록의 수입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The maximum number of frame records kept for `CSV` export. </br>
+/// 
+const MAX_HISTORY: usize = 1000;
+
+/// #### 한국어 </br>
+/// 측정할 수 있는 최대 패스의 수입니다. 기록된 패스들은 `u32` 비트 마스크로 표시됩니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The maximum number of passes that can be measured. The written passes are marked in a `u32` bit mask. </br>
+/// 
+pub const MAX_PASSES: usize = u32::BITS as usize;
+
+const STATE_IDLE: u8 = 0;
+const STATE_MAPPING: u8 = 1;
+const STATE_MAPPED: u8 = 2;
+const STATE_FAILED: u8 = 3;
+
+
+
+/// #### 한국어 </br>
+/// 한 프레임의 CPU 시간과 패스별 GPU 시간 기록입니다. </br>
+/// 기록되지 않은 패스의 시간은 `None` 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The record of the CPU time and the GPU time per pass of a frame. </br>
+/// The time of passes that were not recorded is `None`. </br>
+/// 
+#[derive(Debug, Clone, PartialEq)]
+pub struct GpuFrameRecord {
+    pub frame_index: u64, 
+    pub cpu_time_ms: f32, 
+    pub pass_times_ms: Vec<Option<f32>>, 
+}
+
+
+
+/// #### 한국어 </br>
+/// 타임스탬프 쿼리 결과를 CPU로 읽어오기 위한 버퍼입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A buffer for reading timestamp query results back to the CPU. </br>
+/// 
+#[derive(Debug)]
+struct Readback {
+    buffer: wgpu::Buffer, 
+    state: Arc<AtomicU8>, 
+    written: AtomicU32, 
+    frame_index: u64, 
+    cpu_time_ms: f32, 
+}
+
+
+
+/// #### 한국어 </br>
+/// 렌더 패스들의 시작과 끝에 타임스탬프를 기록하여 GPU 시간을 측정하는 프로파일러입니다. </br>
+/// 장치에 `Features::TIMESTAMP_QUERY`가 활성화된 경우에만 생성됩니다. </br>
+/// 
+/// 결과는 비동기적으로 읽어오므로 몇 프레임 늦게 반영됩니다. </br>
+/// 장면은 `on_update`에서 `begin_frame`을 호출하고, `on_draw`에서 `timestamp_writes`, `resolve`,
+/// 그리고 명령어 제출 후 `map_results`를 호출합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A profiler that measures GPU time by writing timestamps at the beginning and end of render passes. </br>
+/// It is created only if `Features::TIMESTAMP_QUERY` is enabled on the device. </br>
+/// 
+/// Results are read back asynchronously, so they are reflected a few frames later. </br>
+/// The scene calls `begin_frame` in `on_update`, and `timestamp_writes`, `resolve`,
+/// and `map_results` after submitting commands in `on_draw`. </br>
+/// 
+#[derive(Debug)]
+pub struct GpuProfiler {
+    pass_names: Vec<&'static str>, 
+    query_set: wgpu::QuerySet, 
+    resolve_buffer: wgpu::Buffer, 
+    readbacks: Vec<Readback>, 
+    current: Option<usize>, 
+    frame_index: u64, 
+    timestamp_period: f32, 
+    samples: Vec<VecDeque<f32>>, 
+    history: VecDeque<GpuFrameRecord>, 
+}
+
+#[allow(dead_code)]
+impl GpuProfiler {
+    /// #### 한국어 </br>
+    /// 주어진 이름의 패스들을 측정하는 프로파일러를 생성합니다. </br>
+    /// 장치가 `Features::TIMESTAMP_QUERY`를 지원하지 않거나 패스의 수가 `MAX_PASSES`보다 많은 경우 `None`을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates a profiler that measures passes with the given names. </br>
+    /// Returns `None` if the device does not support `Features::TIMESTAMP_QUERY` or there are more passes than `MAX_PASSES`. </br>
+    /// 
+    pub fn new(pass_names: &[&'static str], device: &wgpu::Device, queue: &wgpu::Queue) -> Option<Self> {
+        if !device.features().contains(wgpu::Features::TIMESTAMP_QUERY) || pass_names.is_empty() {
+            return None;
+        }
+        if pass_names.len() > MAX_PASSES {
+            log::error!("The GPU profiler can measure at most {} passes, but {} were given", MAX_PASSES, pass_names.len());
+            return None;
+        }
+
+        let num_queries = 2 * pass_names.len() as u32;
+        let size = num_queries as wgpu::BufferAddress * wgpu::QUERY_SIZE as wgpu::BufferAddress;
+
+        // (한국어) 쿼리 결과를 복사하는 위치는 `QUERY_RESOLVE_BUFFER_ALIGNMENT`로 정렬되어야 하므로 패스마다 정렬된 영역을 사용합니다.
+        // (English Translation) The location where query results are copied must be aligned to `QUERY_RESOLVE_BUFFER_ALIGNMENT`, so an aligned region is used for each pass.
+        let resolve_size = pass_names.len() as wgpu::BufferAddress * wgpu::QUERY_RESOLVE_BUFFER_ALIGNMENT;
+
+        let query_set = device.create_query_set(
+            &wgpu::QuerySetDescriptor {
+                label: Some("QuerySet(GpuProfiler)"), 
+                ty: wgpu::QueryType::Timestamp, 
+                count: num_queries, 
+            }, 
+        );
+
+        let resolve_buffer = device.create_buffer(
+            &wgpu::BufferDescriptor {
+                label: Some("ResolveBuffer(GpuProfiler)"), 
+                size: resolve_size, 
+                usage: wgpu::BufferUsages::QUERY_RESOLVE | wgpu::BufferUsages::COPY_SRC, 
+                mapped_at_creation: false, 
+            }, 
+        );
+
+        let readbacks = (0..NUM_READBACKS)
+            .map(|index| Readback {
+                buffer: device.create_buffer(
+                    &wgpu::BufferDescriptor {
+                        label: Some(&format!("ReadbackBuffer(GpuProfiler, {})", index)), 
+                        size, 
+                        usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ, 
+                        mapped_at_creation: false, 
+                    }, 
+                ), 
+                state: Arc::new(AtomicU8::new(STATE_IDLE)), 
+                written: AtomicU32::new(0), 
+                frame_index: 0, 
+                cpu_time_ms: 0.0, 
+            })
+            .collect();
+
+        Some(Self {
+            pass_names: pass_names.to_vec(), 
+            query_set, 
+            resolve_buffer, 
+            readbacks, 
+            current: None, 
+            frame_index: 0, 
+            timestamp_period: queue.get_timestamp_period(), 
+            samples: vec![VecDeque::with_capacity(NUM_SAMPLES); pass_names.len()], 
+            history: VecDeque::new(), 
+        })
+    }
+
+    #[inline]
+    pub fn pass_names(&self) -> &[&'static str] {
+        &self.pass_names
+    }
+
+    /// #### 한국어 </br>
+    /// 읽어오기가 끝난 결과들을 수집하고, 이번 프레임에 사용할 읽기용 버퍼를 선택합니다. </br>
+    /// 사용할 수 있는 버퍼가 없다면 이번 프레임은 측정하지 않습니다. </br>
+    /// `cpu_time_ms`는 이번 프레임의 기록에 함께 저장되는 CPU 프레임 시간입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Collects the results that have finished reading back, and selects a readback buffer to use for this frame. </br>
+    /// If there is no buffer available, this frame is not measured. </br>
+    /// `cpu_time_ms` is the CPU frame time stored together in the record of this frame. </br>
+    /// 
+    pub fn begin_frame(&mut self, cpu_time_ms: f32) {
+        self.collect_results();
+
+        self.frame_index += 1;
+        self.current = self.readbacks.iter()
+            .position(|readback| readback.state.load(Ordering::Acquire) == STATE_IDLE);
+
+        if let Some(readback) = self.current.map(|index| &mut self.readbacks[index]) {
+            readback.written.store(0, Ordering::Relaxed);
+            readback.frame_index = self.frame_index;
+            readback.cpu_time_ms = cpu_time_ms;
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// `pass`번째 패스의 시작과 끝에 타임스탬프를 기록하는 설정을 반환합니다. </br>
+    /// 이번 프레임을 측정하지 않는 경우 `None`을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the settings that write timestamps at the beginning and end of the `pass`-th pass. </br>
+    /// Returns `None` if this frame is not measured. </br>
+    /// 
+    pub fn timestamp_writes(&self, pass: usize) -> Option<wgpu::RenderPassTimestampWrites<'_>> {
+        let readback = self.current_readback()?;
+        if pass >= self.pass_names.len() {
+            return None;
+        }
+
+        readback.written.fetch_or(1 << pass, Ordering::Relaxed);
+        Some(wgpu::RenderPassTimestampWrites {
+            query_set: &self.query_set, 
+            beginning_of_pass_write_index: Some(2 * pass as u32), 
+            end_of_pass_write_index: Some(2 * pass as u32 + 1), 
+        })
+    }
+
+    /// #### 한국어 </br>
+    /// 기록된 타임스탬프들을 이번 프레임의 읽기용 버퍼로 복사하는 명령을 추가합니다. </br>
+    /// 기록되지 않은 쿼리는 결과를 기다리지 않도록 복사하지 않습니다. </br>
+    /// 모든 패스를 기록한 후, 명령어 목록을 제출하기 전에 호출해야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Adds commands that copy the written timestamps to the readback buffer of this frame. </br>
+    /// Queries that were not written are not copied so as not to wait for their results. </br>
+    /// It must be called after all passes are recorded and before the command list is submitted. </br>
+    /// 
+    pub fn resolve(&self, encoder: &mut wgpu::CommandEncoder) {
+        let Some(readback) = self.current_readback() else {
+            return;
+        };
+
+        let written = readback.written.load(Ordering::Relaxed);
+        let pair_size = 2 * wgpu::QUERY_SIZE as wgpu::BufferAddress;
+        for pass in (0..self.pass_names.len()).filter(|pass| written & (1 << pass) != 0) {
+            let resolve_offset = pass as wgpu::BufferAddress * wgpu::QUERY_RESOLVE_BUFFER_ALIGNMENT;
+            let query = 2 * pass as u32;
+            encoder.resolve_query_set(&self.query_set, query..(query + 2), &self.resolve_buffer, resolve_offset);
+            encoder.copy_buffer_to_buffer(
+                &self.resolve_buffer, 
+                resolve_offset, 
+                &readback.buffer, 
+                pass as wgpu::BufferAddress * pair_size, 
+                pair_size
+            );
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 이번 프레임의 읽기용 버퍼를 비동기적으로 읽어오기 시작합니다. 명령어 목록을 제출한 후에 호출해야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Starts reading back the readback buffer of this frame asynchronously. It must be called after the command list is submitted. </br>
+    /// 
+    pub fn map_results(&self) {
+        let Some(readback) = self.current_readback() else {
+            return;
+        };
+        if readback.written.load(Ordering::Relaxed) == 0 {
+            return;
+        }
+
+        let state = readback.state.clone();
+        readback.buffer.slice(..).map_async(wgpu::MapMode::Read, move |result| {
+            let next = match result {
+                Ok(_) => STATE_MAPPED, 
+                Err(_) => STATE_FAILED, 
+            };
+            state.store(next, Ordering::Release);
+        });
+        readback.state.store(STATE_MAPPING, Ordering::Release);
+    }
+
+    /// #### 한국어 </br>
+    /// 패스별 최근 GPU 시간의 평균(밀리초)을 반환합니다. 아직 결과가 없는 패스는 `None` 입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the average of recent GPU times per pass (in milliseconds). Passes without results yet are `None`. </br>
+    /// 
+    pub fn pass_times_ms(&self) -> impl Iterator<Item = (&'static str, Option<f32>)> + '_ {
+        self.pass_names.iter()
+            .zip(self.samples.iter())
+            .map(|(name, samples)| {
+                let average = (!samples.is_empty())
+                    .then(|| samples.iter().sum::<f32>() / samples.len() as f32);
+                (*name, average)
+            })
+    }
+
+    /// #### 한국어 </br>
+    /// 측정된 패스들의 GPU 시간 평균의 합(밀리초)을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the sum of the average GPU times of the measured passes (in milliseconds). </br>
+    /// 
+    pub fn total_time_ms(&self) -> f32 {
+        self.pass_times_ms()
+            .filter_map(|(_, time)| time)
+            .sum()
+    }
+
+    #[inline]
+    pub fn history(&self) -> impl Iterator<Item = &GpuFrameRecord> {
+        self.history.iter()
+    }
+
+    /// #### 한국어 </br>
+    /// 보관된 프레임 기록들을 `CSV` 형식으로 씁니다. </br>
+    /// 첫 번째 행은 `frame,cpu_ms,<pass>_gpu_ms,...` 형식의 머리글 입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Writes the kept frame records in `CSV` format. </br>
+    /// The first row is a header in the form of `frame,cpu_ms,<pass>_gpu_ms,...`. </br>
+    /// 
+    pub fn write_csv<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        write!(writer, "frame,cpu_ms")?;
+        for name in self.pass_names.iter() {
+            write!(writer, ",{}_gpu_ms", name.to_lowercase())?;
+        }
+        writeln!(writer)?;
+
+        for record in self.history.iter() {
+            write!(writer, "{},{:.4}", record.frame_index, record.cpu_time_ms)?;
+            for time in record.pass_times_ms.iter() {
+                match time {
+                    Some(time) => write!(writer, ",{:.4}", time)?, 
+                    None => write!(writer, ",")?, 
+                };
+            }
+            writeln!(writer)?;
+        }
+        Ok(())
+    }
+
+    /// #### 한국어 </br>
+    /// 보관된 프레임 기록들을 `CSV` 파일로 내보냅니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Exports the kept frame records to a `CSV` file. </br>
+    /// 
+    pub fn export_csv<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let file = File::create(path)?;
+        self.write_csv(io::BufWriter::new(file))
+    }
+
+    #[inline]
+    fn current_readback(&self) -> Option<&Readback> {
+        self.current
+            .map(|index| &self.readbacks[index])
+            .filter(|readback| readback.state.load(Ordering::Acquire) == STATE_IDLE)
+    }
+
+    /// #### 한국어 </br>
+    /// 읽어오기가 끝난 버퍼들의 타임스탬프를 패스별 시간으로 변환하여 저장합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Converts the timestamps of the buffers that have finished reading back into times per pass and stores them. </br>
+    /// 
+    fn collect_results(&mut self) {
+        let mut records = Vec::new();
+        for readback in self.readbacks.iter() {
+            match readback.state.load(Ordering::Acquire) {
+                STATE_MAPPED => {
+                    let timestamps: Vec<u64> = {
+                        let data = readback.buffer.slice(..).get_mapped_range();
+                        bytemuck::cast_slice(&data).to_vec()
+                    };
+                    readback.buffer.unmap();
+
+                    let written = readback.written.load(Ordering::Relaxed);
+                    let pass_times_ms = (0..self.pass_names.len())
+                        .map(|pass| {
+                            let (begin, end) = (timestamps[2 * pass], timestamps[2 * pass + 1]);
+                            (written & (1 << pass) != 0 && end >= begin)
+                                .then(|| ((end - begin) as f64 * self.timestamp_period as f64 / 1_000_000.0) as f32)
+                        })
+                        .collect();
+
+                    records.push(GpuFrameRecord {
+                        frame_index: readback.frame_index, 
+                        cpu_time_ms: readback.cpu_time_ms, 
+                        pass_times_ms, 
+                    });
+                    readback.state.store(STATE_IDLE, Ordering::Release);
+                }, 
+                STATE_FAILED => {
+                    readback.state.store(STATE_IDLE, Ordering::Release);
+                }, 
+                _ => { /* empty */ }
+            }
+        }
+
+        records.sort_by_key(|record| record.frame_index);
+        for record in records {
+            for (samples, time) in self.samples.iter_mut().zip(record.pass_times_ms.iter()) {
+                if let Some(time) = time {
+                    if samples.len() == NUM_SAMPLES {
+                        samples.pop_front();
+                    }
+                    samples.push_back(*time);
+                }
+            }
+
+            if self.history.len() == MAX_HISTORY {
+                self.history.pop_front();
+            }
+            self.history.push_back(record);
+        }
+    }
+}
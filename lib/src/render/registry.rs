@@ -0,0 +1,159 @@
+use std::sync::Arc;
+use std::any::TypeId;
+use std::collections::HashMap;
+
+use crate::render::mesh::ModelMesh;
+use crate::render::mesh::create_cube_mesh;
+use crate::render::mesh::create_plane_mesh;
+use crate::render::shape;
+
+
+
+/// #### 한국어 </br>
+/// 메쉬를 생성하기 위한 CPU 측 서술입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The CPU-side description for creating a mesh. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MeshDescriptor {
+    Plane { width: f32, height: f32 }, 
+    Cube { width: f32, height: f32, depth: f32 }, 
+    UvSphere { radius: f32, segments: u32, rings: u32 }, 
+    IcoSphere { radius: f32, subdivisions: u32 }, 
+    Cylinder { radius: f32, height: f32, segments: u32 }, 
+    Cone { radius: f32, height: f32, segments: u32 }, 
+    Torus { major_radius: f32, minor_radius: f32, major_segments: u32, minor_segments: u32 }, 
+    Capsule { radius: f32, height: f32, segments: u32, rings: u32 }, 
+    Grid { width: f32, depth: f32, x_segments: u32, z_segments: u32 }, 
+}
+
+impl MeshDescriptor {
+    /// #### 한국어 </br>
+    /// 서술로부터 주어진 장치에서 메쉬를 생성합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates a mesh on the given device from the description. </br>
+    /// 
+    pub fn create(&self, device: &wgpu::Device, queue: &wgpu::Queue) -> ModelMesh {
+        match *self {
+            Self::Plane { width, height } => create_plane_mesh(width, height, device, queue), 
+            Self::Cube { width, height, depth } => create_cube_mesh(width, height, depth, device, queue), 
+            Self::UvSphere { radius, segments, rings } => shape::uv_sphere(radius, segments, rings)
+                .with_label("UvSphereMesh").upload(device, queue), 
+            Self::IcoSphere { radius, subdivisions } => shape::icosphere(radius, subdivisions)
+                .with_label("IcoSphereMesh").upload(device, queue), 
+            Self::Cylinder { radius, height, segments } => shape::cylinder(radius, height, segments)
+                .with_label("CylinderMesh").upload(device, queue), 
+            Self::Cone { radius, height, segments } => shape::cone(radius, height, segments)
+                .with_label("ConeMesh").upload(device, queue), 
+            Self::Torus { major_radius, minor_radius, major_segments, minor_segments } => shape::torus(major_radius, minor_radius, major_segments, minor_segments)
+                .with_label("TorusMesh").upload(device, queue), 
+            Self::Capsule { radius, height, segments, rings } => shape::capsule(radius, height, segments, rings)
+                .with_label("CapsuleMesh").upload(device, queue), 
+            Self::Grid { width, depth, x_segments, z_segments } => shape::grid(width, depth, x_segments, z_segments)
+                .with_label("GridMesh").upload(device, queue), 
+        }
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 바인드 그룹 레이아웃을 생성하는 함수입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A function that creates a bind group layout. </br>
+/// 
+pub type LayoutConstructor = fn(&wgpu::Device) -> wgpu::BindGroupLayout;
+
+
+
+/// #### 한국어 </br>
+/// GPU 자원을 CPU 측 서술과 함께 보관하는 저장소입니다. </br>
+/// 장치가 손실된 경우 새로운 장치에서 모든 자원을 서술로부터 다시 생성할 수 있습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A registry that keeps GPU resources together with their CPU-side descriptions. </br>
+/// If the device is lost, all resources can be recreated from their descriptions on a new device. </br>
+/// 
+#[derive(Debug, Default)]
+pub struct ResourceRegistry {
+    layouts: Vec<(TypeId, LayoutConstructor)>, 
+    meshes: Vec<(MeshDescriptor, ModelMesh)>, 
+}
+
+#[allow(dead_code)]
+impl ResourceRegistry {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// #### 한국어 </br>
+    /// 주어진 타입의 바인드 그룹 레이아웃을 생성하는 함수를 등록합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Registers a function that creates the bind group layout of the given type. </br>
+    /// 
+    pub fn register_layout<T: 'static>(&mut self, create: LayoutConstructor) {
+        let type_id = TypeId::of::<T>();
+        self.layouts.retain(|(it, _)| *it != type_id);
+        self.layouts.push((type_id, create));
+    }
+
+    /// #### 한국어 </br>
+    /// 등록된 모든 바인드 그룹 레이아웃을 주어진 장치에서 생성합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates all registered bind group layouts on the given device. </br>
+    /// 
+    pub fn create_layouts(&self, device: &wgpu::Device) -> Arc<HashMap<TypeId, wgpu::BindGroupLayout>> {
+        self.layouts.iter()
+            .map(|(type_id, create)| (*type_id, create(device)))
+            .collect::<HashMap<_, _>>()
+            .into()
+    }
+
+    /// #### 한국어 </br>
+    /// 서술로부터 메쉬를 생성하고 등록합니다. 이미 같은 서술의 메쉬가 있다면 그 메쉬를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates and registers a mesh from the description. If a mesh with the same description exists, returns that mesh. </br>
+    /// 
+    pub fn create_mesh(
+        &mut self, 
+        descriptor: MeshDescriptor, 
+        device: &wgpu::Device, 
+        queue: &wgpu::Queue
+    ) -> ModelMesh {
+        if let Some((_, mesh)) = self.meshes.iter().find(|(it, _)| *it == descriptor) {
+            return mesh.clone();
+        }
+
+        let mesh = descriptor.create(device, queue);
+        self.meshes.push((descriptor, mesh.clone()));
+        mesh
+    }
+
+    /// #### 한국어 </br>
+    /// 등록된 모든 메쉬를 새로운 장치에서 다시 생성합니다. </br>
+    /// 이전 메쉬의 식별자에서 새로운 메쉬로의 대응표를 반환하며, 엔티티의 메쉬를 교체하는 데 사용됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Recreates all registered meshes on a new device. </br>
+    /// Returns a map from the identifiers of the old meshes to the new ones, used to replace the meshes of entities. </br>
+    /// 
+    pub fn recreate_meshes(
+        &mut self, 
+        device: &wgpu::Device, 
+        queue: &wgpu::Queue
+    ) -> HashMap<u64, ModelMesh> {
+        let mut replaced = HashMap::with_capacity(self.meshes.len());
+        for (descriptor, mesh) in self.meshes.iter_mut() {
+            let new_mesh = descriptor.create(device, queue);
+            replaced.insert(std::mem::replace(mesh, new_mesh.clone()).id(), new_mesh);
+        }
+        replaced
+    }
+}
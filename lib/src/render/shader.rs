@@ -0,0 +1,410 @@
+use std::fs;
+use std::fmt;
+use std::io;
+use std::hash;
+use std::sync::Arc;
+use std::borrow::Cow;
+use std::path::Path;
+use std::path::PathBuf;
+use wgpu::naga;
+
+use crate::vfs::Vfs;
+
+
+
+/// #### 한국어 </br>
+/// 셰이더 소스를 읽거나 컴파일하는 도중 발생한 오류입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// An error that occurred while reading or compiling a shader source. </br>
+/// 
+#[derive(Debug)]
+pub enum ShaderError {
+    Io(PathBuf, io::Error), 
+    UnknownFormat(PathBuf), 
+    UnknownStage(PathBuf), 
+    NotFound(String), 
+    Parse(String), 
+    Validation(String), 
+}
+
+impl fmt::Display for ShaderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(path, err) => write!(f, "Could not read shader file {}: {}", path.display(), err), 
+            Self::UnknownFormat(path) => write!(f, "Unknown shader format: {}", path.display()), 
+            Self::UnknownStage(path) => write!(f, "Unknown shader stage: {}", path.display()), 
+            Self::NotFound(name) => write!(f, "Shader file not found: {}", name), 
+            Self::Parse(msg) => write!(f, "Shader parse error: {}", msg), 
+            Self::Validation(msg) => write!(f, "Shader validation error: {}", msg), 
+        }
+    }
+}
+
+impl std::error::Error for ShaderError { }
+
+impl From<wgpu::Error> for ShaderError {
+    #[inline]
+    fn from(value: wgpu::Error) -> Self {
+        match value {
+            wgpu::Error::Validation { description, .. } => Self::Validation(description), 
+            other => Self::Validation(other.to_string()), 
+        }
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 셰이더 모듈을 생성하기 위한 셰이더 소스 입니다. </br>
+/// `SPIR-V`, `WGSL`, `GLSL` 소스를 지원하며, 모든 소스는 `naga`를 통해 컴파일 및 검증됩니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The shader source for creating a shader module. </br>
+/// Supports `SPIR-V`, `WGSL` and `GLSL` sources, all of which are compiled and validated through `naga`. </br>
+/// 
+#[derive(Debug, Clone)]
+pub enum ShaderSource<'a> {
+    SpirV(Cow<'a, [u8]>), 
+    Wgsl(Cow<'a, str>), 
+    Glsl {
+        code: Cow<'a, str>, 
+        stage: naga::ShaderStage, 
+    }, 
+}
+
+#[allow(dead_code)]
+impl ShaderSource<'static> {
+    /// #### 한국어 </br>
+    /// 가상 파일 시스템의 파일에서 셰이더 소스를 읽어옵니다. </br>
+    /// 소스의 종류는 확장자(`.spv`, `.wgsl`, `.glsl`)로 결정되며,
+    /// `GLSL`의 셰이더 단계는 파일 이름(`*.vs.glsl`, `*.fs.glsl`, `*.cs.glsl`)으로 결정됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Reads the shader source from a file of the virtual file system. </br>
+    /// The kind of source is determined by the extension (`.spv`, `.wgsl`, `.glsl`),
+    /// and the `GLSL` shader stage is determined by the file name (`*.vs.glsl`, `*.fs.glsl`, `*.cs.glsl`). </br>
+    /// 
+    pub fn load<P: AsRef<Path>>(vfs: &Vfs, path: P) -> Result<Self, ShaderError> {
+        let path = path.as_ref();
+        let bytes = vfs.read(path)
+            .map_err(|err| ShaderError::Io(path.to_path_buf(), err))?;
+        Self::from_bytes(path, bytes)
+    }
+
+    /// #### 한국어 </br>
+    /// `path` 파일에서 읽은 내용으로 셰이더 소스를 생성합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates the shader source from the contents read from the `path` file. </br>
+    /// 
+    fn from_bytes(path: &Path, bytes: Vec<u8>) -> Result<Self, ShaderError> {
+        let extension = path.extension()
+            .and_then(|ext| ext.to_str())
+            .ok_or_else(|| ShaderError::UnknownFormat(path.to_path_buf()))?;
+        let into_code = |bytes: Vec<u8>| String::from_utf8(bytes)
+            .map_err(|err| ShaderError::Io(path.to_path_buf(), io::Error::new(io::ErrorKind::InvalidData, err)));
+
+        match extension {
+            "spv" => Ok(Self::SpirV(bytes.into())), 
+            "wgsl" => Ok(Self::Wgsl(into_code(bytes)?.into())), 
+            "glsl" | "vert" | "frag" | "comp" => {
+                let stage = shader_stage_from_path(path)
+                    .ok_or_else(|| ShaderError::UnknownStage(path.to_path_buf()))?;
+                Ok(Self::Glsl { code: into_code(bytes)?.into(), stage })
+            }, 
+            _ => Err(ShaderError::UnknownFormat(path.to_path_buf()))
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl<'a> ShaderSource<'a> {
+    /// #### 한국어 </br>
+    /// 셰이더 소스를 `naga` 모듈로 컴파일하고 검증합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Compiles and validates the shader source into a `naga` module. </br>
+    /// 
+    pub fn compile(&self) -> Result<naga::Module, ShaderError> {
+        let module = match self {
+            Self::SpirV(bytes) => {
+                naga::front::spv::parse_u8_slice(bytes, &naga::front::spv::Options::default())
+                    .map_err(|err| ShaderError::Parse(err.to_string()))?
+            }, 
+            Self::Wgsl(code) => {
+                naga::front::wgsl::parse_str(code)
+                    .map_err(|err| ShaderError::Parse(err.emit_to_string(code)))?
+            }, 
+            Self::Glsl { code, stage } => {
+                naga::front::glsl::Frontend::default()
+                    .parse(&naga::front::glsl::Options::from(*stage), code)
+                    .map_err(|errors| ShaderError::Parse(
+                        errors.iter()
+                            .map(|err| format!("{} (line {})", err.kind, err.meta.location(code).line_number))
+                            .collect::<Vec<_>>()
+                            .join("\n")
+                    ))?
+            }, 
+        };
+
+        naga::valid::Validator::new(
+            naga::valid::ValidationFlags::all(), 
+            naga::valid::Capabilities::all()
+        )
+        .validate(&module)
+        .map_err(|err| ShaderError::Validation(format!("{:?}", err.as_inner())))?;
+
+        Ok(module)
+    }
+
+    /// #### 한국어 </br>
+    /// 셰이더 소스를 컴파일하여 셰이더 모듈을 생성합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Compiles the shader source to create a shader module. </br>
+    /// 
+    pub fn create_shader_module(
+        &self, 
+        label: Option<&str>, 
+        device: &wgpu::Device
+    ) -> Result<wgpu::ShaderModule, ShaderError> {
+        let module = self.compile()?;
+        Ok(device.create_shader_module(
+            wgpu::ShaderModuleDescriptor {
+                label, 
+                source: wgpu::ShaderSource::Naga(Cow::Owned(module)), 
+            }
+        ))
+    }
+
+    /// #### 한국어 </br>
+    /// 빌린 데이터를 복사하여 소유한 셰이더 소스로 변환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Converts into an owned shader source by copying the borrowed data. </br>
+    /// 
+    pub fn into_owned(self) -> ShaderSource<'static> {
+        match self {
+            Self::SpirV(bytes) => ShaderSource::SpirV(Cow::Owned(bytes.into_owned())), 
+            Self::Wgsl(code) => ShaderSource::Wgsl(Cow::Owned(code.into_owned())), 
+            Self::Glsl { code, stage } => ShaderSource::Glsl { code: Cow::Owned(code.into_owned()), stage }, 
+        }
+    }
+}
+
+/// #### 한국어 </br>
+/// 파일 이름으로부터 셰이더 단계를 결정합니다. (예: `color.vs.glsl`) </br>
+/// 
+/// #### English (Translation) </br>
+/// Determines the shader stage from the file name. (e.g. `color.vs.glsl`) </br>
+/// 
+pub fn shader_stage_from_path(path: &Path) -> Option<naga::ShaderStage> {
+    let file_name = path.file_name()?.to_str()?;
+    let mut parts = file_name.rsplit('.');
+    let extension = parts.next()?;
+    match extension {
+        "vert" => return Some(naga::ShaderStage::Vertex), 
+        "frag" => return Some(naga::ShaderStage::Fragment), 
+        "comp" => return Some(naga::ShaderStage::Compute), 
+        _ => { /* empty */ }
+    };
+
+    match parts.next()? {
+        "vs" | "vert" => Some(naga::ShaderStage::Vertex), 
+        "fs" | "frag" => Some(naga::ShaderStage::Fragment), 
+        "cs" | "comp" => Some(naga::ShaderStage::Compute), 
+        _ => None
+    }
+}
+
+/// #### 한국어 </br>
+/// 가상 파일 시스템의 주어진 디렉토리에서 이름과 단계에 맞는 셰이더 파일을 찾습니다. </br>
+/// 소스 파일이 미리 컴파일된 파일보다 우선됩니다. (`.wgsl` > `.glsl` > `.spv`) </br>
+/// 
+/// #### English (Translation) </br>
+/// Finds a shader file matching the name and stage in the given directory of the virtual file system. </br>
+/// Source files take precedence over precompiled files. (`.wgsl` > `.glsl` > `.spv`) </br>
+/// 
+pub fn find_shader_file(vfs: &Vfs, dir: &Path, name: &str, stage: naga::ShaderStage) -> Option<PathBuf> {
+    let stage = match stage {
+        naga::ShaderStage::Vertex => "vs", 
+        naga::ShaderStage::Fragment => "fs", 
+        naga::ShaderStage::Compute => "cs", 
+    };
+
+    ["wgsl", "glsl", "spv"].iter()
+        .map(|ext| dir.join(format!("{}.{}.{}", name, stage, ext)))
+        .find(|path| vfs.exists(path))
+}
+
+
+
+/// #### 한국어 </br>
+/// 감시하는 셰이더 디렉토리가 가상 파일 시스템에 마운트되는 지점입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The mount point of the watched shader directory in the virtual file system. </br>
+/// 
+pub const SHADER_MOUNT_POINT: &str = "shaders";
+
+
+
+/// #### 한국어 </br>
+/// 셰이더 파일들의 변경을 감시합니다. </br>
+/// 파일의 수정 시각을 주기적으로 확인하여 변경된 파일의 목록을 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Watches shader files for changes. </br>
+/// Periodically checks the modification time of files and returns a list of changed files. </br>
+/// 
+#[derive(Debug)]
+#[cfg(not(target_os = "android"))]
+pub struct ShaderWatcher {
+    dir: PathBuf, 
+    files: std::collections::HashMap<PathBuf, std::time::SystemTime>, 
+    interval: std::time::Duration, 
+    last_poll: std::time::Instant, 
+}
+
+#[allow(dead_code)]
+#[cfg(not(target_os = "android"))]
+impl ShaderWatcher {
+    pub fn new<P: Into<PathBuf>>(dir: P) -> Self {
+        let mut watcher = Self {
+            dir: dir.into(), 
+            files: std::collections::HashMap::new(), 
+            interval: std::time::Duration::from_millis(250), 
+            last_poll: std::time::Instant::now(), 
+        };
+        watcher.scan();
+        watcher
+    }
+
+    #[inline]
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// #### 한국어 </br>
+    /// 이전 호출 이후 변경되거나 새로 추가된 셰이더 파일들을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns shader files that have been changed or added since the previous call. </br>
+    /// 
+    pub fn poll(&mut self) -> Vec<PathBuf> {
+        if self.last_poll.elapsed() < self.interval {
+            return Vec::new();
+        }
+        self.last_poll = std::time::Instant::now();
+        self.scan()
+    }
+
+    fn scan(&mut self) -> Vec<PathBuf> {
+        let mut changed = Vec::new();
+        let entries = match fs::read_dir(&self.dir) {
+            Ok(entries) => entries, 
+            Err(err) => {
+                log::warn!("Could not watch shader directory {}: {}", self.dir.display(), err);
+                return changed;
+            }
+        };
+
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let is_shader = path.extension()
+                .and_then(|ext| ext.to_str())
+                .is_some_and(|ext| matches!(ext, "spv" | "wgsl" | "glsl" | "vert" | "frag" | "comp"));
+            if !is_shader {
+                continue;
+            }
+
+            let Ok(modified) = entry.metadata().and_then(|meta| meta.modified()) else {
+                continue;
+            };
+
+            if self.files.insert(path.clone(), modified) != Some(modified) {
+                changed.push(path);
+            }
+        }
+
+        changed
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 이름이 붙은 셰이더 모듈입니다. </br>
+/// 이름은 셰이더 파일의 이름과 단계로 구성됩니다. (예: `color.vs`) </br>
+/// 장치가 다시 생성될 때 모듈을 다시 만들 수 있도록 소스를 함께 보관합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A named shader module. </br>
+/// The name consists of the shader file name and stage. (e.g. `color.vs`) </br>
+/// The source is kept so that the module can be rebuilt when the device is recreated. </br>
+/// 
+#[derive(Debug, Clone)]
+pub struct Shader {
+    name: Arc<str>, 
+    module: Arc<wgpu::ShaderModule>, 
+    source: Arc<ShaderSource<'static>>, 
+}
+
+#[allow(dead_code)]
+impl Shader {
+    /// #### 한국어 </br>
+    /// 셰이더 소스를 컴파일하여 이름이 붙은 셰이더 모듈을 생성합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Compiles the shader source to create a named shader module. </br>
+    /// 
+    pub fn from_source(
+        name: &str, 
+        source: &ShaderSource<'_>, 
+        device: &wgpu::Device
+    ) -> Result<Self, ShaderError> {
+        let module = source.create_shader_module(Some(name), device)?;
+        Ok(Self {
+            name: name.into(), 
+            module: module.into(), 
+            source: source.clone().into_owned().into(), 
+        })
+    }
+
+    /// #### 한국어 </br>
+    /// 보관된 소스로부터 주어진 장치에서 셰이더 모듈을 다시 생성합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Recreates the shader module on the given device from the kept source. </br>
+    /// 
+    pub fn recreate(&self, device: &wgpu::Device) -> Result<Self, ShaderError> {
+        Self::from_source(&self.name, &self.source, device)
+    }
+
+    #[inline]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    #[inline]
+    pub fn module(&self) -> &wgpu::ShaderModule {
+        &self.module
+    }
+}
+
+impl Eq for Shader { }
+
+impl PartialEq<Self> for Shader {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.module.global_id().eq(&other.module.global_id())
+    }
+}
+
+impl hash::Hash for Shader {
+    #[inline]
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.module.global_id().hash(state)
+    }
+}
@@ -0,0 +1,464 @@
+use std::f32::consts::PI;
+use std::f32::consts::TAU;
+use std::f32::consts::FRAC_PI_2;
+use std::collections::HashMap;
+
+use crate::render::mesh_data::MeshData;
+
+
+
+/// #### 한국어 </br>
+/// 도형의 버텍스와 인덱스를 쌓아 `MeshData`를 만드는 도우미입니다. </br>
+/// 모든 도형은 원점을 중심으로 하며, 앞면은 바깥쪽에서 보았을 때 반시계 방향으로 감기고, 법선은 단위 길이입니다. </br>
+/// `icosphere`를 제외한 모든 도형은 텍스처 좌표를 가집니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A helper that builds `MeshData` by accumulating the vertices and indices of a shape. </br>
+/// All shapes are centered at the origin, front faces are wound counter-clockwise when seen from outside, and normals have unit length. </br>
+/// All shapes except `icosphere` have texture coordinates. </br>
+/// 
+#[derive(Debug, Default)]
+struct ShapeBuilder {
+    mesh: MeshData, 
+}
+
+impl ShapeBuilder {
+    #[inline]
+    fn finish(self) -> MeshData {
+        self.mesh
+    }
+
+    #[inline]
+    fn num_vertices(&self) -> u32 {
+        self.mesh.positions.len() as u32
+    }
+
+    #[inline]
+    fn push_vertex(&mut self, position: glam::Vec3, normal: glam::Vec3, uv: glam::Vec2) -> u32 {
+        self.mesh.positions.push(position);
+        self.mesh.normals.push(normal);
+        self.mesh.uvs.push(uv);
+        self.num_vertices() - 1
+    }
+
+    #[inline]
+    fn push_triangle(&mut self, a: u32, b: u32, c: u32) {
+        self.mesh.indices.extend_from_slice(&[a, b, c]);
+    }
+
+    /// #### 한국어 </br>
+    /// `a`, `b`, `c`, `d` 순서로 반시계 방향으로 감긴 사각형을 두 개의 삼각형으로 추가합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Adds a quad wound counter-clockwise in the order `a`, `b`, `c`, `d` as two triangles. </br>
+    /// 
+    #[inline]
+    fn push_quad(&mut self, a: u32, b: u32, c: u32, d: u32) {
+        self.mesh.indices.extend_from_slice(&[a, b, c, c, d, a]);
+    }
+
+    /// #### 한국어 </br>
+    /// `y` 높이에 `normal_y` 방향을 바라보는 원판을 추가합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Adds a disc at height `y` facing the `normal_y` direction. </br>
+    /// 
+    fn push_disc(&mut self, radius: f32, y: f32, normal_y: f32, segments: u32) {
+        let normal = glam::Vec3::new(0.0, normal_y, 0.0);
+        let center = self.push_vertex(glam::Vec3::new(0.0, y, 0.0), normal, glam::Vec2::splat(0.5));
+        let first = self.num_vertices();
+        for segment in 0..=segments {
+            let (sin, cos) = (TAU * segment as f32 / segments as f32).sin_cos();
+            let uv = glam::Vec2::new(0.5 + 0.5 * cos, 0.5 + 0.5 * sin);
+            self.push_vertex(glam::Vec3::new(radius * cos, y, radius * sin), normal, uv);
+        }
+        for segment in 0..segments {
+            let (current, next) = (first + segment, first + segment + 1);
+            match normal_y > 0.0 {
+                true => self.push_triangle(center, next, current), 
+                false => self.push_triangle(center, current, next), 
+            };
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 위에서 아래로 나열된 위도 행들을 `y`축 둘레로 회전시켜 곡면을 추가합니다. </br>
+    /// 각 행은 (극각, `y` 이동량)이며, 극각이 `0` 또는 `π`인 행은 극점으로 취급됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Adds a surface by revolving latitude rows listed from top to bottom around the `y` axis. </br>
+    /// Each row is (polar angle, `y` offset), and rows whose polar angle is `0` or `π` are treated as poles. </br>
+    /// 
+    fn push_lathe(&mut self, radius: f32, rows: &[(f32, f32)], segments: u32) {
+        let first = self.num_vertices();
+        let stride = segments + 1;
+        for (row, &(theta, offset)) in rows.iter().enumerate() {
+            let (sin_theta, cos_theta) = theta.sin_cos();
+            let v = row as f32 / (rows.len() - 1) as f32;
+            for segment in 0..=segments {
+                let u = segment as f32 / segments as f32;
+                let (sin_phi, cos_phi) = (TAU * u).sin_cos();
+                let normal = glam::Vec3::new(sin_theta * cos_phi, cos_theta, sin_theta * sin_phi).normalize();
+                self.push_vertex(normal * radius + glam::Vec3::new(0.0, offset, 0.0), normal, glam::Vec2::new(u, v));
+            }
+        }
+
+        let is_pole = |theta: f32| theta.sin().abs() <= f32::EPSILON;
+        for row in 0..(rows.len() as u32 - 1) {
+            for segment in 0..segments {
+                let a = first + row * stride + segment;
+                let b = a + 1;
+                let c = a + stride + 1;
+                let d = a + stride;
+                match (is_pole(rows[row as usize].0), is_pole(rows[row as usize + 1].0)) {
+                    (true, _) => self.push_triangle(a, c, d), 
+                    (_, true) => self.push_triangle(a, b, d), 
+                    _ => self.push_quad(a, b, c, d), 
+                };
+            }
+        }
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 위도와 경도로 나뉜 구를 생성합니다. `segments`는 경도 방향, `rings`는 위도 방향의 분할 수입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates a sphere divided by latitude and longitude. `segments` is the number of divisions along the longitude, and `rings` along the latitude. </br>
+/// 
+pub fn uv_sphere(radius: f32, segments: u32, rings: u32) -> MeshData {
+    assert!(radius > 0.0 && segments >= 3 && rings >= 2);
+
+    let rows: Vec<_> = (0..=rings)
+        .map(|ring| (PI * ring as f32 / rings as f32, 0.0))
+        .collect();
+    let mut geometry = ShapeBuilder::default();
+    geometry.push_lathe(radius, &rows, segments);
+    geometry.finish()
+}
+
+/// #### 한국어 </br>
+/// 정이십면체의 각 면을 `subdivisions`번 4개로 나누어 구를 생성합니다. </br>
+/// 버텍스 수는 `10 * 4^subdivisions + 2`입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates a sphere by splitting each face of an icosahedron into 4, `subdivisions` times. </br>
+/// The number of vertices is `10 * 4^subdivisions + 2`. </br>
+/// 
+pub fn icosphere(radius: f32, subdivisions: u32) -> MeshData {
+    assert!(radius > 0.0);
+
+    let t = (1.0 + 5.0f32.sqrt()) * 0.5;
+    let mut positions: Vec<glam::Vec3> = [
+        (-1.0, t, 0.0), (1.0, t, 0.0), (-1.0, -t, 0.0), (1.0, -t, 0.0), 
+        (0.0, -1.0, t), (0.0, 1.0, t), (0.0, -1.0, -t), (0.0, 1.0, -t), 
+        (t, 0.0, -1.0), (t, 0.0, 1.0), (-t, 0.0, -1.0), (-t, 0.0, 1.0), 
+    ]
+    .into_iter()
+    .map(|it| glam::Vec3::from(it).normalize())
+    .collect();
+
+    let mut triangles: Vec<[u32; 3]> = vec![
+        [0, 11, 5], [0, 5, 1], [0, 1, 7], [0, 7, 10], [0, 10, 11], 
+        [1, 5, 9], [5, 11, 4], [11, 10, 2], [10, 7, 6], [7, 1, 8], 
+        [3, 9, 4], [3, 4, 2], [3, 2, 6], [3, 6, 8], [3, 8, 9], 
+        [4, 9, 5], [2, 4, 11], [6, 2, 10], [8, 6, 7], [9, 8, 1], 
+    ];
+
+    for _ in 0..subdivisions {
+        // (한국어) 이웃한 면들이 같은 중점을 공유하도록 모서리마다 중점을 한 번만 생성합니다.
+        // (English Translation) Creates the midpoint only once per edge so that neighboring faces share the same midpoint.
+        let mut midpoints: HashMap<(u32, u32), u32> = HashMap::new();
+        let mut midpoint = |a: u32, b: u32, positions: &mut Vec<glam::Vec3>| {
+            *midpoints.entry((a.min(b), a.max(b))).or_insert_with(|| {
+                positions.push((positions[a as usize] + positions[b as usize]).normalize());
+                (positions.len() - 1) as u32
+            })
+        };
+
+        triangles = triangles.into_iter()
+            .flat_map(|[a, b, c]| {
+                let ab = midpoint(a, b, &mut positions);
+                let bc = midpoint(b, c, &mut positions);
+                let ca = midpoint(c, a, &mut positions);
+                [[a, ab, ca], [b, bc, ab], [c, ca, bc], [ab, bc, ca]]
+            })
+            .collect();
+    }
+
+    MeshData::new(
+        positions.iter().map(|&normal| normal * radius).collect(), 
+        positions, 
+        triangles.into_iter().flatten().collect()
+    )
+}
+
+/// #### 한국어 </br>
+/// `y`축을 따라 세워진 원기둥을 생성합니다. 옆면은 부드러운 법선을, 윗면과 아랫면은 평평한 법선을 가집니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates a cylinder standing along the `y` axis. The side has smooth normals, and the top and bottom caps have flat normals. </br>
+/// 
+pub fn cylinder(radius: f32, height: f32, segments: u32) -> MeshData {
+    assert!(radius > 0.0 && height > 0.0 && segments >= 3);
+
+    let half = 0.5 * height;
+    let mut geometry = ShapeBuilder::default();
+    let first = geometry.num_vertices();
+    for segment in 0..=segments {
+        let u = segment as f32 / segments as f32;
+        let (sin, cos) = (TAU * u).sin_cos();
+        let normal = glam::Vec3::new(cos, 0.0, sin);
+        geometry.push_vertex(glam::Vec3::new(radius * cos, half, radius * sin), normal, glam::Vec2::new(u, 0.0));
+        geometry.push_vertex(glam::Vec3::new(radius * cos, -half, radius * sin), normal, glam::Vec2::new(u, 1.0));
+    }
+    for segment in 0..segments {
+        let top = first + 2 * segment;
+        geometry.push_quad(top, top + 2, top + 3, top + 1);
+    }
+
+    geometry.push_disc(radius, half, 1.0, segments);
+    geometry.push_disc(radius, -half, -1.0, segments);
+    geometry.finish()
+}
+
+/// #### 한국어 </br>
+/// `y`축을 따라 꼭짓점이 위를 향하는 원뿔을 생성합니다. 꼭짓점의 버텍스는 분할마다 따로 생성되어 옆면의 법선이 매끄럽게 이어집니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates a cone along the `y` axis with its apex pointing up. The apex vertex is created per segment so that the normals of the side blend smoothly. </br>
+/// 
+pub fn cone(radius: f32, height: f32, segments: u32) -> MeshData {
+    assert!(radius > 0.0 && height > 0.0 && segments >= 3);
+
+    let half = 0.5 * height;
+    let side_normal = |angle: f32| {
+        let (sin, cos) = angle.sin_cos();
+        glam::Vec3::new(height * cos, radius, height * sin).normalize()
+    };
+
+    let mut geometry = ShapeBuilder::default();
+    let first = geometry.num_vertices();
+    for segment in 0..=segments {
+        let u = segment as f32 / segments as f32;
+        let (sin, cos) = (TAU * u).sin_cos();
+        geometry.push_vertex(glam::Vec3::new(radius * cos, -half, radius * sin), side_normal(TAU * u), glam::Vec2::new(u, 1.0));
+    }
+    for segment in 0..segments {
+        let u = (segment as f32 + 0.5) / segments as f32;
+        let apex = geometry.push_vertex(glam::Vec3::new(0.0, half, 0.0), side_normal(TAU * u), glam::Vec2::new(u, 0.0));
+        geometry.push_triangle(first + segment, apex, first + segment + 1);
+    }
+
+    geometry.push_disc(radius, -half, -1.0, segments);
+    geometry.finish()
+}
+
+/// #### 한국어 </br>
+/// `xz` 평면에 놓인 도넛 모양의 원환면을 생성합니다. </br>
+/// `major_radius`는 중심에서 관의 중심까지, `minor_radius`는 관의 반지름입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates a donut-shaped torus lying on the `xz` plane. </br>
+/// `major_radius` is the distance from the center to the center of the tube, and `minor_radius` is the radius of the tube. </br>
+/// 
+pub fn torus(major_radius: f32, minor_radius: f32, major_segments: u32, minor_segments: u32) -> MeshData {
+    assert!(major_radius > 0.0 && minor_radius > 0.0 && major_segments >= 3 && minor_segments >= 3);
+
+    let mut geometry = ShapeBuilder::default();
+    let stride = minor_segments + 1;
+    for major in 0..=major_segments {
+        let u = major as f32 / major_segments as f32;
+        let (sin_u, cos_u) = (TAU * u).sin_cos();
+        let center = glam::Vec3::new(major_radius * cos_u, 0.0, major_radius * sin_u);
+        for minor in 0..=minor_segments {
+            let v = minor as f32 / minor_segments as f32;
+            let (sin_v, cos_v) = (TAU * v).sin_cos();
+            let normal = glam::Vec3::new(cos_v * cos_u, sin_v, cos_v * sin_u);
+            geometry.push_vertex(center + normal * minor_radius, normal, glam::Vec2::new(u, v));
+        }
+    }
+
+    for major in 0..major_segments {
+        for minor in 0..minor_segments {
+            let a = major * stride + minor;
+            geometry.push_quad(a, a + 1, a + stride + 1, a + stride);
+        }
+    }
+    geometry.finish()
+}
+
+/// #### 한국어 </br>
+/// `y`축을 따라 세워진 캡슐을 생성합니다. `height`는 두 반구 사이의 원기둥 부분의 높이이며, 전체 높이는 `height + 2 * radius`입니다. </br>
+/// `rings`는 각 반구의 위도 방향 분할 수입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates a capsule standing along the `y` axis. `height` is the height of the cylinder part between the two hemispheres, and the total height is `height + 2 * radius`. </br>
+/// `rings` is the number of divisions along the latitude of each hemisphere. </br>
+/// 
+pub fn capsule(radius: f32, height: f32, segments: u32, rings: u32) -> MeshData {
+    assert!(radius > 0.0 && height >= 0.0 && segments >= 3 && rings >= 1);
+
+    let half = 0.5 * height;
+    let top = (0..=rings).map(|ring| (FRAC_PI_2 * ring as f32 / rings as f32, half));
+    // (한국어) 원기둥 부분이 없다면 적도 행이 겹치므로 아래 반구의 첫 행을 생략합니다.
+    // (English Translation) Without a cylinder part the equator rows overlap, so the first row of the lower hemisphere is skipped.
+    let bottom = (0..=rings)
+        .skip(usize::from(height == 0.0))
+        .map(|ring| (FRAC_PI_2 + FRAC_PI_2 * ring as f32 / rings as f32, -half));
+    let rows: Vec<_> = top.chain(bottom).collect();
+
+    let mut geometry = ShapeBuilder::default();
+    geometry.push_lathe(radius, &rows, segments);
+    geometry.finish()
+}
+
+/// #### 한국어 </br>
+/// `xz` 평면에 놓이고 위(`+y`)를 바라보는, 여러 칸으로 나뉜 평면을 생성합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates a plane divided into several cells, lying on the `xz` plane and facing up (`+y`). </br>
+/// 
+pub fn grid(width: f32, depth: f32, x_segments: u32, z_segments: u32) -> MeshData {
+    assert!(width > 0.0 && depth > 0.0 && x_segments >= 1 && z_segments >= 1);
+
+    let mut geometry = ShapeBuilder::default();
+    let stride = x_segments + 1;
+    for z in 0..=z_segments {
+        for x in 0..=x_segments {
+            let uv = glam::Vec2::new(x as f32 / x_segments as f32, z as f32 / z_segments as f32);
+            let position = glam::Vec3::new(width * (uv.x - 0.5), 0.0, depth * (uv.y - 0.5));
+            geometry.push_vertex(position, glam::Vec3::Y, uv);
+        }
+    }
+
+    for z in 0..z_segments {
+        for x in 0..x_segments {
+            let a = z * stride + x;
+            geometry.push_quad(a, a + stride, a + stride + 1, a + 1);
+        }
+    }
+    geometry.finish()
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// #### 한국어 </br>
+    /// 메쉬의 버텍스 수와 삼각형 수, 인덱스 범위와 법선의 길이를 검사합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Checks the number of vertices and triangles, the range of the indices and the length of the normals of a mesh. </br>
+    /// 
+    fn assert_mesh(mesh: &MeshData, num_vertices: usize, num_triangles: usize) {
+        assert_eq!(mesh.num_vertices(), num_vertices);
+        assert_eq!(mesh.num_triangles(), num_triangles);
+        assert_eq!(mesh.indices.len(), 3 * num_triangles);
+        assert!(mesh.indices.iter().all(|&index| (index as usize) < num_vertices));
+        assert_eq!(mesh.validate(), Ok(()));
+        for normal in mesh.normals.iter() {
+            assert!((normal.length() - 1.0).abs() < 1.0e-5, "normal {} is not unit length", normal);
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 모든 삼각형이 바깥쪽에서 보았을 때 반시계 방향으로 감겨 있는지 검사합니다. </br>
+    /// `inside`는 삼각형의 중심에서 가장 가까운 도형 안쪽의 점을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Checks that every triangle is wound counter-clockwise when seen from outside. </br>
+    /// `inside` returns the point inside the shape closest to the center of the triangle. </br>
+    /// 
+    fn assert_outward_winding<F: Fn(glam::Vec3) -> glam::Vec3>(mesh: &MeshData, inside: F) {
+        for triangle in mesh.indices.chunks_exact(3) {
+            let [a, b, c] = [0, 1, 2].map(|i| mesh.positions[triangle[i] as usize]);
+            let face_normal = (b - a).cross(c - a);
+            let centroid = (a + b + c) / 3.0;
+            assert!(face_normal.length() > 0.0, "triangle {:?} is degenerate", triangle);
+            assert!(face_normal.dot(centroid - inside(centroid)) > 0.0, "triangle {:?} faces inward", triangle);
+
+            let vertex_normal = [0, 1, 2].map(|i| mesh.normals[triangle[i] as usize]).iter().sum::<glam::Vec3>();
+            assert!(face_normal.dot(vertex_normal) > 0.0, "triangle {:?} disagrees with its normals", triangle);
+        }
+    }
+
+    #[test]
+    fn uv_sphere_is_closed_and_faces_outward() {
+        let mesh = uv_sphere(2.0, 8, 4);
+        assert_mesh(&mesh, 5 * 9, 2 * 8 * 3);
+        assert_eq!(mesh.uvs.len(), mesh.num_vertices());
+        assert_outward_winding(&mesh, |_| glam::Vec3::ZERO);
+        assert!(mesh.positions.iter().all(|position| (position.length() - 2.0).abs() < 1.0e-5));
+    }
+
+    #[test]
+    fn icosphere_has_expected_counts_and_faces_outward() {
+        for subdivisions in 0..3 {
+            let mesh = icosphere(1.5, subdivisions);
+            let scale = 4usize.pow(subdivisions);
+            assert_mesh(&mesh, 10 * scale + 2, 20 * scale);
+            assert!(!mesh.has_uvs());
+            assert_outward_winding(&mesh, |_| glam::Vec3::ZERO);
+            assert!(mesh.positions.iter().all(|position| (position.length() - 1.5).abs() < 1.0e-5));
+        }
+    }
+
+    #[test]
+    fn cylinder_has_side_and_caps_facing_outward() {
+        let segments = 6;
+        let mesh = cylinder(1.0, 2.0, segments);
+        assert_mesh(&mesh, 4 * segments as usize + 6, 4 * segments as usize);
+        assert_eq!(mesh.uvs.len(), mesh.num_vertices());
+        assert_outward_winding(&mesh, |_| glam::Vec3::ZERO);
+        assert!(mesh.positions.iter().all(|position| position.y.abs() <= 1.0 + 1.0e-6));
+    }
+
+    #[test]
+    fn cone_has_side_and_base_facing_outward() {
+        let segments = 7;
+        let mesh = cone(1.0, 3.0, segments);
+        assert_mesh(&mesh, 3 * segments as usize + 3, 2 * segments as usize);
+        assert_eq!(mesh.uvs.len(), mesh.num_vertices());
+        assert_outward_winding(&mesh, |_| glam::Vec3::ZERO);
+    }
+
+    #[test]
+    fn torus_faces_away_from_its_tube() {
+        let mesh = torus(2.0, 0.5, 8, 6);
+        assert_mesh(&mesh, 9 * 7, 2 * 8 * 6);
+        assert_eq!(mesh.uvs.len(), mesh.num_vertices());
+        assert_outward_winding(&mesh, |centroid| {
+            glam::Vec3::new(centroid.x, 0.0, centroid.z).normalize() * 2.0
+        });
+    }
+
+    #[test]
+    fn capsule_faces_outward_with_and_without_cylinder() {
+        let (segments, rings) = (8, 3);
+        let mesh = capsule(0.5, 1.0, segments, rings);
+        assert_mesh(&mesh, (2 * rings as usize + 2) * (segments as usize + 1), 4 * segments as usize * rings as usize);
+        assert_eq!(mesh.uvs.len(), mesh.num_vertices());
+        assert_outward_winding(&mesh, |centroid| glam::Vec3::new(0.0, centroid.y.clamp(-0.5, 0.5), 0.0));
+
+        let mesh = capsule(0.5, 0.0, segments, rings);
+        assert_mesh(&mesh, (2 * rings as usize + 1) * (segments as usize + 1), 2 * segments as usize * (2 * rings as usize - 1));
+        assert_outward_winding(&mesh, |_| glam::Vec3::ZERO);
+    }
+
+    #[test]
+    fn grid_faces_up() {
+        let mesh = grid(4.0, 2.0, 4, 2);
+        assert_mesh(&mesh, 5 * 3, 2 * 4 * 2);
+        assert_eq!(mesh.uvs.len(), mesh.num_vertices());
+        assert_outward_winding(&mesh, |centroid| centroid - glam::Vec3::Y);
+
+        let bounds = mesh.bounds().unwrap();
+        assert_eq!(bounds.size(), glam::Vec3::new(4.0, 0.0, 2.0));
+        assert_eq!(bounds.center(), glam::Vec3::ZERO);
+    }
+}
@@ -2,6 +2,9 @@ use std::sync::Arc;
 use std::any::TypeId;
 use std::collections::HashMap;
 
+use crate::error::AppError;
+use crate::render::find_layout;
+
 
 
 /// #### 한국어 </br>
@@ -22,7 +25,7 @@ impl WeightedBlendedOIT {
         width: u32, height: u32, 
         layouts: &HashMap<TypeId, wgpu::BindGroupLayout>, 
         device: &wgpu::Device
-    ) -> Self {
+    ) -> Result<Self, AppError> {
         let sampler = device.create_sampler(
             &wgpu::SamplerDescriptor {
                 label: Some("Sampler(WeightedBlendedOIT)"), 
@@ -67,8 +70,7 @@ impl WeightedBlendedOIT {
         let bind_group = device.create_bind_group(
             &wgpu::BindGroupDescriptor {
                 label: Some("BindGroup(WeightedBlendedOIT)"), 
-                layout: layouts.get(&TypeId::of::<WeightedBlendedOIT>())
-                    .expect("WeightedBlendedOIT not found!"), 
+                layout: find_layout::<WeightedBlendedOIT>(layouts)?, 
                 entries: &[
                     wgpu::BindGroupEntry {
                         binding: 0, 
@@ -90,11 +92,11 @@ impl WeightedBlendedOIT {
             }, 
         );
 
-        return Self { 
+        Ok(Self { 
             accumulation: accumulation.into(), 
             revealage: revealage.into(), 
             bind_group: bind_group.into() 
-        };
+        })
     }
 
     pub fn accumulation_attachment(&self) -> wgpu::RenderPassColorAttachment {
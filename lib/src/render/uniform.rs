@@ -5,6 +5,19 @@ use std::collections::HashMap;
 use bytemuck::Pod;
 use bytemuck::Zeroable;
 
+use crate::error::AppError;
+use crate::render::find_layout;
+
+
+
+/// #### 한국어 </br>
+/// 스킨 유니폼 버퍼에 담을 수 있는 관절의 최대 수입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The maximum number of joints that fit in the skin uniform buffer. </br>
+/// 
+pub const MAX_SKIN_JOINTS: usize = 64;
+
 
 
 /// #### 한국어 </br>
@@ -73,7 +86,7 @@ impl CameraUniform {
     pub fn new(
         layouts: &HashMap<TypeId, wgpu::BindGroupLayout>, 
         device: &wgpu::Device
-    ) -> Self {
+    ) -> Result<Self, AppError> {
         let buffer = device.create_buffer(
             &wgpu::BufferDescriptor {
                 label: Some("UniformBuffer(CameraUniformLayout)"), 
@@ -86,8 +99,7 @@ impl CameraUniform {
         let bind_group = device.create_bind_group(
             &wgpu::BindGroupDescriptor {
                 label: Some("BindGroup(CameraUniform)"), 
-                layout: layouts.get(&TypeId::of::<CameraUniformLayout>())
-                    .expect("CameraUniformLayout not found!"), 
+                layout: find_layout::<CameraUniformLayout>(layouts)?, 
                 entries: &[
                     wgpu::BindGroupEntry {
                         binding: 0, 
@@ -99,10 +111,10 @@ impl CameraUniform {
             }, 
         );
 
-        Self { 
+        Ok(Self { 
             buffer: buffer.into(), 
             bind_group: bind_group.into() 
-        }
+        })
     }
 
     #[inline]
@@ -192,7 +204,7 @@ impl EntityUniform {
     pub fn new(
         layouts: &HashMap<TypeId, wgpu::BindGroupLayout>, 
         device: &wgpu::Device
-    ) -> Self {
+    ) -> Result<Self, AppError> {
         let buffer = device.create_buffer(
             &wgpu::BufferDescriptor {
                 label: Some("UniformBuffer(EntityUniformLayout)"), 
@@ -205,8 +217,7 @@ impl EntityUniform {
         let bind_group = device.create_bind_group(
             &wgpu::BindGroupDescriptor {
                 label: Some("BindGroup(EntityUniform)"), 
-                layout: layouts.get(&TypeId::of::<EntityUniformLayout>())
-                    .expect("EntityUniformLayout not found!"), 
+                layout: find_layout::<EntityUniformLayout>(layouts)?, 
                 entries: &[
                     wgpu::BindGroupEntry {
                         binding: 0, 
@@ -218,10 +229,10 @@ impl EntityUniform {
             }, 
         );
 
-        Self { 
+        Ok(Self { 
             buffer: buffer.into(), 
             bind_group: bind_group.into() 
-        }
+        })
     }
 
     #[inline]
@@ -244,3 +255,128 @@ impl PartialEq<Self> for EntityUniform {
         && self.bind_group.global_id().eq(&other.bind_group.global_id())
     }
 }
+
+
+
+/// #### 한국어 </br>
+/// 스킨 유니폼 데이터의 레이아웃 입니다. 관절들의 스키닝 행렬을 담습니다. </br>
+/// 저장 버퍼를 지원하지 않는 장치(GLES)에서도 사용할 수 있도록 유니폼 버퍼를 사용합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The layout of the skin uniform data. Holds the skinning matrices of the joints. </br>
+/// Uses a uniform buffer so that it can be used on devices without storage buffer support (GLES). </br>
+/// 
+#[repr(C, align(16))]
+#[derive(Pod, Zeroable)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SkinUniformLayout {
+    pub joints: [glam::Mat4; MAX_SKIN_JOINTS], 
+}
+
+impl SkinUniformLayout {
+    pub fn layout(device: &wgpu::Device) -> wgpu::BindGroupLayout {
+        device.create_bind_group_layout(
+            &wgpu::BindGroupLayoutDescriptor {
+                label: Some("BindGroupLayout(SkinUniformLayout)"), 
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0, 
+                        visibility: wgpu::ShaderStages::VERTEX, 
+                        ty: wgpu::BindingType::Buffer { 
+                            ty: wgpu::BufferBindingType::Uniform, 
+                            has_dynamic_offset: false, 
+                            min_binding_size: None 
+                        }, 
+                        count: None, 
+                    }, 
+                ], 
+            }, 
+        )
+    }
+}
+
+impl Default for SkinUniformLayout {
+    #[inline]
+    fn default() -> Self {
+        Self { 
+            joints: [glam::Mat4::IDENTITY; MAX_SKIN_JOINTS], 
+        }
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 스킨 유니폼 데이터 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Skin uniform data. </br>
+/// 
+#[derive(Debug, Clone)]
+pub struct SkinUniform {
+    buffer: Arc<wgpu::Buffer>, 
+    bind_group: Arc<wgpu::BindGroup>, 
+}
+
+impl SkinUniform {
+    pub fn new(
+        layouts: &HashMap<TypeId, wgpu::BindGroupLayout>, 
+        device: &wgpu::Device
+    ) -> Result<Self, AppError> {
+        let buffer = device.create_buffer(
+            &wgpu::BufferDescriptor {
+                label: Some("UniformBuffer(SkinUniformLayout)"), 
+                mapped_at_creation: false, 
+                size: mem::size_of::<SkinUniformLayout>() as wgpu::BufferAddress, 
+                usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST, 
+            }, 
+        );
+
+        let bind_group = device.create_bind_group(
+            &wgpu::BindGroupDescriptor {
+                label: Some("BindGroup(SkinUniform)"), 
+                layout: find_layout::<SkinUniformLayout>(layouts)?, 
+                entries: &[
+                    wgpu::BindGroupEntry {
+                        binding: 0, 
+                        resource: wgpu::BindingResource::Buffer(
+                            buffer.as_entire_buffer_binding()
+                        ), 
+                    }, 
+                ], 
+            }, 
+        );
+
+        Ok(Self { 
+            buffer: buffer.into(), 
+            bind_group: bind_group.into() 
+        })
+    }
+
+    /// #### 한국어 </br>
+    /// 관절들의 스키닝 행렬을 씁니다. `MAX_SKIN_JOINTS`를 넘는 관절은 무시됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Writes the skinning matrices of the joints. Joints beyond `MAX_SKIN_JOINTS` are ignored. </br>
+    /// 
+    #[inline]
+    pub fn update(&self, queue: &wgpu::Queue, joint_matrices: &[glam::Mat4]) {
+        let joint_matrices = &joint_matrices[..joint_matrices.len().min(MAX_SKIN_JOINTS)];
+        queue.write_buffer(&self.buffer, 0, bytemuck::cast_slice(joint_matrices));
+    }
+
+    #[inline]
+    pub fn bind<'a>(&'a self, rpass: &mut wgpu::RenderPass<'a>, index: u32) {
+        rpass.set_bind_group(index, &self.bind_group, &[]);
+    }
+}
+
+impl Eq for SkinUniform { }
+
+impl PartialEq<Self> for SkinUniform {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.buffer.global_id().eq(&other.buffer.global_id())
+        && self.bind_group.global_id().eq(&other.bind_group.global_id())
+    }
+}
@@ -0,0 +1,229 @@
+use std::fmt;
+
+
+
+/// #### 한국어 </br>
+/// 버텍스가 가질 수 있는 속성입니다. 각 속성은 모든 버텍스 형식에서 같은 셰이더 위치를 가집니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// An attribute a vertex can have. Each attribute has the same shader location in every vertex format. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VertexAttribute {
+    Position, 
+    Normal, 
+    Uv, 
+    Tangent, 
+    Color, 
+    Joints, 
+    Weights, 
+}
+
+#[allow(dead_code)]
+impl VertexAttribute {
+    /// #### 한국어 </br>
+    /// 버텍스 버퍼에 저장되는 순서대로 나열된 모든 속성입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// All attributes, listed in the order they are stored in a vertex buffer. </br>
+    /// 
+    pub const ALL: [Self; 7] = [
+        Self::Position, 
+        Self::Normal, 
+        Self::Uv, 
+        Self::Tangent, 
+        Self::Color, 
+        Self::Joints, 
+        Self::Weights, 
+    ];
+
+    #[inline]
+    pub const fn shader_location(&self) -> u32 {
+        *self as u32
+    }
+
+    #[inline]
+    pub const fn format(&self) -> wgpu::VertexFormat {
+        match self {
+            Self::Position => wgpu::VertexFormat::Float32x3, 
+            Self::Normal => wgpu::VertexFormat::Float32x3, 
+            Self::Uv => wgpu::VertexFormat::Float32x2, 
+            Self::Tangent => wgpu::VertexFormat::Float32x4, 
+            Self::Color => wgpu::VertexFormat::Float32x4, 
+            Self::Joints => wgpu::VertexFormat::Uint16x4, 
+            Self::Weights => wgpu::VertexFormat::Float32x4, 
+        }
+    }
+
+    #[inline]
+    pub const fn size(&self) -> u64 {
+        self.format().size()
+    }
+
+    #[inline]
+    const fn bit(&self) -> u8 {
+        1 << (*self as u8)
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 버텍스 버퍼에 담긴 속성들의 집합입니다. </br>
+/// 속성들은 `VertexAttribute::ALL`의 순서대로 빈틈없이 교차 배치되며, 메쉬와 재질이 같은 형식을 사용하는지 비교하는 데 쓰입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The set of attributes contained in a vertex buffer. </br>
+/// Attributes are tightly interleaved in the order of `VertexAttribute::ALL`, and it is used to check that a mesh and a material use the same format. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct VertexFormat(u8);
+
+#[allow(dead_code)]
+impl VertexFormat {
+    pub const POSITION: Self = Self(VertexAttribute::Position.bit());
+    pub const POSITION_NORMAL: Self = Self::POSITION.with(VertexAttribute::Normal);
+    pub const POSITION_NORMAL_UV: Self = Self::POSITION_NORMAL.with(VertexAttribute::Uv);
+    pub const POSITION_NORMAL_UV_TANGENT: Self = Self::POSITION_NORMAL_UV.with(VertexAttribute::Tangent);
+    pub const POSITION_NORMAL_SKIN: Self = Self::POSITION_NORMAL.with(VertexAttribute::Joints).with(VertexAttribute::Weights);
+
+    #[inline]
+    pub const fn with(self, attribute: VertexAttribute) -> Self {
+        Self(self.0 | attribute.bit())
+    }
+
+    #[inline]
+    pub const fn without(self, attribute: VertexAttribute) -> Self {
+        Self(self.0 & !attribute.bit())
+    }
+
+    #[inline]
+    pub const fn contains(&self, attribute: VertexAttribute) -> bool {
+        self.0 & attribute.bit() != 0
+    }
+
+    /// #### 한국어 </br>
+    /// 이 형식이 `other`의 모든 속성을 가지고 있는지 여부를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns whether this format has all attributes of `other`. </br>
+    /// 
+    #[inline]
+    pub const fn contains_all(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    #[inline]
+    pub const fn intersection(&self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    }
+
+    #[inline]
+    pub fn attributes(&self) -> impl Iterator<Item = VertexAttribute> + '_ {
+        VertexAttribute::ALL.into_iter().filter(|attribute| self.contains(*attribute))
+    }
+
+    /// #### 한국어 </br>
+    /// 버텍스 하나의 크기(바이트)입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// The size of a single vertex in bytes. </br>
+    /// 
+    #[inline]
+    pub fn stride(&self) -> u64 {
+        VERTEX_LAYOUT_TABLE[self.0 as usize].stride
+    }
+
+    /// #### 한국어 </br>
+    /// 버텍스 안에서 속성의 바이트 오프셋을 반환합니다. 속성이 없다면 `None`을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the byte offset of the attribute within a vertex. Returns `None` if the attribute is absent. </br>
+    /// 
+    pub fn offset_of(&self, attribute: VertexAttribute) -> Option<u64> {
+        self.buffer_layout().attributes.iter()
+            .find(|it| it.shader_location == attribute.shader_location())
+            .map(|it| it.offset)
+    }
+
+    /// #### 한국어 </br>
+    /// 이 형식의 버텍스 버퍼 레이아웃을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the vertex buffer layout of this format. </br>
+    /// 
+    pub fn buffer_layout(&self) -> wgpu::VertexBufferLayout<'static> {
+        let entry = &VERTEX_LAYOUT_TABLE[self.0 as usize];
+        wgpu::VertexBufferLayout {
+            array_stride: entry.stride, 
+            step_mode: wgpu::VertexStepMode::Vertex, 
+            attributes: &entry.attributes[..entry.num_attributes], 
+        }
+    }
+}
+
+impl fmt::Display for VertexFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let names: Vec<String> = self.attributes()
+            .map(|attribute| format!("{:?}", attribute))
+            .collect();
+        match names.is_empty() {
+            true => write!(f, "(Empty)"), 
+            false => write!(f, "{}", names.join("+")), 
+        }
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 가능한 모든 속성 조합의 버텍스 버퍼 레이아웃을 미리 계산해 둔 표입니다. </br>
+/// `wgpu::VertexBufferLayout`은 `'static` 수명의 속성 배열을 요구하므로, 형식의 비트를 인덱스로 사용합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A table with the vertex buffer layouts of every possible attribute combination computed in advance. </br>
+/// `wgpu::VertexBufferLayout` requires an attribute array with a `'static` lifetime, so the bits of the format are used as the index. </br>
+/// 
+static VERTEX_LAYOUT_TABLE: [VertexLayoutEntry; 1 << VertexAttribute::ALL.len()] = build_vertex_layout_table();
+
+#[derive(Clone, Copy)]
+struct VertexLayoutEntry {
+    stride: u64, 
+    num_attributes: usize, 
+    attributes: [wgpu::VertexAttribute; VertexAttribute::ALL.len()], 
+}
+
+const fn build_vertex_layout_table() -> [VertexLayoutEntry; 1 << VertexAttribute::ALL.len()] {
+    let empty = VertexLayoutEntry {
+        stride: 0, 
+        num_attributes: 0, 
+        attributes: [wgpu::VertexAttribute {
+            format: wgpu::VertexFormat::Float32, 
+            offset: 0, 
+            shader_location: 0, 
+        }; VertexAttribute::ALL.len()], 
+    };
+
+    let mut table = [empty; 1 << VertexAttribute::ALL.len()];
+    let mut bits = 0;
+    while bits < table.len() {
+        let mut entry = empty;
+        let mut i = 0;
+        while i < VertexAttribute::ALL.len() {
+            let attribute = VertexAttribute::ALL[i];
+            if bits as u8 & attribute.bit() != 0 {
+                entry.attributes[entry.num_attributes] = wgpu::VertexAttribute {
+                    format: attribute.format(), 
+                    offset: entry.stride, 
+                    shader_location: attribute.shader_location(), 
+                };
+                entry.num_attributes += 1;
+                entry.stride += attribute.size();
+            }
+            i += 1;
+        }
+        table[bits] = entry;
+        bits += 1;
+    }
+    table
+}
@@ -1,31 +1,75 @@
-use std::any::Any;
 use std::sync::Arc;
 use std::any::TypeId;
+use std::path::Path;
 use std::collections::HashMap;
+use std::collections::HashSet;
 use hecs::World;
 use hecs::Entity;
 use winit::window::Window;
+use winit::dpi::PhysicalPosition;
 use winit::event::Touch;
 use winit::event::TouchPhase;
 use winit::event::KeyEvent;
+use winit::event::ElementState;
+use winit::event::MouseButton;
 use winit::keyboard::KeyCode;
 use winit::keyboard::PhysicalKey;
 
+use crate::asset::Assets;
+use crate::asset::AssetServer;
+use crate::asset::Handle;
+use crate::asset::LoadState;
+use crate::error::AppError;
+use crate::item::animation::AnimationPlayer;
+use crate::item::camera_path::CameraPath;
+use crate::item::camera_path::CameraSequencer;
 use crate::item::color::Color;
 use crate::item::projection::PerspectiveBuilder;
 use crate::item::projection::Projection;
+use crate::item::skeleton::Skeleton;
 use crate::item::transform::Transform;
 use crate::item::transform::TransformBuilder;
+use crate::item::tween::Easing;
+use crate::item::tween::RepeatMode;
+use crate::item::tween::Tween;
+use crate::item::tween::TweenEvent;
+use crate::item::tween::TweenSequence;
+use crate::item::tween::TweenTarget;
+use crate::item::tween::TweenTargets;
+use crate::item::tween::Tweener;
+use crate::profiler;
+use crate::render::debug_draw::DebugDraw;
+use crate::render::gltf::GltfModel;
+use crate::render::hud::BitmapFont;
+use crate::render::hud::Hud;
+use crate::render::hud::HudUniformLayout;
+use crate::render::mesh::DynamicMesh;
 use crate::render::mesh::ModelMesh;
-use crate::render::pipeline::GraphicsPipeline;
-use crate::render::pipeline::ColoredPipeline;
-use crate::render::pipeline::TransparentPipeline;
-use crate::render::pipeline::CompositePipeline;
+use crate::render::mesh_data::MeshData;
+use crate::render::material::BlendMode;
+use crate::render::material::MaterialHandle;
+use crate::render::pipeline::DebugViewMode;
+use crate::render::pipeline::PipelineCache;
+use crate::render::profiler::GpuProfiler;
+use crate::render::registry::MeshDescriptor;
+use crate::render::registry::ResourceRegistry;
+use crate::render::shader::Shader;
+use crate::render::shader::ShaderError;
+use crate::render::shader::ShaderSource;
+use crate::render::shader::find_shader_file;
+use crate::render::shape;
 use crate::render::texture::WeightedBlendedOIT;
 use crate::render::uniform::CameraUniformLayout;
 use crate::render::uniform::CameraUniform;
 use crate::render::uniform::EntityUniformLayout;
 use crate::render::uniform::EntityUniform;
+use crate::render::uniform::SkinUniformLayout;
+use crate::render::uniform::SkinUniform;
+use crate::render::vertex::VertexFormat;
+use crate::timer::GameTimer;
+use crate::vfs::Vfs;
+#[cfg(feature = "egui")]
+use crate::timer::FrameTimeHistogram;
 
 
 
@@ -76,86 +120,434 @@ pub trait GameScene {
         queue: &wgpu::Queue
     ) { /* empty */}
 
+    #[inline]
+    fn on_cursor_moved(
+        &mut self, 
+        position: PhysicalPosition<f64>, 
+        device: &wgpu::Device, 
+        queue: &wgpu::Queue
+    ) { /* empty */ }
+
+    #[inline]
+    fn on_mouse_input(
+        &mut self, 
+        state: ElementState, 
+        button: MouseButton, 
+        device: &wgpu::Device, 
+        queue: &wgpu::Queue
+    ) { /* empty */ }
+
+    /// #### 한국어 </br>
+    /// 셰이더 파일이 변경되었을 때 호출됩니다. `path`는 `SHADER_MOUNT_POINT` 아래에 있는 가상 파일 시스템의 경로입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Called when a shader file has changed. `path` is a path of the virtual file system under `SHADER_MOUNT_POINT`. </br>
+    /// 
+    #[inline]
+    fn on_shader_changed(
+        &mut self, 
+        path: &Path, 
+        device: &wgpu::Device, 
+        queue: &wgpu::Queue
+    ) { /* empty */ }
+
+    /// #### 한국어 </br>
+    /// 장치가 손실된 후 새로운 장치가 생성되었을 때 호출됩니다. </br>
+    /// 이전 장치에서 생성된 모든 GPU 자원을 새로운 장치에서 다시 생성해야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Called when a new device has been created after the device was lost. </br>
+    /// All GPU resources created on the previous device must be recreated on the new device. </br>
+    /// 
+    #[inline]
+    fn on_device_recreated(
+        &mut self, 
+        device: &wgpu::Device, 
+        queue: &wgpu::Queue
+    ) -> Result<(), AppError> { 
+        Ok(())
+    }
+
+    /// #### 한국어 </br>
+    /// `surface`의 텍스처 형식이 바뀌었을 때 호출됩니다. </br>
+    /// 렌더 타겟에 그리는 모든 그래픽스 파이프라인을 새로운 형식으로 다시 생성해야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Called when the texture format of the `surface` has changed. </br>
+    /// All graphics pipelines that draw to the render target must be recreated with the new format. </br>
+    /// 
+    #[inline]
+    fn on_surface_format_changed(
+        &mut self, 
+        format: wgpu::TextureFormat, 
+        device: &wgpu::Device, 
+        queue: &wgpu::Queue
+    ) -> Result<(), AppError> {
+        Ok(())
+    }
+
+    /// #### 한국어 </br>
+    /// 장면이 변경되어 다시 그려야 하는지 여부를 반환합니다. </br>
+    /// `OnDemand` 루프 방식에서 입력이 없을 때도 프레임을 그려야 하는지 판단하는 데 사용됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns whether the scene has changed and needs to be redrawn. </br>
+    /// Used in the `OnDemand` loop mode to decide whether a frame should be drawn even without input. </br>
+    /// 
+    #[inline]
+    fn is_dirty(&self) -> bool {
+        true
+    }
+
+    /// #### 한국어 </br>
+    /// 고정된 시간 간격(`timer.fixed_time_step_sec()`)마다 호출됩니다. </br>
+    /// 프레임 속도와 상관없이 일정해야 하는 시뮬레이션은 여기서 갱신합니다. </br>
+    /// 한 프레임 동안 여러 번 호출되거나, 한 번도 호출되지 않을 수 있습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Called every fixed time step (`timer.fixed_time_step_sec()`). </br>
+    /// Simulations that must be consistent regardless of the frame rate are updated here. </br>
+    /// It may be called several times or not at all during a frame. </br>
+    /// 
+    #[inline]
+    fn on_fixed_update(
+        &mut self, 
+        timer: &GameTimer, 
+        device: &wgpu::Device, 
+        queue: &wgpu::Queue
+    ) { /* empty */ }
+
+    /// #### 한국어 </br>
+    /// 매 프레임마다 고정 갱신들이 끝난 후 호출됩니다. </br>
+    /// 시간 배율, 일시 정지, 고정 갱신 빈도는 `timer`를 통해 변경할 수 있으며, 다음 프레임부터 적용됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Called every frame after the fixed updates are finished. </br>
+    /// Time scale, pause and fixed update rate can be changed through the `timer`, and take effect from the next frame. </br>
+    /// 
     #[inline]
     fn on_update(
         &mut self, 
-        elapsed_time_sec: f32, 
+        timer: &mut GameTimer, 
+        device: &wgpu::Device, 
+        queue: &wgpu::Queue
+    ) { /* empty */ }
+
+    /// #### 한국어 </br>
+    /// 즉시 모드 GUI의 디버그 패널들을 구성합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Builds the debug panels of the immediate mode GUI. </br>
+    /// 
+    #[cfg(feature = "egui")]
+    #[inline]
+    fn on_gui(
+        &mut self, 
+        ctx: &egui::Context, 
+        timer: &GameTimer, 
         device: &wgpu::Device, 
         queue: &wgpu::Queue
     ) { /* empty */ }
 
+    /// #### 한국어 </br>
+    /// 장면을 그립니다. </br>
+    /// `interpolation_alpha`는 이전 고정 갱신과 현재 고정 갱신 사이의 보간 비율입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Draws the scene. </br>
+    /// `interpolation_alpha` is the interpolation ratio between the previous and the current fixed update. </br>
+    /// 
     #[inline]
     fn on_draw(
         &self, 
         render_target_view: &wgpu::TextureView, 
         depth_stencil_view: &wgpu::TextureView, 
+        interpolation_alpha: f32, 
         device: &wgpu::Device, 
         queue: &wgpu::Queue
     ) { /* empty */ }
 }
 
+/// #### 한국어 </br>
+/// 샘플 장면에서 조절할 수 있는 시간 배율의 범위입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The range of the time scale that can be adjusted in the sample scene. </br>
+/// 
+const MIN_TIME_SCALE: f32 = 0.125;
+const MAX_TIME_SCALE: f32 = 8.0;
+
+/// #### 한국어 </br>
+/// GPU 프로파일러가 측정하는 샘플 장면의 렌더 패스들입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The render passes of the sample scene measured by the GPU profiler. </br>
+/// 
+const GPU_PASS_NAMES: [&str; 3] = ["Opaque", "Transparent", "Composite"];
+const GPU_PASS_OPAQUE: usize = 0;
+const GPU_PASS_TRANSPARENT: usize = 1;
+const GPU_PASS_COMPOSITE: usize = 2;
+
+/// #### 한국어 </br>
+/// GPU 프로파일러의 기록을 내보낼 `CSV` 파일의 경로입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The path of the `CSV` file to export the records of the GPU profiler. </br>
+/// 
+const GPU_PROFILE_CSV_PATH: &str = "gpu_profile.csv";
+
+/// #### 한국어 </br>
+/// CPU 프로파일러의 기록을 내보낼 `Chrome trace` 파일의 경로입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The path of the `Chrome trace` file to export the records of the CPU profiler. </br>
+/// 
+const CPU_TRACE_JSON_PATH: &str = "cpu_trace.json";
+
+/// #### 한국어 </br>
+/// 매 프레임 물결 모양으로 변형되는 격자 표면입니다. </br>
+/// 원래의 격자를 보관하며, 변형된 결과는 같은 엔티티의 갱신 가능한 메쉬에 씁니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A grid surface that deforms into a wave every frame. </br>
+/// Keeps the original grid, and the deformed result is written to the updatable mesh of the same entity. </br>
+/// 
+#[derive(Debug, Clone)]
+struct WaveSurface {
+    base: MeshData, 
+    amplitude: f32, 
+    wave_number: f32, 
+    speed: f32, 
+    time: f32, 
+}
+
+impl WaveSurface {
+    fn new(base: MeshData) -> Self {
+        Self { base, amplitude: 0.06, wave_number: 9.0, speed: 3.0, time: 0.0 }
+    }
+
+    /// #### 한국어 </br>
+    /// 현재 시간의 변형된 메쉬 데이터를 계산합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Computes the deformed mesh data at the current time. </br>
+    /// 
+    fn deform(&self) -> MeshData {
+        let mut mesh = self.base.clone();
+        for position in mesh.positions.iter_mut() {
+            let distance = glam::Vec2::new(position.x, position.z).length();
+            position.y = self.amplitude * (self.wave_number * distance - self.speed * self.time).sin();
+        }
+        mesh.compute_smooth_normals();
+        mesh
+    }
+
+    /// #### 한국어 </br>
+    /// 격자를 담을 수 있는 갱신 가능한 메쉬를 생성합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates an updatable mesh that can hold the grid. </br>
+    /// 
+    fn create_mesh(&self, device: &wgpu::Device) -> DynamicMesh {
+        DynamicMesh::new(
+            Some("WaveSurfaceMesh"), 
+            VertexFormat::POSITION_NORMAL, 
+            self.base.num_vertices() as u32, 
+            Some(self.base.indices.len() as u32), 
+            device
+        )
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 한 번의 그리기 호출로 그려지는 엔티티 하나의 유니폼들입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The uniforms of one entity drawn in a single draw call. </br>
+/// 
+type DrawInstance = (EntityUniform, Option<SkinUniform>);
+
+/// #### 한국어 </br>
+/// 같은 재질과 메쉬로 그려지는 엔티티들의 묶음입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A batch of entities drawn with the same material and mesh. </br>
+/// 
+type DrawBatch = (MaterialHandle, ModelMesh, Vec<DrawInstance>);
+
+/// #### 한국어 </br>
+/// 엔티티가 그리는 메쉬입니다. 파일이나 도형으로 만든 메쉬이거나 매 프레임 갱신되는 메쉬입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The mesh drawn by an entity. Either a mesh made from a file or a shape, or a mesh updated every frame. </br>
+/// 
+type DrawnMesh<'a> = hecs::Or<&'a ModelMesh, &'a DynamicMesh>;
+
+/// #### 한국어 </br>
+/// 엔티티가 그리는 메쉬의 현재 상태를 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns the current state of the mesh drawn by an entity. </br>
+/// 
+fn drawn_mesh<'a>(mesh: DrawnMesh<'a>) -> &'a ModelMesh {
+    match mesh {
+        hecs::Or::Left(model_mesh) | hecs::Or::Both(model_mesh, _) => model_mesh, 
+        hecs::Or::Right(dynamic_mesh) => dynamic_mesh.mesh(), 
+    }
+}
+
+
+
 pub struct SampleScene {
+    vfs: Vfs, 
     world: World, 
 
     main_camera: Entity, 
     camera_force: f32, 
+    previous_camera_transform: Transform, 
+
+    paused: bool, 
+    time_scale: f32, 
 
     touch_id: Option<u64>, 
     touch_prev_x: f32, 
+    active_touches: HashSet<u64>, 
 
+    registry: ResourceRegistry, 
     layouts: Arc<HashMap<TypeId, wgpu::BindGroupLayout>>, 
     weighted_blended_oit: Option<WeightedBlendedOIT>,
 
-    graphics_pipelines: HashMap<TypeId, Box<dyn GraphicsPipeline>>,
+    pipeline_cache: PipelineCache, 
+    composite_material: MaterialHandle, 
+
+    debug_view_mode: DebugViewMode, 
+    debug_materials: HashMap<(MaterialHandle, DebugViewMode), MaterialHandle>, 
+    barycentric_wireframe: bool, 
+    selected_vertex_formats: HashSet<(MaterialHandle, VertexFormat)>, 
+    material_variants_dirty: bool, 
+
+    debug_draw: DebugDraw, 
+    debug_draw_material: MaterialHandle, 
+    show_debug_draw: bool, 
+
+    hud: Hud, 
+    hud_material: MaterialHandle, 
+
+    gpu_profiler: Option<GpuProfiler>, 
+
+    meshes: Assets<ModelMesh>, 
+    models: Assets<GltfModel>, 
+    camera_paths: Assets<CameraPath>, 
 }
 
 impl SampleScene {
-    pub fn new(device: &wgpu::Device, queue: &wgpu::Queue) -> Self {
-        use crate::render::mesh::create_plane_mesh;
-        use crate::render::mesh::create_cube_mesh;
-
-        // (한국어) 바인드 그룹 레이아웃들을 생성합니다.
-        // (English Translation) Create a bind group layouts. 
-        let layouts: Arc<HashMap<TypeId, wgpu::BindGroupLayout>> = HashMap::from([
-            (TypeId::of::<CameraUniformLayout>(), CameraUniformLayout::layout(device)),
-            (TypeId::of::<EntityUniformLayout>(), EntityUniformLayout::layout(device)),
-            (TypeId::of::<WeightedBlendedOIT>(), WeightedBlendedOIT::layout(device)), 
-        ]).into();
+    pub fn new(vfs: &Vfs, color_format: wgpu::TextureFormat, device: &wgpu::Device, queue: &wgpu::Queue) -> Result<Self, AppError> {
+        use crate::render::pipeline::create_colored_material;
+        use crate::render::pipeline::create_skinned_material;
+        use crate::render::pipeline::create_transparent_material;
+        use crate::render::pipeline::create_composite_material;
+        use crate::render::pipeline::create_debug_material;
+        use crate::render::pipeline::create_debug_draw_material;
+        use crate::render::pipeline::create_hud_material;
+        use crate::render::pipeline::DebugShaders;
+
+        // (한국어) 바인드 그룹 레이아웃들을 등록하고 생성합니다.
+        // (English Translation) Register and create a bind group layouts. 
+        let mut registry = ResourceRegistry::new();
+        registry.register_layout::<CameraUniformLayout>(CameraUniformLayout::layout);
+        registry.register_layout::<EntityUniformLayout>(EntityUniformLayout::layout);
+        registry.register_layout::<SkinUniformLayout>(SkinUniformLayout::layout);
+        registry.register_layout::<WeightedBlendedOIT>(WeightedBlendedOIT::layout);
+        registry.register_layout::<HudUniformLayout>(HudUniformLayout::layout);
+        let layouts = registry.create_layouts(device);
 
         // (한국어) 메쉬들을 생성합니다.
         // (English Translation) Create the meshes.
-        let plane_mesh = create_plane_mesh(5.0, 5.0, device, queue);
-        let cube_mesh_0 = create_cube_mesh(0.7, 0.7, 0.7, device, queue);
-        let cube_mesh_1 = create_cube_mesh(0.3, 0.3, 0.3, device, queue);
+        let plane_mesh = registry.create_mesh(MeshDescriptor::Plane { width: 5.0, height: 5.0 }, device, queue);
+        let cube_mesh_0 = registry.create_mesh(MeshDescriptor::Cube { width: 0.7, height: 0.7, depth: 0.7 }, device, queue);
+        let cube_mesh_1 = registry.create_mesh(MeshDescriptor::Cube { width: 0.3, height: 0.3, depth: 0.3 }, device, queue);
+        let sphere_mesh = registry.create_mesh(MeshDescriptor::UvSphere { radius: 0.3, segments: 32, rings: 16 }, device, queue);
+        let torus_mesh = registry.create_mesh(MeshDescriptor::Torus { major_radius: 0.3, minor_radius: 0.08, major_segments: 32, minor_segments: 12 }, device, queue);
+        let cone_mesh = registry.create_mesh(MeshDescriptor::Cone { radius: 0.25, height: 0.6, segments: 24 }, device, queue);
+
+        // (한국어) 재질들을 등록하고 그래픽스 파이프라인들을 생성합니다. 
+        // (English Translation) Register materials and create graphics pipelines. 
+        let mut pipeline_cache = PipelineCache::new(color_format);
+        let colored_material = pipeline_cache.register(create_colored_material(device)?);
+        let skinned_material = pipeline_cache.register(create_skinned_material(device)?);
+        let transparent_material = pipeline_cache.register(create_transparent_material(device)?);
+        let composite_material = pipeline_cache.register(create_composite_material(device)?);
+        let debug_draw_material = pipeline_cache.register(create_debug_draw_material(device)?);
+        let hud_material = pipeline_cache.register(create_hud_material(device)?);
 
-        // (한국어) 그래픽스 파이프라인들을 생성합니다. 
-        // (English Translation) Create graphics pipelines. 
-        let colored_pipeline = ColoredPipeline::new(device, &layouts);
-        let transparent_pipeline = TransparentPipeline::new(device, &layouts);
-        let composite_pipeline = CompositePipeline::new(device, &layouts);
+        // (한국어) 각 재질의 디버그 시각화 변형 재질들을 등록합니다.
+        // (English Translation) Register the debug visualization variants of each material.
+        let debug_shaders = DebugShaders::new(device)?;
+        let mut debug_materials = HashMap::new();
+        for material in [colored_material, skinned_material, transparent_material] {
+            for mode in [DebugViewMode::Wireframe, DebugViewMode::Normals, DebugViewMode::Depth, DebugViewMode::Overdraw] {
+                let base = pipeline_cache.material(material)
+                    .ok_or_else(|| AppError::Pipeline(format!("material {:?} is not registered", material)))?;
+                if let Some(variant) = create_debug_material(base, mode, &debug_shaders, device.features()) {
+                    debug_materials.insert((material, mode), pipeline_cache.register(variant));
+                }
+            }
+        }
+        pipeline_cache.prepare(device, &layouts)?;
+
+        // (한국어) 화면 오버레이를 생성합니다.
+        // (English Translation) Create the screen overlay.
+        let hud = Hud::new(BitmapFont::default_font(), &layouts, device, queue)?;
+
+        // (한국어) 장치가 타임스탬프 쿼리를 지원하면 GPU 프로파일러를 생성합니다.
+        // (English Translation) Creates the GPU profiler if the device supports timestamp queries.
+        let gpu_profiler = GpuProfiler::new(&GPU_PASS_NAMES, device, queue);
+        if gpu_profiler.is_some() {
+            log::info!("GPU profiling is enabled for passes: {:?}", GPU_PASS_NAMES);
+        }
+
+        // (한국어) 파일로 된 메쉬와 모델들을 작업 스레드에서 불러오기 시작합니다.
+        // (English Translation) Starts loading the meshes and models stored in files on worker threads.
+        let mut meshes = Assets::new(AssetServer::with_default_workers(vfs.clone()));
+        let octahedron_mesh: Handle<ModelMesh> = meshes.load("meshes/octahedron.obj");
+        let mut models = Assets::new(meshes.server().clone());
+        let tentacle_model: Handle<GltfModel> = models.load("meshes/tentacle.gltf");
+        let mut camera_paths = Assets::new(meshes.server().clone());
+        let orbit_path: Handle<CameraPath> = camera_paths.load("paths/orbit.toml");
 
         // (한국어) 엔티티들을 생성합니다.
         // (English Translation) Create entities.
         let mut world = World::new();
+        let camera_transform = TransformBuilder::new()
+            .set_translation((0.0, 2.0, 5.0).into())
+            .rotate_from_x_axis(-22.0f32.to_radians())
+            .build();
         let camera = world.spawn((
             Projection::Perspective(
                 PerspectiveBuilder::new()
                     .set_aspect_ratio(16.0 / 9.0)
                     .build()
             ), 
-            TransformBuilder::new()
-                .set_translation((0.0, 2.0, 5.0).into())
-                .rotate_from_x_axis(-22.0f32.to_radians())
-                .build(), 
-            CameraUniform::new(&layouts, device), 
+            camera_transform, 
+            CameraUniform::new(&layouts, device)?, 
+            orbit_path, 
+            Tweener::new().with_sequence(
+                TweenSequence::new("CameraIntro")
+                    .then(
+                        Tween::new(TweenTarget::Fov { from: 80.0f32.to_radians(), to: 60.0f32.to_radians() }, 1.5)
+                            .with_easing(Easing::CubicOut)
+                    )
+            ), 
         ));
 
         let _plane = world.spawn((
             Color::Rgb { red: 0.68, green: 0.68, blue: 0.68 }, 
             Transform::new(), 
-            EntityUniform::new(&layouts, device), 
+            EntityUniform::new(&layouts, device)?, 
             plane_mesh.clone(), 
-            colored_pipeline.clone(), 
+            colored_material, 
         ));
 
         let _red_cube = world.spawn((
@@ -163,9 +555,9 @@ impl SampleScene {
             TransformBuilder::new()
                 .set_translation((0.0, 0.5, 0.0).into())
                 .build(), 
-            EntityUniform::new(&layouts, device), 
+            EntityUniform::new(&layouts, device)?, 
             cube_mesh_0.clone(), 
-            colored_pipeline.clone(), 
+            colored_material, 
         ));
 
         let _green_cube = world.spawn((
@@ -174,9 +566,9 @@ impl SampleScene {
                 .set_translation((-1.0, 0.88, 0.67).into())
                 .rotate_from_axis_angle((1.0, 1.0, 0.0).into(), 30.0f32.to_radians())
                 .build(), 
-            EntityUniform::new(&layouts, device), 
+            EntityUniform::new(&layouts, device)?, 
             cube_mesh_0.clone(), 
-            colored_pipeline.clone(), 
+            colored_material, 
         ));
 
         let _blue_cube = world.spawn((
@@ -185,105 +577,986 @@ impl SampleScene {
                 .set_translation((1.33, 1.2, -0.25).into())
                 .rotate_from_axis_angle((1.0, 0.0, 1.0).into(), 60.0f32.to_radians())
                 .build(), 
-            EntityUniform::new(&layouts, device), 
+            EntityUniform::new(&layouts, device)?, 
             cube_mesh_0.clone(), 
-            colored_pipeline.clone(), 
+            colored_material, 
+        ));
+
+        let _yellow_cube = world.spawn((
+            Color::Rgba { red: 0.8, green: 0.8, blue: 0.2, alpha: 0.3 }, 
+            TransformBuilder::new()
+                .set_translation((1.2, 1.0, 1.5).into())
+                .build(),
+            EntityUniform::new(&layouts, device)?, 
+            cube_mesh_1.clone(), 
+            transparent_material, 
+        ));
+
+        let _magenta_cube = world.spawn((
+            Color::Rgba { red: 0.8, green: 0.2, blue: 0.8, alpha: 0.8 }, 
+            TransformBuilder::new()
+                .set_translation((1.3, 1.1, 1.6).into())
+                .build(), 
+            EntityUniform::new(&layouts, device)?, 
+            cube_mesh_1.clone(), 
+            transparent_material, 
+            Tweener::new().with_sequence(
+                TweenSequence::new("MagentaCubeHop")
+                    .then(
+                        Tween::new(TweenTarget::Translation { from: (1.3, 1.1, 1.6).into(), to: (1.3, 1.5, 1.6).into() }, 0.6)
+                            .with_easing(Easing::QuadOut)
+                    )
+                    .with(
+                        Tween::new(TweenTarget::Rotation { from: glam::Quat::IDENTITY, to: glam::Quat::from_rotation_y(90.0f32.to_radians()) }, 0.6)
+                            .with_easing(Easing::SineInOut)
+                    )
+                    .then(
+                        Tween::new(TweenTarget::Translation { from: (1.3, 1.5, 1.6).into(), to: (1.3, 1.1, 1.6).into() }, 0.8)
+                            .with_easing(Easing::BounceOut)
+                    )
+                    .then_wait(0.6)
+                    .with_repeat_mode(RepeatMode::Loop)
+            ), 
+        ));
+
+        let _cyan_cube = world.spawn((
+            Color::Rgba { red: 0.2, green: 0.8, blue: 0.8, alpha: 0.5 }, 
+            TransformBuilder::new()
+                .set_translation((1.1, 0.9, 1.4).into())
+                .build(),
+            EntityUniform::new(&layouts, device)?, 
+            cube_mesh_1.clone(), 
+            transparent_material, 
+            Tweener::new().with_sequence(
+                TweenSequence::new("CyanCubeFade")
+                    .then(
+                        Tween::new(TweenTarget::Alpha { from: 0.5, to: 0.1 }, 1.2)
+                            .with_easing(Easing::SineInOut)
+                    )
+                    .with_repeat_mode(RepeatMode::PingPong)
+            ), 
+        ));
+
+        let _orange_sphere = world.spawn((
+            Color::Rgb { red: 0.9, green: 0.5, blue: 0.1 }, 
+            TransformBuilder::new()
+                .set_translation((-1.7, 0.3, 1.5).into())
+                .build(), 
+            EntityUniform::new(&layouts, device)?, 
+            sphere_mesh, 
+            colored_material, 
+            Tweener::new().with_sequence(
+                TweenSequence::new("OrangeSpherePulse")
+                    .then(
+                        Tween::new(TweenTarget::Scale { from: glam::Vec3::ONE, to: glam::Vec3::splat(1.15) }, 0.5)
+                            .with_easing(Easing::BackOut)
+                    )
+                    .with(
+                        Tween::new(TweenTarget::Color { from: (0.9, 0.5, 0.1, 1.0).into(), to: (1.0, 0.75, 0.2, 1.0).into() }, 0.5)
+                    )
+                    .with_repeat_mode(RepeatMode::PingPong)
+                    .with_repeat_count(6)
+            ), 
         ));
 
-        let _yellow_cube = world.spawn((
-            Color::Rgba { red: 0.8, green: 0.8, blue: 0.2, alpha: 0.3 }, 
-            TransformBuilder::new()
-                .set_translation((1.2, 1.0, 1.5).into())
-                .build(),
-            EntityUniform::new(&layouts, device), 
-            cube_mesh_1.clone(), 
-            transparent_pipeline.clone(), 
-        ));
+        let _purple_torus = world.spawn((
+            Color::Rgb { red: 0.5, green: 0.2, blue: 0.7 }, 
+            TransformBuilder::new()
+                .set_translation((0.0, 0.08, 1.8).into())
+                .build(), 
+            EntityUniform::new(&layouts, device)?, 
+            torus_mesh, 
+            colored_material, 
+        ));
+
+        let _teal_cone = world.spawn((
+            Color::Rgb { red: 0.1, green: 0.6, blue: 0.5 }, 
+            TransformBuilder::new()
+                .set_translation((1.8, 0.3, -1.2).into())
+                .build(), 
+            EntityUniform::new(&layouts, device)?, 
+            cone_mesh, 
+            colored_material, 
+        ));
+
+        // (한국어) 메쉬는 불러오기가 끝난 후 `attach_loaded_meshes`에서 추가됩니다.
+        // (English Translation) The mesh is added in `attach_loaded_meshes` after loading is finished.
+        let _white_octahedron = world.spawn((
+            Color::Rgb { red: 0.9, green: 0.9, blue: 0.9 }, 
+            TransformBuilder::new()
+                .set_translation((-1.4, 0.35, -0.9).into())
+                .build(), 
+            EntityUniform::new(&layouts, device)?, 
+            octahedron_mesh, 
+            colored_material, 
+        ));
+
+        let wave_surface = WaveSurface::new(shape::grid(1.6, 0.9, 48, 27));
+        let _wave_surface = world.spawn((
+            Color::Rgb { red: 0.2, green: 0.45, blue: 0.85 }, 
+            TransformBuilder::new()
+                .set_translation((1.0, 0.1, 2.0).into())
+                .build(), 
+            EntityUniform::new(&layouts, device)?, 
+            wave_surface.create_mesh(device), 
+            wave_surface, 
+            colored_material, 
+        ));
+
+        // (한국어) 메쉬와 골격, 애니메이션은 불러오기가 끝난 후 `attach_loaded_models`에서 추가됩니다.
+        // (English Translation) The mesh, skeleton and animation are added in `attach_loaded_models` after loading is finished.
+        let _coral_tentacle = world.spawn((
+            Color::Rgb { red: 0.9, green: 0.45, blue: 0.35 }, 
+            TransformBuilder::new()
+                .set_translation((-1.0, 0.0, 1.6).into())
+                .build(), 
+            EntityUniform::new(&layouts, device)?, 
+            SkinUniform::new(&layouts, device)?, 
+            tentacle_model, 
+            skinned_material, 
+        ));
+
+        let mut scene = Self { 
+            vfs: vfs.clone(), 
+            world, 
+            main_camera: camera, 
+            camera_force: 0.0, 
+            previous_camera_transform: camera_transform, 
+            paused: false, 
+            time_scale: 1.0, 
+            touch_id: None, 
+            touch_prev_x: 0.0, 
+            active_touches: HashSet::new(), 
+            registry, 
+            layouts, 
+            weighted_blended_oit: None, 
+            pipeline_cache, 
+            composite_material, 
+            debug_view_mode: DebugViewMode::default(), 
+            debug_materials, 
+            barycentric_wireframe: DebugViewMode::Wireframe.uses_barycentric_wireframe(device.features()), 
+            selected_vertex_formats: HashSet::new(), 
+            material_variants_dirty: true, 
+            debug_draw: DebugDraw::new(), 
+            debug_draw_material, 
+            show_debug_draw: true, 
+            hud, 
+            hud_material, 
+            gpu_profiler, 
+            meshes, 
+            models, 
+            camera_paths, 
+        };
+        scene.update_uniforms(queue);
+        scene.update_wave_surfaces(0.0, device, queue);
+        scene.select_material_variants(device);
+
+        Ok(scene)
+    }
+
+    /// #### 한국어 </br>
+    /// 모든 카메라와 엔티티의 유니폼 버퍼를 갱신합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Updates the uniform buffers of all cameras and entities. </br>
+    /// 
+    fn update_uniforms(&self, queue: &wgpu::Queue) {
+        // (한국어) 카메라의 유니폼 버퍼를 갱신합니다.
+        // (English Translation) Updates the camera's uniform buffer.
+        for (_, (projection, transform, uniform)) in self.world.query::<(&Projection, &Transform, &CameraUniform)>().iter() {
+            uniform.update(
+                queue, 
+                CameraUniformLayout {
+                    proj_view: projection.as_ref().mul_mat4(&transform.view_matrix()), 
+                    position: transform.get_translation(), 
+                    ..Default::default()
+                }
+            );
+        }
+
+        // (한국어) 엔티티의 유니폼 버퍼를 갱신합니다.
+        // (English Translation) Updates the entity's uniform buffer. 
+        for (_, (color, transform, uniform)) in self.world.query::<(&Color, &Transform, &EntityUniform)>().iter() {
+            uniform.update(
+                queue,
+                EntityUniformLayout {
+                    color: color.as_vec4(), 
+                    world: *transform.world_matrix_ref(), 
+                }
+            );
+        }
+
+        // (한국어) 골격의 스키닝 행렬들을 갱신합니다.
+        // (English Translation) Updates the skinning matrices of the skeletons.
+        for (_, (skeleton, uniform)) in self.world.query::<(&Skeleton, &SkinUniform)>().iter() {
+            uniform.update(queue, &skeleton.joint_matrices());
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 새로운 장치에서 장면의 모든 GPU 자원을 다시 생성합니다. </br>
+    /// `World`의 엔티티와 컴포넌트(변환, 색상, 투영 등)는 그대로 유지되며, GPU 자원을 가진 컴포넌트만 교체됩니다. </br>
+    /// 윈도우 크기에 따른 자원은 이후 `on_resumed` 또는 `on_resized`에서 다시 생성됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Recreates all GPU resources of the scene on a new device. </br>
+    /// The entities and components (transform, color, projection, etc.) of the `World` are kept, and only the components holding GPU resources are replaced. </br>
+    /// Window size dependent resources are recreated later in `on_resumed` or `on_resized`. </br>
+    /// 
+    fn recreate_gpu_resources(&mut self, device: &wgpu::Device, queue: &wgpu::Queue) -> Result<(), AppError> {
+        self.weighted_blended_oit = None;
+
+        self.layouts = self.registry.create_layouts(device);
+        self.pipeline_cache.recreate(device, &self.layouts)?;
+
+        // (한국어) 엔티티들의 메쉬를 새로운 장치에서 생성된 메쉬로 교체합니다.
+        // (English Translation) Replaces the meshes of entities with the meshes created on the new device.
+        let meshes = self.registry.recreate_meshes(device, queue);
+        for (_, model_mesh) in self.world.query_mut::<&mut ModelMesh>() {
+            if let Some(new_mesh) = meshes.get(&model_mesh.id()) {
+                *model_mesh = new_mesh.clone();
+            }
+        }
+
+        // (한국어) 갱신 가능한 메쉬들은 빈 메쉬로 다시 생성되며, 내용은 이후 `update_wave_surfaces`에서 채워집니다.
+        // (English Translation) Updatable meshes are recreated empty, and their contents are filled later in `update_wave_surfaces`.
+        for (_, (wave_surface, model_mesh)) in self.world.query_mut::<(&WaveSurface, &mut DynamicMesh)>() {
+            *model_mesh = wave_surface.create_mesh(device);
+        }
+        self.update_wave_surfaces(0.0, device, queue);
+
+        // (한국어) 
+        // 파일로 된 메쉬와 모델들은 다시 불러오며, 불러오기가 끝날 때 까지 해당 엔티티들은 그려지지 않습니다.
+        // 골격과 애니메이션 재생 상태는 GPU 자원이 아니므로 그대로 유지됩니다.
+        // 
+        // (English Translation) 
+        // The meshes and models stored in files are loaded again, and the entities are not drawn until loading is finished.
+        // The skeletons and animation playback states are not GPU resources, so they are kept.
+        // 
+        let entities: Vec<_> = self.world.query_mut::<hecs::Or<&Handle<ModelMesh>, &Handle<GltfModel>>>()
+            .into_iter()
+            .map(|(entity, _)| entity)
+            .collect();
+        for entity in entities {
+            let _ = self.world.remove_one::<ModelMesh>(entity);
+        }
+        self.meshes.reload_all();
+        self.models.reload_all();
+
+        // (한국어) 유니폼 버퍼들을 다시 생성하고 `World`에 남아있는 데이터로 채웁니다.
+        // (English Translation) Recreates the uniform buffers and fills them with the data remaining in the `World`.
+        for (_, uniform) in self.world.query_mut::<&mut CameraUniform>() {
+            *uniform = CameraUniform::new(&self.layouts, device)?;
+        }
+        for (_, uniform) in self.world.query_mut::<&mut EntityUniform>() {
+            *uniform = EntityUniform::new(&self.layouts, device)?;
+        }
+        for (_, uniform) in self.world.query_mut::<&mut SkinUniform>() {
+            *uniform = SkinUniform::new(&self.layouts, device)?;
+        }
+        self.update_uniforms(queue);
+
+        self.hud = Hud::new(self.hud.font().clone(), &self.layouts, device, queue)?;
+
+        self.gpu_profiler = GpuProfiler::new(&GPU_PASS_NAMES, device, queue);
+
+        self.debug_draw.release_buffer();
+
+        Ok(())
+    }
+
+    /// #### 한국어 </br>
+    /// 물결 표면들의 시간을 진행시키고 변형된 지오메트리를 메쉬에 씁니다. </br>
+    /// 메쉬는 다시 생성되지 않으므로 엔티티의 그리기 묶음과 선택된 변형 재질이 그대로 유지됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Advances the time of the wave surfaces and writes the deformed geometry to their meshes. </br>
+    /// The meshes are not recreated, so the draw batches and selected variant materials of the entities are kept. </br>
+    /// 
+    fn update_wave_surfaces(&mut self, delta_time_sec: f32, device: &wgpu::Device, queue: &wgpu::Queue) {
+        for (entity, (wave_surface, model_mesh)) in self.world.query_mut::<(&mut WaveSurface, &mut DynamicMesh)>() {
+            wave_surface.time += delta_time_sec;
+            if let Err(err) = wave_surface.deform().update(model_mesh, device, queue) {
+                log::error!("Failed to update the mesh of entity {}: {}", entity.id(), err);
+            }
+        }
+    }
+
+    fn rotate_main_camera(&mut self, angle: f32) {
+        let query = self.world.query_one_mut::<(&mut Transform, Option<&mut CameraSequencer>)>(self.main_camera);
+        if let Ok((transform, sequencer)) = query {
+            // (한국어) 카메라 경로가 카메라를 움직이는 동안에는 경로에서 나올 때 돌아갈 사용자 카메라를 회전시킵니다.
+            // (English Translation) While a camera path moves the camera, rotates the user camera to return to when leaving the path.
+            let transform = match sequencer {
+                Some(sequencer) if sequencer.is_active() => sequencer.user_transform_mut(), 
+                _ => transform, 
+            };
+            let rotation = glam::Quat::from_rotation_y(angle);
+            *transform.world_matrix_mut() = glam::Mat4::from_quat(rotation).mul_mat4(transform.world_matrix_ref());
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 메인 카메라의 카메라 경로 재생을 시작하거나 멈춥니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Starts or stops playing the camera path of the main camera. </br>
+    /// 
+    fn toggle_camera_path(&mut self) {
+        let query = self.world.query_one_mut::<(&mut CameraSequencer, &Transform, &Projection)>(self.main_camera);
+        match query {
+            Ok((sequencer, _, _)) if sequencer.is_playing() => sequencer.stop(), 
+            Ok((sequencer, transform, projection)) => sequencer.play(transform, projection), 
+            Err(_) => log::warn!("The camera path is not loaded yet"), 
+        };
+    }
+
+    /// #### 한국어 </br>
+    /// 사용자가 카메라를 조작하기 시작하면 카메라 경로 재생을 멈추고 사용자 카메라로 돌아갑니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// When the user starts controlling the camera, stops playing the camera path and returns to the user camera. </br>
+    /// 
+    fn yield_camera_to_user(&mut self) {
+        if let Ok(sequencer) = self.world.query_one_mut::<&mut CameraSequencer>(self.main_camera) {
+            sequencer.stop();
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 카메라 경로를 따라 메인 카메라의 변환과 투영을 갱신합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Updates the transform and projection of the main camera along the camera path. </br>
+    /// 
+    fn update_camera_sequencer(&mut self, delta_time_sec: f32) {
+        let query = self.world.query_one_mut::<(&mut CameraSequencer, &mut Transform, &mut Projection)>(self.main_camera);
+        if let Ok((sequencer, transform, projection)) = query {
+            sequencer.update(delta_time_sec, transform, projection);
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 이번 프레임에 그려질 디버그 도형들을 수집합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Collects the debug shapes to be drawn in this frame. </br>
+    /// 
+    fn collect_debug_shapes(&mut self) {
+        self.debug_draw.clear();
+        if !self.show_debug_draw {
+            return;
+        }
+
+        self.debug_draw.axes(&glam::Mat4::IDENTITY, 1.0);
+        for (_, (transform, _)) in self.world.query::<(&Transform, DrawnMesh)>().iter() {
+            self.debug_draw.axes(transform.world_matrix_ref(), 0.5);
+        }
+
+        self.debug_draw.sphere(
+            (1.2, 1.0, 1.5).into(), 
+            0.45, 
+            Color::Rgb { red: 0.9, green: 0.6, blue: 0.1 }
+        );
+        self.debug_draw.aabb(
+            (-2.5, 0.0, -2.5).into(), 
+            (2.5, 2.0, 2.5).into(), 
+            Color::Rgba { red: 0.3, green: 0.3, blue: 0.3, alpha: 0.5 }
+        );
+    }
+
+    /// #### 한국어 </br>
+    /// 이번 프레임에 그려질 화면 오버레이를 구성합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Builds the screen overlay to be drawn in this frame. </br>
+    /// 
+    fn build_hud(&mut self, timer: &GameTimer) {
+        const MARGIN: f32 = 8.0;
+        const PADDING: f32 = 6.0;
+        const BUTTON_HEIGHT: f32 = 28.0;
+
+        self.hud.clear();
+
+        // (한국어) 초당 프레임 수와 프레임 시간, 끊김 횟수를 표시합니다.
+        // (English Translation) Displays the frames per second, the frame time and the hitch count.
+        let frame_time_stats = timer.frame_time_stats();
+        let mut stats = format!(
+            "FPS: {}\nFrame: {:.2} ms (p99 {:.2} ms)\nHitches: {}", 
+            timer.frame_rate(), 
+            timer.elapsed_time_sec() * 1000.0, 
+            frame_time_stats.p99_sec * 1000.0, 
+            timer.hitch_count()
+        );
+
+        // (한국어) GPU 프로파일러가 있다면 패스별 GPU 시간을 함께 표시합니다.
+        // (English Translation) If there is a GPU profiler, also displays the GPU time per pass.
+        if let Some(gpu_profiler) = self.gpu_profiler.as_ref() {
+            stats += &format!("\nGPU: {:.2} ms", gpu_profiler.total_time_ms());
+            for (name, time) in gpu_profiler.pass_times_ms() {
+                match time {
+                    Some(time) => stats += &format!("\n  {}: {:.2} ms", name, time), 
+                    None => stats += &format!("\n  {}: -", name), 
+                };
+            }
+        }
+
+        // (한국어) CPU 프로파일러가 켜져 있다면 마지막으로 완료된 프레임의 CPU 시간을 함께 표시합니다.
+        // (English Translation) If the CPU profiler is on, also displays the CPU time of the last completed frame.
+        if let Some(frame) = profiler::last_frame() {
+            stats += &format!("\nCPU: {:.2} ms", frame.duration_us / 1000.0);
+        }
+        let size = self.hud.text_size(&stats, 1.0);
+        self.hud.panel(
+            MARGIN, MARGIN, 
+            size.x + 2.0 * PADDING, size.y + 2.0 * PADDING, 
+            Color::Rgba { red: 0.0, green: 0.0, blue: 0.0, alpha: 0.6 }
+        );
+        self.hud.text(
+            MARGIN + PADDING, MARGIN + PADDING, 
+            1.0, 
+            Color::Rgb { red: 1.0, green: 1.0, blue: 1.0 }, 
+            &stats
+        );
+
+        // (한국어) 디버그 시각화 방식과 디버그 도형 표시를 전환하는 버튼들입니다.
+        // (English Translation) Buttons that switch the debug visualization mode and the display of debug shapes.
+        let y = self.hud.screen_size().y - MARGIN - BUTTON_HEIGHT;
+        let label = format!("View: {:?}", self.debug_view_mode);
+        if self.hud.button(MARGIN, y, 160.0, BUTTON_HEIGHT, &label) {
+            self.cycle_debug_view_mode();
+        }
+
+        let label = if self.show_debug_draw { "Gizmos: On" } else { "Gizmos: Off" };
+        if self.hud.button(2.0 * MARGIN + 160.0, y, 120.0, BUTTON_HEIGHT, label) {
+            self.show_debug_draw = !self.show_debug_draw;
+        }
+
+        let is_playing_path = self.world.query_one_mut::<&CameraSequencer>(self.main_camera)
+            .is_ok_and(|sequencer| sequencer.is_playing());
+        let label = if is_playing_path { "Camera: Path" } else { "Camera: Free" };
+        if self.hud.button(MARGIN, y - MARGIN - BUTTON_HEIGHT, 160.0, BUTTON_HEIGHT, label) {
+            self.toggle_camera_path();
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 화면 오버레이를 렌더 타겟 위에 그립니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Draws the screen overlay on top of the render target. </br>
+    /// 
+    fn draw_hud(
+        &self, 
+        encoder: &mut wgpu::CommandEncoder, 
+        render_target_view: &wgpu::TextureView, 
+        depth_stencil_view: &wgpu::TextureView
+    ) {
+        let Some(pipeline) = self.pipeline_cache.pipeline(self.hud_material) else { return };
+        if self.hud.is_empty() {
+            return;
+        }
+
+        let mut rpass = encoder.begin_render_pass(
+            &wgpu::RenderPassDescriptor {
+                label: Some("RenderPass(Hud)"), 
+                color_attachments: &[
+                    Some(wgpu::RenderPassColorAttachment {
+                        view: render_target_view, 
+                        resolve_target: None, 
+                        ops: wgpu::Operations {
+                            load: wgpu::LoadOp::Load, 
+                            store: wgpu::StoreOp::Store, 
+                        }, 
+                    }), 
+                ], 
+                depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment { 
+                    view: depth_stencil_view, 
+                    depth_ops: Some(wgpu::Operations {
+                        load: wgpu::LoadOp::Load, 
+                        store: wgpu::StoreOp::Store, 
+                    }), 
+                    stencil_ops: None, 
+                }), 
+                occlusion_query_set: None, 
+                timestamp_writes: None, 
+            }, 
+        );
+
+        rpass.set_pipeline(pipeline);
+        self.hud.draw(&mut rpass, 0);
+    }
+
+    /// #### 한국어 </br>
+    /// 명령어 대기열에 명령어 목록을 제출합니다. </br>
+    /// GPU 프로파일러가 있다면 제출 전에 타임스탬프를 복사하고, 제출 후에 결과를 읽어오기 시작합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Submits a list of commands to the command queue. </br>
+    /// If there is a GPU profiler, copies the timestamps before submitting and starts reading back the results after submitting. </br>
+    /// 
+    fn submit_commands(&self, mut encoder: wgpu::CommandEncoder, queue: &wgpu::Queue) {
+        let _scope = profiler::scope("SubmitCommands");
+        if let Some(gpu_profiler) = self.gpu_profiler.as_ref() {
+            gpu_profiler.resolve(&mut encoder);
+        }
+
+        queue.submit(Some(encoder.finish()));
+
+        if let Some(gpu_profiler) = self.gpu_profiler.as_ref() {
+            gpu_profiler.map_results();
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// GPU 프로파일러의 기록을 `CSV` 파일로 내보냅니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Exports the records of the GPU profiler to a `CSV` file. </br>
+    /// 
+    fn export_gpu_profile(&self) {
+        let Some(gpu_profiler) = self.gpu_profiler.as_ref() else {
+            log::warn!("GPU profiling is not enabled.");
+            return;
+        };
+
+        match gpu_profiler.export_csv(GPU_PROFILE_CSV_PATH) {
+            Ok(_) => log::info!("Exported the GPU profile to {}", GPU_PROFILE_CSV_PATH), 
+            Err(err) => log::error!("Failed to export the GPU profile to {}: {}", GPU_PROFILE_CSV_PATH, err), 
+        };
+    }
+
+    /// #### 한국어 </br>
+    /// CPU 프로파일러에 남아있는 최근 프레임들을 `Chrome trace` 파일로 내보냅니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Exports the recent frames remaining in the CPU profiler to a `Chrome trace` file. </br>
+    /// 
+    fn export_cpu_trace(&self) {
+        if !profiler::is_enabled() {
+            log::warn!("CPU profiling is not enabled.");
+            return;
+        }
+
+        match profiler::export_chrome_trace(CPU_TRACE_JSON_PATH) {
+            Ok(_) => log::info!("Exported the CPU trace to {}", CPU_TRACE_JSON_PATH), 
+            Err(err) => log::error!("Failed to export the CPU trace to {}: {}", CPU_TRACE_JSON_PATH, err), 
+        };
+    }
+
+    /// #### 한국어 </br>
+    /// 재생 중인 트윈들의 시간을 진행시키고 값을 엔티티들에 쓴 뒤, 그 엔티티들의 유니폼 버퍼를 갱신합니다. </br>
+    /// 재생이 끝난 트윈은 더 이상 값을 쓰지 않으므로, 이후의 편집을 덮어쓰지 않습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Advances the time of the playing tweens, writes their values to the entities and updates the uniform buffers of those entities. </br>
+    /// Tweens that finished playing no longer write values, so they do not overwrite later edits. </br>
+    /// 
+    fn update_tweens(&mut self, delta_time_sec: f32, queue: &wgpu::Queue) {
+        let mut updated = Vec::new();
+        let mut events = Vec::new();
+        let query = self.world.query_mut::<(&mut Tweener, Option<&mut Transform>, Option<&mut Color>, Option<&mut Projection>)>();
+        for (entity, (tweener, transform, color, projection)) in query.into_iter() {
+            if !tweener.is_playing() {
+                continue;
+            }
+
+            tweener.advance(delta_time_sec);
+            tweener.apply(&mut TweenTargets { transform, color, projection });
+            events.extend(tweener.drain_events().map(|event| (entity, event)));
+            updated.push(entity);
+        }
+
+        for entity in updated {
+            self.update_entity_uniform(queue, entity);
+        }
+        for (entity, event) in events {
+            match event {
+                TweenEvent::Repeated { sequence, count } => {
+                    log::debug!("Tween sequence {} of entity {} finished playback {}", sequence, entity.id(), count);
+                }, 
+                TweenEvent::Completed { sequence } => {
+                    log::info!("Tween sequence {} of entity {} completed", sequence, entity.id());
+                }, 
+            };
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 애니메이션 재생기들의 시간을 진행시키고 골격들에 자세를 적용한 뒤, 스키닝 행렬들을 유니폼 버퍼에 씁니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Advances the time of the animation players, applies the poses to the skeletons and writes the skinning matrices to the uniform buffers. </br>
+    /// 
+    fn animate_skeletons(&mut self, delta_time_sec: f32, queue: &wgpu::Queue) {
+        for (_, (player, skeleton, uniform)) in self.world.query_mut::<(&mut AnimationPlayer, &mut Skeleton, &SkinUniform)>() {
+            player.advance(delta_time_sec);
+            player.apply(skeleton);
+            uniform.update(queue, &skeleton.joint_matrices());
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 불러오기가 끝난 메쉬를 그 메쉬의 핸들을 가진 엔티티들에 추가합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Adds the meshes whose loading is finished to the entities holding their handles. </br>
+    /// 
+    fn attach_loaded_meshes(&mut self) {
+        let mut loaded = Vec::new();
+        for (entity, (handle, model_mesh)) in self.world.query::<(&Handle<ModelMesh>, Option<&ModelMesh>)>().iter() {
+            match (self.meshes.load_state(handle), self.meshes.get(handle)) {
+                (LoadState::Ready, Some(new_mesh)) if model_mesh != Some(new_mesh) => {
+                    loaded.push((entity, new_mesh.clone()));
+                }, 
+                (LoadState::Failed, _) => {
+                    log::warn!(
+                        "Mesh of entity {} could not be loaded: {}", 
+                        entity.id(), 
+                        self.meshes.load_error(handle).unwrap_or("unknown error")
+                    );
+                }, 
+                _ => { /* empty */ }, 
+            };
+        }
+
+        self.material_variants_dirty |= !loaded.is_empty();
+        for (entity, model_mesh) in loaded {
+            let _ = self.world.insert_one(entity, model_mesh);
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 불러오기가 끝난 모델을 그 모델의 핸들을 가진 엔티티들에 추가합니다. </br>
+    /// 엔티티는 모델의 첫 번째 메쉬를 그리며, 골격과 첫 번째 애니메이션 클립의 재생기는 엔티티에 아직 없을 때만 추가됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Adds the models whose loading is finished to the entities holding their handles. </br>
+    /// The entity draws the first mesh of the model, and the skeleton and a player for the first animation clip are added only if the entity does not have them yet. </br>
+    /// 
+    fn attach_loaded_models(&mut self) {
+        let mut loaded = Vec::new();
+        let mut query = self.world.query::<(&Handle<GltfModel>, Option<&ModelMesh>, Option<&Skeleton>, Option<&AnimationPlayer>)>();
+        for (entity, (handle, model_mesh, skeleton, player)) in query.iter() {
+            match (self.models.load_state(handle), self.models.get(handle)) {
+                (LoadState::Ready, Some(model)) if model_mesh != model.meshes().first() => {
+                    if model.meshes().len() > 1 {
+                        log::warn!("Model of entity {} has {} meshes, but only the first one is drawn", entity.id(), model.meshes().len());
+                    }
+                    let skeleton = model.skeleton().filter(|_| skeleton.is_none()).cloned();
+                    let player = model.clips().first()
+                        .filter(|_| player.is_none())
+                        .map(|clip| AnimationPlayer::new(clip.clone()));
+                    loaded.push((entity, model.meshes()[0].clone(), skeleton, player));
+                }, 
+                (LoadState::Failed, _) => {
+                    log::warn!(
+                        "Model of entity {} could not be loaded: {}", 
+                        entity.id(), 
+                        self.models.load_error(handle).unwrap_or("unknown error")
+                    );
+                }, 
+                _ => { /* empty */ }, 
+            };
+        }
+        drop(query);
+
+        self.material_variants_dirty |= !loaded.is_empty();
+        for (entity, model_mesh, skeleton, player) in loaded {
+            let _ = self.world.insert_one(entity, model_mesh);
+            if let Some(skeleton) = skeleton {
+                let _ = self.world.insert_one(entity, skeleton);
+            }
+            if let Some(player) = player {
+                let _ = self.world.insert_one(entity, player);
+            }
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 불러오기가 끝난 카메라 경로를 그 경로의 핸들을 가진 엔티티들에 재생기로 추가합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Adds the camera paths whose loading is finished to the entities holding their handles as sequencers. </br>
+    /// 
+    fn attach_loaded_camera_paths(&mut self) {
+        let mut loaded = Vec::new();
+        for (entity, (handle, sequencer)) in self.world.query::<(&Handle<CameraPath>, Option<&CameraSequencer>)>().iter() {
+            match (self.camera_paths.load_state(handle), self.camera_paths.get(handle)) {
+                (LoadState::Ready, Some(path)) if sequencer.map_or(true, |it| **it.path() != *path) => {
+                    let sequencer = CameraSequencer::new(Arc::new(path.clone()))
+                        .with_blend_durations(1.5, 1.0);
+                    loaded.push((entity, sequencer));
+                }, 
+                (LoadState::Failed, _) => {
+                    log::warn!(
+                        "Camera path of entity {} could not be loaded: {}", 
+                        entity.id(), 
+                        self.camera_paths.load_error(handle).unwrap_or("unknown error")
+                    );
+                }, 
+                _ => { /* empty */ }, 
+            };
+        }
+
+        for (entity, sequencer) in loaded {
+            let _ = self.world.insert_one(entity, sequencer);
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 엔티티들의 메쉬 형식에 맞는 변형 재질들을 선택하고 그 그래픽스 파이프라인을 생성합니다. </br>
+    /// 메쉬에 재질이 요구하는 속성이 없는 경우 한 번만 보고하며, 해당 엔티티들은 그려지지 않습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Selects the variant materials matching the mesh formats of entities and creates their graphics pipelines. </br>
+    /// If a mesh lacks an attribute required by the material, it is reported only once and those entities are not drawn. </br>
+    /// 
+    fn select_material_variants(&mut self, device: &wgpu::Device) {
+        // (한국어) 메쉬가 추가되거나 디버그 시각화 방식이 바뀐 경우에만 엔티티들을 다시 검사합니다.
+        // (English Translation) Entities are checked again only when meshes were added or the debug view mode changed.
+        if !std::mem::take(&mut self.material_variants_dirty) {
+            return;
+        }
+
+        let pairs: HashSet<_> = self.world.query::<(&MaterialHandle, DrawnMesh)>()
+            .iter()
+            .map(|(_, (material, mesh))| (*material, drawn_mesh(mesh).format()))
+            .filter(|pair| !self.selected_vertex_formats.contains(pair))
+            .collect();
+        if pairs.is_empty() {
+            return;
+        }
+
+        for (material, format) in pairs {
+            self.selected_vertex_formats.insert((material, format));
+            let debug_materials = self.debug_materials.iter()
+                .filter(|((base, _), _)| *base == material)
+                .map(|(_, variant)| *variant);
+            for handle in std::iter::once(material).chain(debug_materials) {
+                if let Err(err) = self.pipeline_cache.select(handle, format) {
+                    log::error!("{}. The entities with this mesh are not drawn.", err);
+                }
+            }
+        }
+
+        // (한국어) 생성에 실패한 파이프라인은 캐시에 추가되지 않으므로, 해당 변형 재질을 사용하는 엔티티들은 그려지지 않습니다.
+        // (English Translation) Pipelines that failed to be created are not added to the cache, so the entities using those variant materials are not drawn.
+        if let Err(err) = self.pipeline_cache.prepare(device, &self.layouts) {
+            log::error!("Failed to create the graphics pipelines of the material variants: {}. The entities with these variants are not drawn.", err);
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 다음 디버그 시각화 방식으로 전환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Switches to the next debug visualization mode. </br>
+    /// 
+    fn cycle_debug_view_mode(&mut self) {
+        self.set_debug_view_mode(self.debug_view_mode.next());
+    }
 
-        let _magenta_cube = world.spawn((
-            Color::Rgba { red: 0.8, green: 0.2, blue: 0.8, alpha: 0.8 }, 
-            TransformBuilder::new()
-                .set_translation((1.3, 1.1, 1.6).into())
-                .build(), 
-            EntityUniform::new(&layouts, device), 
-            cube_mesh_1.clone(), 
-            transparent_pipeline.clone(), 
-        ));
+    /// #### 한국어 </br>
+    /// 디버그 시각화 방식을 바꾸고, 다음 갱신에서 변형 재질들을 다시 선택하도록 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Changes the debug visualization mode, and makes the variant materials be selected again in the next update. </br>
+    /// 
+    fn set_debug_view_mode(&mut self, mode: DebugViewMode) {
+        self.debug_view_mode = mode;
+        self.material_variants_dirty = true;
+        log::info!("Debug view mode: {:?}", self.debug_view_mode);
+    }
 
-        let _cyan_cube = world.spawn((
-            Color::Rgba { red: 0.2, green: 0.8, blue: 0.8, alpha: 0.5 }, 
-            TransformBuilder::new()
-                .set_translation((1.1, 0.9, 1.4).into())
-                .build(),
-            EntityUniform::new(&layouts, device), 
-            cube_mesh_1.clone(), 
-            transparent_pipeline.clone(), 
-        ));
+    /// #### 한국어 </br>
+    /// 변경된 셰이더 파일과 같은 이름의 셰이더들을 다시 컴파일하고, 그 셰이더들을 사용하는 그래픽스 파이프라인들을 다시 생성합니다. </br>
+    /// 컴파일에 실패한 경우 기존의 파이프라인을 그대로 사용합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Recompiles the shaders with the same name as the changed shader file and recreates the graphics pipelines that use them. </br>
+    /// If compilation fails, the existing pipelines are kept. </br>
+    /// 
+    fn reload_shaders(&mut self, path: &Path, device: &wgpu::Device) -> Result<(), AppError> {
+        use wgpu::naga::ShaderStage;
 
-        // (한국어) 카메라의 유니폼 버퍼를 갱신합니다.
-        // (English Translation) Updates the camera's uniform buffer.
-        for (_, (projection, transform, uniform)) in world.query::<(&Projection, &Transform, &CameraUniform)>().iter() {
-            uniform.update(
-                queue, 
-                CameraUniformLayout {
-                    proj_view: projection.as_ref().mul_mat4(&transform.view_matrix()), 
-                    position: transform.get_translation(), 
-                    ..Default::default()
-                }
-            );
+        let dir = path.parent()
+            .ok_or_else(|| ShaderError::NotFound(path.display().to_string()))?;
+        let name = path.file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.split('.').next())
+            .ok_or_else(|| ShaderError::UnknownFormat(path.to_path_buf()))?;
+
+        // (한국어) 
+        // 서로 다른 프론트엔드로 컴파일된 셰이더 단계들은 인터페이스가 일치하지 않을 수 있으므로,
+        // 같은 이름의 모든 단계를 우선순위가 가장 높은 소스 파일로 함께 다시 컴파일합니다.
+        // 
+        // (English Translation) 
+        // Since shader stages compiled with different frontends may have mismatched interfaces, 
+        // all stages with the same name are recompiled together from the source files with the highest priority.
+        // 
+        let mut sources = Vec::new();
+        for (stage, suffix) in [(ShaderStage::Vertex, "vs"), (ShaderStage::Fragment, "fs")] {
+            if let Some(source_path) = find_shader_file(&self.vfs, dir, name, stage) {
+                sources.push((format!("{}.{}", name, suffix), ShaderSource::load(&self.vfs, &source_path)?));
+            }
         }
 
-        // (한국어) 엔티티의 유니폼 버퍼를 갱신합니다.
-        // (English Translation) Updates the entity's uniform buffer. 
-        for (_, (color, transform, uniform)) in world.query::<(&Color, &Transform, &EntityUniform)>().iter() {
-            uniform.update(
-                queue,
-                EntityUniformLayout {
-                    color: color.as_vec4(), 
-                    world: transform.world_matrix_ref().clone(), 
-                }
-            );
+        // (한국어) 셰이더 생성 중 발생한 오류가 애플리케이션을 종료시키지 않도록 오류를 포착합니다.
+        // (English Translation) Captures errors during shader creation so that they do not terminate the application.
+        device.push_error_scope(wgpu::ErrorFilter::Validation);
+        let shaders: Result<Vec<Shader>, ShaderError> = sources.iter()
+            .map(|(shader_name, source)| Shader::from_source(shader_name, source, device))
+            .collect();
+        let error = pollster::block_on(device.pop_error_scope());
+
+        let shaders = shaders?;
+        if let Some(error) = error {
+            return Err(ShaderError::from(error).into());
         }
 
-        Self { 
-            world, 
-            main_camera: camera, 
-            camera_force: 0.0, 
-            touch_id: None, 
-            touch_prev_x: 0.0, 
-            layouts, 
-            weighted_blended_oit: None, 
-            graphics_pipelines: HashMap::from([
-                (colored_pipeline.type_id(), Box::new(colored_pipeline) as Box<_>),
-                (transparent_pipeline.type_id(), Box::new(transparent_pipeline) as Box<_>), 
-                (composite_pipeline.type_id(), Box::new(composite_pipeline) as Box<_>),
-            ]), 
+        let num_replaced = self.pipeline_cache.replace_shaders(&shaders, device, &self.layouts)?;
+        log::info!("Reloaded shaders {} ({} material(s))", name, num_replaced);
+        Ok(())
+    }
+
+    /// #### 한국어 </br>
+    /// 주어진 블렌드 방식의 재질로 그려지는 엔티티들을 재질과 메쉬 별로 묶습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Groups entities drawn with materials of the given blend modes by material and mesh. </br>
+    /// 
+    fn collect_draw_batches(&self, blend_modes: &[BlendMode]) -> Vec<DrawBatch> {
+        let mut query = self.world.query::<(&MaterialHandle, DrawnMesh, &EntityUniform, Option<&SkinUniform>)>();
+        let mut batches: HashMap<(MaterialHandle, u64), (ModelMesh, Vec<DrawInstance>)> = HashMap::new();
+        for (_id, (material, mesh, uniform, skin)) in query.iter() {
+            let is_target = self.pipeline_cache.material(*material)
+                .is_some_and(|material| blend_modes.contains(&material.blend_mode));
+            if is_target {
+                let model_mesh = drawn_mesh(mesh);
+                batches.entry((*material, model_mesh.id()))
+                    .or_insert_with(|| (model_mesh.clone(), Vec::new()))
+                    .1
+                    .push((uniform.clone(), skin.cloned()));
+            }
         }
+
+        // (한국어) 블렌드 방식의 순서대로 정렬하여, 불투명한 물체가 먼저 그려지도록 합니다.
+        // (English Translation) Sorts in the order of blend modes so that opaque objects are drawn first.
+        let mut batches: Vec<_> = batches.into_iter()
+            .map(|((material, _), (model_mesh, uniforms))| (material, model_mesh, uniforms))
+            .collect();
+        batches.sort_by_key(|(material, _, _)| {
+            let blend_mode = self.pipeline_cache.material(*material).map(|material| material.blend_mode);
+            (blend_modes.iter().position(|it| Some(*it) == blend_mode), *material)
+        });
+        batches
     }
 
-    fn rotate_main_camera(&mut self, angle: f32) {
-        let query = self.world.query_one_mut::<&mut Transform>(self.main_camera);
-        if let Ok(transform) = query {
-            let rotation = glam::Quat::from_rotation_y(angle);
-            *transform.world_matrix_mut() = glam::Mat4::from_quat(rotation).mul_mat4(transform.world_matrix_ref());
+    /// #### 한국어 </br>
+    /// 모든 엔티티들을 현재 디버그 시각화 방식의 변형 재질로 묶습니다. </br>
+    /// 변형 재질이 없는 엔티티는 그려지지 않습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Groups all entities with the variant materials of the current debug visualization mode. </br>
+    /// Entities without a variant material are not drawn. </br>
+    /// 
+    fn collect_debug_draw_batches(&self) -> Vec<DrawBatch> {
+        let mut batches: Vec<_> = self.collect_draw_batches(&[
+                BlendMode::Opaque, 
+                BlendMode::Alpha, 
+                BlendMode::Additive, 
+                BlendMode::WeightedBlendedOIT
+            ])
+            .into_iter()
+            .filter_map(|(material, model_mesh, uniforms)| {
+                self.debug_materials.get(&(material, self.debug_view_mode))
+                    .map(|variant| (*variant, model_mesh, uniforms))
+            })
+            .collect();
+        batches.sort_by_key(|(material, _, _)| *material);
+        batches
+    }
+
+    fn draw_batches<'a>(
+        &'a self, 
+        rpass: &mut wgpu::RenderPass<'a>, 
+        batches: &'a [DrawBatch], 
+        unindexed: bool
+    ) {
+        let mut current_material = None;
+        for (material, model_mesh, entities) in batches.iter() {
+            // (한국어) 메쉬의 형식에 맞는 파이프라인이 없다면 잘못된 레이아웃으로 그리지 않고 건너뜁니다.
+            // (English Translation) If there is no pipeline matching the mesh's format, skips it instead of drawing with the wrong layout.
+            let format = model_mesh.format();
+            if current_material != Some((*material, format)) {
+                let Some(pipeline) = self.pipeline_cache.pipeline_for(*material, format) else { continue };
+                rpass.set_pipeline(pipeline);
+                current_material = Some((*material, format));
+            }
+
+            // (한국어) 무게중심 좌표 와이어프레임은 삼각형마다 고유한 정점이 필요합니다.
+            // (English Translation) The barycentric wireframe requires unique vertices per triangle.
+            if unindexed {
+                for (entity, skin) in entities.iter() {
+                    entity.bind(rpass, 1);
+                    if let Some(skin) = skin {
+                        skin.bind(rpass, 2);
+                    }
+                    model_mesh.draw_unindexed(rpass, 0, 0..1);
+                }
+            } else {
+                model_mesh.bind(rpass, 0);
+                for (entity, skin) in entities.iter() {
+                    entity.bind(rpass, 1);
+                    if let Some(skin) = skin {
+                        skin.bind(rpass, 2);
+                    }
+                    model_mesh.draw(rpass, 0..1);
+                }
+            }
         }
     }
 
-    fn update_camera_uniform(&mut self, queue: &wgpu::Queue, entity: Entity) {
-        let query = self.world.query_one::<(&Projection, &Transform, &CameraUniform)>(entity);
+    fn update_entity_uniform(&mut self, queue: &wgpu::Queue, entity: Entity) {
+        let query = self.world.query_one::<(&Color, &Transform, &EntityUniform)>(entity);
         if let Ok(mut query_one) = query {
-            if let Some((projection, transform, uniform)) = query_one.get() {
+            if let Some((color, transform, uniform)) = query_one.get() {
                 uniform.update(
                     queue, 
-                    CameraUniformLayout { 
-                        proj_view: projection.as_ref().mul_mat4(&transform.view_matrix()), 
-                        position: transform.get_translation(), 
-                        ..Default::default() 
+                    EntityUniformLayout { 
+                        color: color.as_vec4(), 
+                        world: *transform.world_matrix_ref(), 
                     }
                 );
             };
         };
     }
+
+    /// #### 한국어 </br>
+    /// 윈도우 크기에 맞게 Weighted Blended OIT의 렌더 타겟들을 다시 생성합니다. </br>
+    /// 생성에 실패한 경우 오류를 기록하고, 다음 크기 변경 전까지 프레임을 그리지 않습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Recreates the render targets of Weighted Blended OIT to fit the window size. </br>
+    /// If creation fails, the error is logged and frames are not drawn until the next resize. </br>
+    /// 
+    fn resize_weighted_blended_oit(&mut self, window: &Window, device: &wgpu::Device) {
+        let result = WeightedBlendedOIT::new(
+            window.inner_size().width, 
+            window.inner_size().height, 
+            &self.layouts, 
+            device
+        );
+
+        self.weighted_blended_oit = match result {
+            Ok(weighted_blended_oit) => Some(weighted_blended_oit), 
+            Err(err) => {
+                log::error!("Failed to create WeightedBlendedOIT: {}", err);
+                None
+            }, 
+        };
+    }
 }
 
 impl GameScene for SampleScene {
@@ -301,12 +1574,8 @@ impl GameScene for SampleScene {
         device: &wgpu::Device, 
         _queue: &wgpu::Queue
     ) {
-        self.weighted_blended_oit = Some(WeightedBlendedOIT::new(
-            window.inner_size().width, 
-            window.inner_size().height, 
-            &self.layouts, 
-            device
-        ))
+        self.hud.set_screen_size(window.inner_size().width, window.inner_size().height, window.scale_factor());
+        self.resize_weighted_blended_oit(window, device);
     }
 
     fn on_resized(
@@ -315,12 +1584,8 @@ impl GameScene for SampleScene {
         device: &wgpu::Device, 
         _queue: &wgpu::Queue
     ) {
-        self.weighted_blended_oit = Some(WeightedBlendedOIT::new(
-            window.inner_size().width, 
-            window.inner_size().height, 
-            &self.layouts, 
-            device
-        ))
+        self.hud.set_screen_size(window.inner_size().width, window.inner_size().height, window.scale_factor());
+        self.resize_weighted_blended_oit(window, device);
     }
 
     fn on_touch_event(
@@ -329,6 +1594,26 @@ impl GameScene for SampleScene {
         _device: &wgpu::Device, 
         _queue: &wgpu::Queue
     ) {
+        // (한국어) 세 손가락으로 화면을 터치하면 디버그 시각화 방식을 전환합니다.
+        // (English Translation) Touching the screen with three fingers switches the debug visualization mode.
+        match touch.phase {
+            TouchPhase::Started => {
+                self.hud.pointer_moved(touch.location.x as f32, touch.location.y as f32);
+                self.hud.pointer_pressed(true);
+                self.active_touches.insert(touch.id);
+                if self.active_touches.len() == 3 {
+                    self.cycle_debug_view_mode();
+                }
+            }, 
+            TouchPhase::Moved => {
+                self.hud.pointer_moved(touch.location.x as f32, touch.location.y as f32);
+            }, 
+            TouchPhase::Ended | TouchPhase::Cancelled => {
+                self.hud.pointer_pressed(false);
+                self.active_touches.remove(&touch.id);
+            }, 
+        };
+
         match touch.phase {
             TouchPhase::Started if self.touch_id.is_none() => {
                 self.touch_id = Some(touch.id);
@@ -338,6 +1623,7 @@ impl GameScene for SampleScene {
                 if touch_id == touch.id {
                     self.camera_force = (self.touch_prev_x - touch.location.x as f32).to_radians();
                     self.touch_prev_x = touch.location.x as f32;
+                    self.yield_camera_to_user();
                 };
             },
             TouchPhase::Ended => if let Some(touch_id) = self.touch_id {
@@ -360,50 +1646,375 @@ impl GameScene for SampleScene {
         if let PhysicalKey::Code(code) = &event.physical_key {
             if KeyCode::ArrowLeft == *code && !event.repeat && event.state.is_pressed() {
                 self.camera_force -= 180.0f32.to_radians();
+                self.yield_camera_to_user();
             } else if KeyCode::ArrowRight == *code && !event.repeat && event.state.is_pressed() {
                 self.camera_force += 180.0f32.to_radians();
+                self.yield_camera_to_user();
             } else if KeyCode::ArrowLeft == *code && !event.repeat && !event.state.is_pressed() {
                 self.camera_force += 180.0f32.to_radians();
             } else if KeyCode::ArrowRight == *code && !event.repeat && !event.state.is_pressed() {
                 self.camera_force -= 180.0f32.to_radians();
+            } else if KeyCode::F1 == *code && !event.repeat && event.state.is_pressed() {
+                self.cycle_debug_view_mode();
+            } else if KeyCode::F2 == *code && !event.repeat && event.state.is_pressed() {
+                self.show_debug_draw = !self.show_debug_draw;
+            } else if KeyCode::F3 == *code && !event.repeat && event.state.is_pressed() {
+                self.export_gpu_profile();
+            } else if KeyCode::F4 == *code && !event.repeat && event.state.is_pressed() {
+                self.export_cpu_trace();
+            } else if KeyCode::KeyC == *code && !event.repeat && event.state.is_pressed() {
+                self.toggle_camera_path();
+            } else if KeyCode::KeyP == *code && !event.repeat && event.state.is_pressed() {
+                self.paused = !self.paused;
+            } else if KeyCode::BracketLeft == *code && !event.repeat && event.state.is_pressed() {
+                self.time_scale = (self.time_scale * 0.5).max(MIN_TIME_SCALE);
+            } else if KeyCode::BracketRight == *code && !event.repeat && event.state.is_pressed() {
+                self.time_scale = (self.time_scale * 2.0).min(MAX_TIME_SCALE);
             }
         };
     }
 
+    fn on_cursor_moved(
+        &mut self, 
+        position: PhysicalPosition<f64>, 
+        _device: &wgpu::Device, 
+        _queue: &wgpu::Queue
+    ) {
+        self.hud.pointer_moved(position.x as f32, position.y as f32);
+    }
+
+    fn on_mouse_input(
+        &mut self, 
+        state: ElementState, 
+        button: MouseButton, 
+        _device: &wgpu::Device, 
+        _queue: &wgpu::Queue
+    ) {
+        if MouseButton::Left == button {
+            self.hud.pointer_pressed(state.is_pressed());
+        }
+    }
+
+    fn on_shader_changed(
+        &mut self, 
+        path: &Path, 
+        device: &wgpu::Device, 
+        _queue: &wgpu::Queue
+    ) {
+        if let Err(err) = self.reload_shaders(path, device) {
+            log::error!("Failed to reload shader {}: {}", path.display(), err);
+        }
+    }
+
+    fn on_device_recreated(
+        &mut self, 
+        device: &wgpu::Device, 
+        queue: &wgpu::Queue
+    ) -> Result<(), AppError> {
+        self.recreate_gpu_resources(device, queue)
+    }
+
+    fn on_surface_format_changed(
+        &mut self, 
+        format: wgpu::TextureFormat, 
+        device: &wgpu::Device, 
+        _queue: &wgpu::Queue
+    ) -> Result<(), AppError> {
+        if self.pipeline_cache.set_color_format(format, device, &self.layouts)? {
+            log::info!("Recreated the graphics pipelines for the surface format {:?}.", format);
+        }
+        Ok(())
+    }
+
+    fn is_dirty(&self) -> bool {
+        // (한국어) 카메라가 움직이거나, 애니메이션과 트윈이 재생되거나, 에셋을 불러오는 동안에는 계속 다시 그립니다.
+        // (English Translation) Keeps redrawing while the camera is moving, animations and tweens are playing or assets are being loaded.
+        let is_animating = !self.paused && (
+            self.world.query::<&AnimationPlayer>().iter().any(|(_, player)| player.is_playing())
+            || self.world.query::<&Tweener>().iter().any(|(_, tweener)| tweener.is_playing())
+            || self.world.query::<&CameraSequencer>().iter().any(|(_, sequencer)| sequencer.is_active())
+        );
+        let is_loading = self.meshes.is_loading() || self.models.is_loading() || self.camera_paths.is_loading();
+        (self.camera_force != 0.0 && !self.paused) || is_animating || is_loading
+    }
+
+    fn on_fixed_update(
+        &mut self, 
+        timer: &GameTimer, 
+        _device: &wgpu::Device, 
+        _queue: &wgpu::Queue
+    ) {
+        // (한국어) 렌더링 시 보간할 수 있도록 갱신 전의 카메라 변환을 저장합니다.
+        // (English Translation) Stores the camera transform before the update so that it can be interpolated when rendering.
+        if let Ok(transform) = self.world.query_one_mut::<&Transform>(self.main_camera) {
+            self.previous_camera_transform = *transform;
+        }
+        self.rotate_main_camera(self.camera_force * timer.fixed_time_step_sec());
+        self.update_camera_sequencer(timer.fixed_time_step_sec());
+    }
+
     fn on_update(
         &mut self, 
-        elapsed_time_sec: f32, 
+        timer: &mut GameTimer, 
+        device: &wgpu::Device, 
+        queue: &wgpu::Queue
+    ) {
+        timer.set_paused(self.paused);
+        timer.set_time_scale(self.time_scale);
+
+        if let Some(gpu_profiler) = self.gpu_profiler.as_mut() {
+            gpu_profiler.begin_frame(timer.delta_time_sec() * 1000.0);
+        }
+
+        // (한국어) 작업 스레드에서 디코딩이 끝난 메쉬들을 업로드하고 엔티티에 추가합니다.
+        // (English Translation) Uploads the meshes decoded on worker threads and adds them to the entities.
+        {
+            let _scope = profiler::scope("ProcessLoadedAssets");
+            if !self.meshes.process_loaded(device, queue).is_empty() {
+                self.attach_loaded_meshes();
+            }
+            if !self.models.process_loaded(device, queue).is_empty() {
+                self.attach_loaded_models();
+            }
+            if !self.camera_paths.process_loaded(device, queue).is_empty() {
+                self.attach_loaded_camera_paths();
+            }
+            self.select_material_variants(device);
+        }
+        {
+            let _scope = profiler::scope("UpdateWaveSurfaces");
+            self.update_wave_surfaces(timer.scaled_delta_time_sec(), device, queue);
+        }
+        {
+            let _scope = profiler::scope("AnimateSkeletons");
+            self.animate_skeletons(timer.scaled_delta_time_sec(), queue);
+        }
+        {
+            let _scope = profiler::scope("UpdateTweens");
+            self.update_tweens(timer.scaled_delta_time_sec(), queue);
+        }
+
+        // (한국어) 화면 오버레이의 버튼이 디버그 도형 표시를 바꿀 수 있으므로 먼저 구성합니다.
+        // (English Translation) The overlay is built first since its buttons can change the display of debug shapes.
+        {
+            let _scope = profiler::scope("BuildHud");
+            self.build_hud(timer);
+        }
+        {
+            let _scope = profiler::scope("UploadHud");
+            self.hud.upload(device, queue);
+        }
+
+        {
+            let _scope = profiler::scope("CollectDebugShapes");
+            self.collect_debug_shapes();
+        }
+        {
+            let _scope = profiler::scope("UploadDebugDraw");
+            self.debug_draw.upload(device, queue);
+        }
+    }
+
+    #[cfg(feature = "egui")]
+    fn on_gui(
+        &mut self, 
+        ctx: &egui::Context, 
+        timer: &GameTimer, 
         _device: &wgpu::Device, 
         queue: &wgpu::Queue
     ) {
-        self.rotate_main_camera(self.camera_force * elapsed_time_sec);
-        self.update_camera_uniform(queue, self.main_camera);
+        // (한국어) 게임 타이머의 통계와 디버그 시각화 설정을 표시합니다.
+        // (English Translation) Displays the game timer statistics and the debug visualization settings.
+        egui::Window::new("Stats")
+            .default_pos((8.0, 96.0))
+            .show(ctx, |ui| {
+                ui.label(format!("FPS: {}", timer.frame_rate()));
+                ui.label(format!("Frame: {:.2} ms", timer.elapsed_time_sec() * 1000.0));
+
+                let frame_time_stats = timer.frame_time_stats();
+                ui.label(format!(
+                    "Min / Max: {:.2} / {:.2} ms", 
+                    frame_time_stats.min_sec * 1000.0, 
+                    frame_time_stats.max_sec * 1000.0
+                ));
+                ui.label(format!(
+                    "p50 / p95 / p99: {:.2} / {:.2} / {:.2} ms", 
+                    frame_time_stats.p50_sec * 1000.0, 
+                    frame_time_stats.p95_sec * 1000.0, 
+                    frame_time_stats.p99_sec * 1000.0
+                ));
+                ui.label(format!(
+                    "Hitches (> {:.0} ms): {}", 
+                    timer.hitch_threshold_sec() * 1000.0, 
+                    timer.hitch_count()
+                ));
+
+                // (한국어) 프레임 시간의 분포를 구간별 막대로 표시합니다.
+                // (English Translation) Displays the distribution of frame times as a bar per bucket.
+                egui::CollapsingHeader::new("Histogram")
+                    .show(ui, |ui| {
+                        let histogram = timer.histogram();
+                        let total = histogram.total().max(1);
+                        for (index, &count) in histogram.counts().iter().enumerate() {
+                            let label = match FrameTimeHistogram::bucket_range_ms(index) {
+                                (lower, Some(upper)) => format!("{:.1}-{:.1} ms", lower, upper), 
+                                (lower, None) => format!("{:.1}+ ms", lower), 
+                            };
+                            ui.add(egui::ProgressBar::new(count as f32 / total as f32)
+                                .text(format!("{}: {}", label, count)));
+                        }
+                    });
+                ui.separator();
+
+                let mut mode = self.debug_view_mode;
+                egui::ComboBox::from_label("View")
+                    .selected_text(format!("{:?}", mode))
+                    .show_ui(ui, |ui| {
+                        let mut next = DebugViewMode::default();
+                        loop {
+                            ui.selectable_value(&mut mode, next, format!("{:?}", next));
+                            next = next.next();
+                            if next == DebugViewMode::default() {
+                                break;
+                            }
+                        }
+                    });
+                if mode != self.debug_view_mode {
+                    self.set_debug_view_mode(mode);
+                }
+
+                ui.checkbox(&mut self.show_debug_draw, "Gizmos");
+
+                ui.separator();
+                ui.label(format!("Fixed update: {:.0} Hz", timer.fixed_update_rate()));
+                ui.checkbox(&mut self.paused, "Paused");
+                ui.add(egui::Slider::new(&mut self.time_scale, MIN_TIME_SCALE..=MAX_TIME_SCALE)
+                    .logarithmic(true)
+                    .text("Time scale"));
+
+                if let Some(gpu_profiler) = self.gpu_profiler.as_ref() {
+                    ui.separator();
+                    ui.label(format!("GPU: {:.2} ms", gpu_profiler.total_time_ms()));
+                    for (name, time) in gpu_profiler.pass_times_ms() {
+                        match time {
+                            Some(time) => ui.label(format!("  {}: {:.2} ms", name, time)), 
+                            None => ui.label(format!("  {}: -", name)), 
+                        };
+                    }
+                    if ui.button("Export CSV").clicked() {
+                        self.export_gpu_profile();
+                    }
+                }
+
+                ui.separator();
+                let mut cpu_profiling = profiler::is_enabled();
+                if ui.checkbox(&mut cpu_profiling, "CPU profiling").changed() {
+                    profiler::set_enabled(cpu_profiling);
+                }
+                if let Some(frame) = profiler::last_frame() {
+                    ui.label(format!("CPU: {:.2} ms ({} scopes)", frame.duration_us / 1000.0, frame.events.len()));
+                }
+                if ui.button("Export trace").clicked() {
+                    self.export_cpu_trace();
+                }
+            });
+
+        // (한국어) 엔티티들의 변환과 색상을 검사하고 편집합니다.
+        // (English Translation) Inspects and edits the transforms and colors of the entities.
+        let mut changed = Vec::new();
+        egui::Window::new("Entities")
+            .default_pos((8.0, 220.0))
+            .default_height(240.0)
+            .show(ctx, |ui| {
+                egui::ScrollArea::vertical().show(ui, |ui| {
+                    for (entity, (transform, color)) in self.world.query_mut::<(&mut Transform, Option<&mut Color>)>() {
+                        let title = if entity == self.main_camera {
+                            format!("Entity {} (Camera)", entity.id())
+                        } else {
+                            format!("Entity {}", entity.id())
+                        };
+
+                        egui::CollapsingHeader::new(title)
+                            .id_source(entity)
+                            .show(ui, |ui| {
+                                if edit_transform(ui, transform) | color.is_some_and(|color| edit_color(ui, color)) {
+                                    changed.push(entity);
+                                }
+                            });
+                    }
+                });
+            });
+
+        for entity in changed {
+            if entity == self.main_camera {
+                // (한국어) 편집된 카메라가 이전 변환과 보간되지 않도록 합니다. 유니폼 버퍼는 그리기 전에 갱신됩니다.
+                // (English Translation) Prevents the edited camera from being interpolated with its previous transform. The uniform buffer is updated before drawing.
+                if let Ok(transform) = self.world.query_one_mut::<&Transform>(entity) {
+                    self.previous_camera_transform = *transform;
+                }
+            } else {
+                self.update_entity_uniform(queue, entity);
+            }
+        }
     }
 
     fn on_draw(
         &self, 
         render_target_view: &wgpu::TextureView, 
         depth_stencil_view: &wgpu::TextureView, 
+        interpolation_alpha: f32, 
         device: &wgpu::Device, 
         queue: &wgpu::Queue
     ) {
-        let weighted_blended_oit = self.weighted_blended_oit.as_ref().unwrap();
+        let Some(weighted_blended_oit) = self.weighted_blended_oit.as_ref() else {
+            return;
+        };
+
+        // (한국어) 이전 고정 갱신과 현재 고정 갱신 사이에서 보간된 카메라의 변환으로 유니폼 버퍼를 갱신합니다.
+        // (English Translation) Updates the uniform buffer with the camera transform interpolated between the previous and the current fixed update.
+        let upload_scope = profiler::scope("UploadCameraUniform");
+        if let Ok(mut query) = self.world.query_one::<(&Projection, &Transform, &CameraUniform)>(self.main_camera) {
+            if let Some((projection, transform, uniform)) = query.get() {
+                let transform = self.previous_camera_transform.lerp(transform, interpolation_alpha);
+                uniform.update(
+                    queue, 
+                    CameraUniformLayout {
+                        proj_view: projection.as_ref().mul_mat4(&transform.view_matrix()), 
+                        position: transform.get_translation(), 
+                        ..Default::default()
+                    }
+                );
+            }
+        }
+        drop(upload_scope);
 
-        let mut query = self.world.query_one::<&CameraUniform>(self.main_camera).unwrap();
-        let main_camera = query.get().unwrap();
+        let Ok(mut query) = self.world.query_one::<&CameraUniform>(self.main_camera) else {
+            return;
+        };
+        let Some(main_camera) = query.get() else {
+            return;
+        };
 
         let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
 
-        let pipeline = self.graphics_pipelines.get(&TypeId::of::<ColoredPipeline>())
-            .expect("ColoredPipeline not found!");
-        let mut query = self.world.query::<(&ModelMesh, &EntityUniform)>().with::<&ColoredPipeline>();
-        let mut model_mesh_map: HashMap<ModelMesh, Vec<EntityUniform>> = HashMap::new();
-        for (_id, (model_mesh, uniform)) in query.iter() {
-            model_mesh_map.entry(model_mesh.clone())
-                .and_modify(|vec| vec.push(uniform.clone()))
-                .or_insert(vec![uniform.clone()]);
+        // (한국어) 디버그 시각화 중에는 모든 엔티티를 하나의 패스에서 그립니다.
+        // (English Translation) During debug visualization, all entities are drawn in a single pass.
+        let is_debug_view = self.debug_view_mode != DebugViewMode::Shaded;
+        let collect_scope = profiler::scope("CollectDrawBatches");
+        let opaque_batches = match is_debug_view {
+            true => self.collect_debug_draw_batches(), 
+            false => self.collect_draw_batches(&[BlendMode::Opaque, BlendMode::Alpha, BlendMode::Additive]), 
+        };
+        let transparent_batches = self.collect_draw_batches(&[BlendMode::WeightedBlendedOIT]);
+        let unindexed = self.barycentric_wireframe && self.debug_view_mode == DebugViewMode::Wireframe;
+        if unindexed {
+            for (_, model_mesh, _) in opaque_batches.iter() {
+                model_mesh.prepare_unindexed(device, queue);
+            }
         }
+        drop(collect_scope);
 
+        let encode_scope = profiler::scope("EncodeCommands");
         {
             let mut rpass = encoder.begin_render_pass(
                 &wgpu::RenderPassDescriptor {
@@ -413,13 +2024,13 @@ impl GameScene for SampleScene {
                             view: render_target_view, 
                             resolve_target: None, 
                             ops: wgpu::Operations {
-                                load: wgpu::LoadOp::Clear(wgpu::Color::WHITE), 
+                                load: wgpu::LoadOp::Clear(self.debug_view_mode.clear_color()), 
                                 store: wgpu::StoreOp::Store, 
                             }, 
                         }), 
                     ], 
                     depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment { 
-                        view: &depth_stencil_view , 
+                        view: depth_stencil_view, 
                         depth_ops: Some(wgpu::Operations {
                             load: wgpu::LoadOp::Clear(1.0), 
                             store: wgpu::StoreOp::Store, 
@@ -427,30 +2038,54 @@ impl GameScene for SampleScene {
                         stencil_ops: None, 
                     }), 
                     occlusion_query_set: None, 
+                    timestamp_writes: self.gpu_profiler.as_ref()
+                        .and_then(|gpu_profiler| gpu_profiler.timestamp_writes(GPU_PASS_OPAQUE)), 
+                }, 
+            );
+
+            main_camera.bind(&mut rpass, 0);
+            self.draw_batches(&mut rpass, &opaque_batches, unindexed);
+        }
+
+        let debug_draw_pipeline = self.pipeline_cache.pipeline(self.debug_draw_material)
+            .filter(|_| !self.debug_draw.is_empty());
+        if let Some(pipeline) = debug_draw_pipeline {
+            let mut rpass = encoder.begin_render_pass(
+                &wgpu::RenderPassDescriptor {
+                    label: Some("RenderPass(DebugDraw)"), 
+                    color_attachments: &[
+                        Some(wgpu::RenderPassColorAttachment {
+                            view: render_target_view, 
+                            resolve_target: None, 
+                            ops: wgpu::Operations {
+                                load: wgpu::LoadOp::Load, 
+                                store: wgpu::StoreOp::Store, 
+                            }, 
+                        }), 
+                    ], 
+                    depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment { 
+                        view: depth_stencil_view, 
+                        depth_ops: Some(wgpu::Operations {
+                            load: wgpu::LoadOp::Load, 
+                            store: wgpu::StoreOp::Store, 
+                        }), 
+                        stencil_ops: None, 
+                    }), 
+                    occlusion_query_set: None, 
                     timestamp_writes: None, 
                 }, 
             );
 
-            pipeline.bind(&mut rpass);
+            rpass.set_pipeline(pipeline);
             main_camera.bind(&mut rpass, 0);
-            for (model_mesh, entities) in model_mesh_map.iter() {
-                model_mesh.bind(&mut rpass, 0);
-                for entity in entities.iter() {
-                    entity.bind(&mut rpass, 1);
-                    model_mesh.draw(&mut rpass, 0..1);
-                }
-            }
+            self.debug_draw.draw(&mut rpass);
         }
 
-        
-        let pipeline = self.graphics_pipelines.get(&TypeId::of::<TransparentPipeline>())
-            .expect("ColoredPipeline not found!");
-        let mut query = self.world.query::<(&ModelMesh, &EntityUniform)>().with::<&TransparentPipeline>();
-        let mut model_mesh_map: HashMap<ModelMesh, Vec<EntityUniform>> = HashMap::new();
-        for (_id, (model_mesh, uniform)) in query.iter() {
-            model_mesh_map.entry(model_mesh.clone())
-                .and_modify(|vec| vec.push(uniform.clone()))
-                .or_insert(vec![uniform.clone()]);
+        if is_debug_view {
+            self.draw_hud(&mut encoder, render_target_view, depth_stencil_view);
+            drop(encode_scope);
+            self.submit_commands(encoder, queue);
+            return;
         }
 
         {
@@ -463,7 +2098,7 @@ impl GameScene for SampleScene {
                     ],
                     depth_stencil_attachment: Some(
                         wgpu::RenderPassDepthStencilAttachment {
-                            view: &depth_stencil_view, 
+                            view: depth_stencil_view, 
                             depth_ops: Some(wgpu::Operations {
                                 load: wgpu::LoadOp::Load, 
                                 store: wgpu::StoreOp::Store,
@@ -471,26 +2106,24 @@ impl GameScene for SampleScene {
                             stencil_ops: None, 
                         }, 
                     ), 
-                    timestamp_writes: None, 
+                    timestamp_writes: self.gpu_profiler.as_ref()
+                        .and_then(|gpu_profiler| gpu_profiler.timestamp_writes(GPU_PASS_TRANSPARENT)), 
                     occlusion_query_set: None, 
                 }
             );
 
-            pipeline.bind(&mut rpass);
             main_camera.bind(&mut rpass, 0);
-            for (model_mesh, entities) in model_mesh_map.iter() {
-                model_mesh.bind(&mut rpass, 0);
-                for entity in entities.iter() {
-                    entity.bind(&mut rpass, 1);
-                    model_mesh.draw(&mut rpass, 0..1);
-                }
-            }
+            self.draw_batches(&mut rpass, &transparent_batches, false);
         }
 
-        let pipeline = self.graphics_pipelines.get(&TypeId::of::<CompositePipeline>())
-            .expect("ColoredPipeline not found!");
+        // (한국어) 합성 파이프라인이 없다면 반투명 물체들의 합성을 건너뛰고 나머지를 그립니다.
+        // (English Translation) If there is no composite pipeline, skips compositing the translucent objects and draws the rest.
+        let pipeline = self.pipeline_cache.pipeline(self.composite_material);
+        if pipeline.is_none() {
+            log::warn!("Composite pipeline not found, skipping the composite pass.");
+        }
 
-        {
+        if let Some(pipeline) = pipeline {
             let mut rpass = encoder.begin_render_pass(
                 &wgpu::RenderPassDescriptor {
                     label: Some("RenderPass(Composite)"), 
@@ -514,18 +2147,94 @@ impl GameScene for SampleScene {
                             stencil_ops: None 
                         }, 
                     ), 
-                    timestamp_writes: None,
+                    timestamp_writes: self.gpu_profiler.as_ref()
+                        .and_then(|gpu_profiler| gpu_profiler.timestamp_writes(GPU_PASS_COMPOSITE)), 
                     occlusion_query_set: None, 
                 }
             );
 
-            pipeline.bind(&mut rpass);
+            rpass.set_pipeline(pipeline);
             weighted_blended_oit.bind(&mut rpass, 0);
             rpass.draw(0..4, 0..1);
         }
 
-        // (한국어) 명령어 대기열에 명령어 목록을 제출합니다. </br>
-        // (English Translation) Submit a list of commands to the command queue. </br>
-        queue.submit(Some(encoder.finish()));
+        self.draw_hud(&mut encoder, render_target_view, depth_stencil_view);
+        drop(encode_scope);
+        self.submit_commands(encoder, queue);
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 변환의 위치와 회전(오일러 각, 도 단위)을 편집합니다. 값이 변경된 경우 `true`를 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Edits the translation and rotation (Euler angles in degrees) of the transform. Returns `true` if a value has changed. </br>
+/// 
+#[cfg(feature = "egui")]
+fn edit_transform(ui: &mut egui::Ui, transform: &mut Transform) -> bool {
+    let mut changed = false;
+
+    let mut translation = transform.get_translation();
+    ui.horizontal(|ui| {
+        ui.label("Translation");
+        changed |= ui.add(egui::DragValue::new(&mut translation.x).speed(0.01).prefix("x: ")).changed();
+        changed |= ui.add(egui::DragValue::new(&mut translation.y).speed(0.01).prefix("y: ")).changed();
+        changed |= ui.add(egui::DragValue::new(&mut translation.z).speed(0.01).prefix("z: ")).changed();
+    });
+
+    let (x, y, z) = transform.get_rotation().to_euler(glam::EulerRot::YXZ);
+    let (mut x, mut y, mut z) = (x.to_degrees(), y.to_degrees(), z.to_degrees());
+    let mut rotated = false;
+    ui.horizontal(|ui| {
+        ui.label("Rotation");
+        rotated |= ui.add(egui::DragValue::new(&mut y).speed(0.5).prefix("x: ").suffix("°")).changed();
+        rotated |= ui.add(egui::DragValue::new(&mut x).speed(0.5).prefix("y: ").suffix("°")).changed();
+        rotated |= ui.add(egui::DragValue::new(&mut z).speed(0.5).prefix("z: ").suffix("°")).changed();
+    });
+
+    if changed {
+        transform.set_translation(translation);
+    }
+
+    if rotated {
+        transform.set_rotation(glam::Quat::from_euler(
+            glam::EulerRot::YXZ, 
+            x.to_radians(), 
+            y.to_radians(), 
+            z.to_radians()
+        ));
     }
+
+    changed | rotated
+}
+
+
+
+/// #### 한국어 </br>
+/// 색상을 편집합니다. 알파 값의 유무는 유지됩니다. 값이 변경된 경우 `true`를 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Edits the color. Whether it has an alpha value is preserved. Returns `true` if a value has changed. </br>
+/// 
+#[cfg(feature = "egui")]
+fn edit_color(ui: &mut egui::Ui, color: &mut Color) -> bool {
+    ui.horizontal(|ui| {
+        ui.label("Color");
+        match color {
+            Color::Rgb { red, green, blue } => {
+                let mut rgb = [*red, *green, *blue];
+                let changed = ui.color_edit_button_rgb(&mut rgb).changed();
+                [*red, *green, *blue] = rgb;
+                changed
+            },
+            Color::Rgba { red, green, blue, alpha } => {
+                let mut rgba = [*red, *green, *blue, *alpha];
+                let changed = ui.color_edit_button_rgba_unmultiplied(&mut rgba).changed();
+                [*red, *green, *blue, *alpha] = rgba;
+                changed
+            },
+        }
+    }).inner
 }
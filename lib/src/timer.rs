@@ -1,44 +1,304 @@
+use std::sync::Arc;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::time::Duration;
 use std::time::Instant;
 
 
 
+/// #### 한국어 </br>
+/// 고정 시간 간격의 기본 갱신 빈도(Hz)입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The default update rate (Hz) of the fixed time step. </br>
+/// 
+pub const DEFAULT_FIXED_UPDATE_RATE: f64 = 60.0;
+
+/// #### 한국어 </br>
+/// 한 프레임 동안 실행할 수 있는 고정 갱신의 최대 횟수입니다. </br>
+/// 프레임이 오래 걸렸을 때 밀린 고정 갱신이 계속 쌓이는 것을 막습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The maximum number of fixed updates that can run during a frame. </br>
+/// Prevents delayed fixed updates from piling up when a frame takes a long time. </br>
+/// 
+pub const DEFAULT_MAX_FIXED_STEPS: u32 = 8;
+
+/// #### 한국어 </br>
+/// 프레임 간격이 이 값(초)보다 길면 끊김(hitch)으로 간주합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A frame interval longer than this value (in seconds) is regarded as a hitch. </br>
+/// 
+pub const DEFAULT_HITCH_THRESHOLD_SEC: f64 = 0.05;
+
+/// #### 한국어 </br>
+/// 프레임 시간 히스토그램 구간들의 경계(밀리초)입니다. 마지막 구간은 상한이 없습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The bounds (in milliseconds) of the frame time histogram buckets. The last bucket has no upper bound. </br>
+/// 
+pub const HISTOGRAM_BUCKET_BOUNDS_MS: [f64; 8] = [4.0, 8.0, 12.0, 16.7, 20.0, 33.3, 50.0, 100.0];
+
+/// #### 한국어 </br>
+/// 프레임 시간 히스토그램 구간의 수입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The number of buckets of the frame time histogram. </br>
+/// 
+pub const NUM_HISTOGRAM_BUCKETS: usize = HISTOGRAM_BUCKET_BOUNDS_MS.len() + 1;
+
+/// #### 한국어 </br>
+/// 누적된 시간이 고정 시간 간격에 이 비율만큼 모자라도 고정 갱신 하나로 간주합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Accumulated time short of the fixed time step by up to this ratio still counts as one fixed update. </br>
+/// 
+const FIXED_STEP_TOLERANCE: f64 = 1.0e-6;
+
+
+
+/// #### 한국어 </br>
+/// 게임 타이머가 현재 시각을 얻는 시계입니다. </br>
+/// 테스트에서는 `ManualClock`을 주입하여 시간의 흐름을 직접 제어할 수 있습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The clock from which the game timer gets the current time. </br>
+/// In tests, a `ManualClock` can be injected to control the passage of time directly. </br>
+/// 
+pub trait Clock {
+    fn now(&self) -> Instant;
+}
+
+/// #### 한국어 </br>
+/// `Instant::now()`를 사용하는 시스템 시계입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The system clock using `Instant::now()`. </br>
+/// 
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    #[inline]
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// #### 한국어 </br>
+/// `advance` 함수를 호출할 때만 시간이 흐르는 시계입니다. </br>
+/// 복제된 시계들은 같은 시각을 공유하므로, 타이머에 주입한 후에도 바깥에서 시간을 진행시킬 수 있습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A clock whose time passes only when the `advance` function is called. </br>
+/// Cloned clocks share the same time, so the time can be advanced from outside even after it is injected into a timer. </br>
+/// 
+#[derive(Debug, Clone)]
+pub struct ManualClock {
+    origin: Instant, 
+    elapsed_nanos: Arc<AtomicU64>, 
+}
+
+#[allow(dead_code)]
+impl ManualClock {
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            origin: Instant::now(), 
+            elapsed_nanos: Arc::new(AtomicU64::new(0)), 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 시계의 시각을 `duration`만큼 진행시킵니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Advances the time of the clock by `duration`. </br>
+    /// 
+    #[inline]
+    pub fn advance(&self, duration: Duration) {
+        let nanos = u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX);
+        self.elapsed_nanos.fetch_add(nanos, Ordering::Relaxed);
+    }
+
+    #[inline]
+    pub fn advance_sec(&self, sec: f64) {
+        self.advance(Duration::from_secs_f64(sec.max(0.0)));
+    }
+}
+
+impl Default for ManualClock {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for ManualClock {
+    #[inline]
+    fn now(&self) -> Instant {
+        self.origin + Duration::from_nanos(self.elapsed_nanos.load(Ordering::Relaxed))
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 최근 프레임 간격들의 통계(초 단위)입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Statistics (in seconds) of recent frame intervals. </br>
+/// 
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct FrameTimeStats {
+    pub min_sec: f32, 
+    pub max_sec: f32, 
+    pub average_sec: f32, 
+    pub p50_sec: f32, 
+    pub p95_sec: f32, 
+    pub p99_sec: f32, 
+}
+
+
+
+/// #### 한국어 </br>
+/// 프레임 시간의 분포를 `HISTOGRAM_BUCKET_BOUNDS_MS`의 구간별로 셉니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Counts the distribution of frame times for each bucket of `HISTOGRAM_BUCKET_BOUNDS_MS`. </br>
+/// 
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct FrameTimeHistogram {
+    counts: [u64; NUM_HISTOGRAM_BUCKETS], 
+}
+
+#[allow(dead_code)]
+impl FrameTimeHistogram {
+    #[inline]
+    fn record(&mut self, frame_time_sec: f64) {
+        let frame_time_ms = frame_time_sec * 1000.0;
+        let index = HISTOGRAM_BUCKET_BOUNDS_MS
+            .iter()
+            .position(|&bound| frame_time_ms < bound)
+            .unwrap_or(NUM_HISTOGRAM_BUCKETS - 1);
+        self.counts[index] += 1;
+    }
+
+    #[inline]
+    pub fn counts(&self) -> &[u64; NUM_HISTOGRAM_BUCKETS] {
+        &self.counts
+    }
+
+    #[inline]
+    pub fn total(&self) -> u64 {
+        self.counts.iter().sum()
+    }
+
+    /// #### 한국어 </br>
+    /// `index`번째 구간의 범위(밀리초)를 반환합니다. 마지막 구간의 상한은 `None`입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the range (in milliseconds) of the `index`-th bucket. The upper bound of the last bucket is `None`. </br>
+    /// 
+    pub fn bucket_range_ms(index: usize) -> (f64, Option<f64>) {
+        let lower = match index {
+            0 => 0.0, 
+            _ => HISTOGRAM_BUCKET_BOUNDS_MS[(index - 1).min(HISTOGRAM_BUCKET_BOUNDS_MS.len() - 1)], 
+        };
+        (lower, HISTOGRAM_BUCKET_BOUNDS_MS.get(index).copied())
+    }
+}
+
+
+
 /// #### 한국어 </br>
 /// `tick`함수를 호출할 때 까지의 걸린 시간을 측정합니다. </br>
+/// 실제 프레임 간격과 함께, 시간 배율과 일시 정지가 적용된 시간을 고정 시간 간격으로 나누어 줍니다. </br>
 /// 
 /// #### English (Translation) </br>
 /// Measures the time it takes to call the `tick` function. </br>
+/// Along with the real frame delta, it divides the time with time scale and pause applied into fixed time steps. </br>
 /// 
 #[derive(Debug, Clone, Copy)]
-pub struct GameTimer<const NUM_SAMPLE: usize = 50> {
+pub struct GameTimer<const NUM_SAMPLE: usize = 50, C: Clock = SystemClock> {
+    clock: C, 
     previous_timepoint: Instant, 
     current_timepoint: Instant, 
 
     frame_times: [f64; NUM_SAMPLE],
     cnt_frame_times: usize, 
 
+    delta_time_sec: f64, 
     elapsed_time_sec: f64,
     fps_elapsed_time_sec: f64, 
     frame_per_seconds: u64, 
     frame_rate: u64, 
+
+    time_scale: f64, 
+    paused: bool, 
+
+    fixed_time_step_sec: f64, 
+    max_fixed_steps: u32, 
+    accumulator_sec: f64, 
+
+    hitch_threshold_sec: f64, 
+    hitch_count: u64, 
+    histogram: FrameTimeHistogram, 
 }
 
 #[allow(dead_code)]
-impl<const NUM_SAMPLES: usize> GameTimer<NUM_SAMPLES> {
+impl<const NUM_SAMPLES: usize> GameTimer<NUM_SAMPLES, SystemClock> {
     #[inline]
     pub fn new() -> Self {
-        let timepoint = Instant::now();
+        Self::with_clock(SystemClock)
+    }
+}
+
+impl<const NUM_SAMPLES: usize> Default for GameTimer<NUM_SAMPLES, SystemClock> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[allow(dead_code)]
+impl<const NUM_SAMPLES: usize, C: Clock> GameTimer<NUM_SAMPLES, C> {
+    /// #### 한국어 </br>
+    /// 주어진 시계로 시간을 측정하는 게임 타이머를 생성합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates a game timer that measures time with the given clock. </br>
+    /// 
+    pub fn with_clock(clock: C) -> Self {
+        let timepoint = clock.now();
         Self {
+            clock, 
             previous_timepoint: timepoint, 
             current_timepoint: timepoint, 
             frame_times: [0.0; NUM_SAMPLES], 
             cnt_frame_times: 0, 
+            delta_time_sec: 0.0, 
             elapsed_time_sec: 0.0, 
             fps_elapsed_time_sec: 0.0, 
             frame_per_seconds: 0, 
             frame_rate: 0,
+            time_scale: 1.0, 
+            paused: false, 
+            fixed_time_step_sec: 1.0 / DEFAULT_FIXED_UPDATE_RATE, 
+            max_fixed_steps: DEFAULT_MAX_FIXED_STEPS, 
+            accumulator_sec: 0.0, 
+            hitch_threshold_sec: DEFAULT_HITCH_THRESHOLD_SEC, 
+            hitch_count: 0, 
+            histogram: FrameTimeHistogram::default(), 
         }
     }
 
+    #[inline]
+    pub fn clock(&self) -> &C {
+        &self.clock
+    }
+
     /// #### 한국어 </br>
     /// 이전 `tick` 함수를 호출한 시점에서 현재 `tick`함수를 호출한 시점까지 걸린 시간을 측정합니다. </br>
     /// 
@@ -46,18 +306,31 @@ impl<const NUM_SAMPLES: usize> GameTimer<NUM_SAMPLES> {
     /// Measures the time taken from calling the previous `tick` function to calling the current `tick` function. </br>
     /// 
     pub fn tick(&mut self) {
-        self.current_timepoint = Instant::now();
+        self.current_timepoint = self.clock.now();
         let elapsed_time_sec = self.current_timepoint
             .saturating_duration_since(self.previous_timepoint)
             .as_secs_f64();
 
         self.previous_timepoint = self.current_timepoint;
+        self.delta_time_sec = elapsed_time_sec;
+
+        // (한국어) 시간 배율과 일시 정지가 적용된 시간을 누적합니다. 밀린 시간은 최대 고정 갱신 횟수까지만 유지합니다.
+        // (English Translation) Accumulates the time with time scale and pause applied. Delayed time is kept only up to the maximum number of fixed updates.
+        self.accumulator_sec += self.scaled_delta_time_sec_f64();
+        self.accumulator_sec = self.accumulator_sec
+            .min(self.fixed_time_step_sec * self.max_fixed_steps as f64);
 
-        if (self.elapsed_time_sec - elapsed_time_sec).abs() < 1.0 {
+        // (한국어) 오래 걸린 프레임도 버리지 않고 기록하여, 통계와 끊김 횟수에 반영합니다.
+        // (English Translation) Long frames are recorded instead of being dropped, so that they are reflected in the statistics and the hitch count.
+        if NUM_SAMPLES > 0 {
             self.frame_times.copy_within(0..(NUM_SAMPLES - 1), 1);
             self.frame_times[0] = elapsed_time_sec;
             self.cnt_frame_times = (self.cnt_frame_times + 1).min(NUM_SAMPLES);
         }
+        self.histogram.record(elapsed_time_sec);
+        if elapsed_time_sec > self.hitch_threshold_sec {
+            self.hitch_count += 1;
+        }
 
         self.frame_per_seconds += 1;
         self.fps_elapsed_time_sec += elapsed_time_sec;
@@ -77,13 +350,407 @@ impl<const NUM_SAMPLES: usize> GameTimer<NUM_SAMPLES> {
         }
     }
 
+    /// #### 한국어 </br>
+    /// 마지막 `tick` 이후 흐른 시간과 누적된 시간을 버립니다. </br>
+    /// 프레임을 갱신하지 않고 쉬었던 시간이 시뮬레이션에 반영되지 않도록 할 때 사용합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Discards the time passed since the last `tick` and the accumulated time. </br>
+    /// Used so that the time spent idle without updating frames is not reflected in the simulation. </br>
+    /// 
+    pub fn reset(&mut self) {
+        self.previous_timepoint = self.clock.now();
+        self.accumulator_sec = 0.0;
+    }
+
+    /// #### 한국어 </br>
+    /// 기록된 프레임 간격들과 끊김 횟수, 히스토그램을 모두 지웁니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Clears all recorded frame intervals, the hitch count, and the histogram. </br>
+    /// 
+    pub fn reset_statistics(&mut self) {
+        self.cnt_frame_times = 0;
+        self.elapsed_time_sec = 0.0;
+        self.hitch_count = 0;
+        self.histogram = FrameTimeHistogram::default();
+    }
+
+    /// #### 한국어 </br>
+    /// 누적된 시간에서 고정 시간 간격 하나를 소비합니다. 소비할 시간이 남아있지 않다면 `false`를 반환합니다. </br>
+    /// `tick` 이후 `false`를 반환할 때 까지 반복하여 호출하고, 매번 고정 갱신을 한 번씩 실행합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Consumes one fixed time step from the accumulated time. Returns `false` if there is no time left to consume. </br>
+    /// Call it repeatedly after `tick` until it returns `false`, running one fixed update each time. </br>
+    /// 
+    pub fn consume_fixed_step(&mut self) -> bool {
+        // (한국어) 반복된 뺄셈의 반올림 오차 때문에 마지막 고정 갱신을 놓치지 않도록 약간의 여유를 둡니다.
+        // (English Translation) Allows a small margin so that the last fixed update is not missed due to rounding errors of repeated subtraction.
+        if self.accumulator_sec < self.fixed_time_step_sec * (1.0 - FIXED_STEP_TOLERANCE) {
+            return false;
+        }
+        self.accumulator_sec = (self.accumulator_sec - self.fixed_time_step_sec).max(0.0);
+        true
+    }
+
+    /// #### 한국어 </br>
+    /// 이전 고정 갱신과 다음 고정 갱신 사이의 보간 비율(0.0 ~ 1.0)을 반환합니다. </br>
+    /// 렌더링 시 이전 상태와 현재 상태를 이 비율로 보간합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the interpolation ratio (0.0 to 1.0) between the previous and the next fixed update. </br>
+    /// When rendering, the previous and current states are interpolated with this ratio. </br>
+    /// 
+    #[inline]
+    pub fn interpolation_alpha(&self) -> f32 {
+        (self.accumulator_sec / self.fixed_time_step_sec).clamp(0.0, 1.0) as f32
+    }
+
+    /// #### 한국어 </br>
+    /// 이전 프레임과 현재 프레임 사이의 실제 시간 간격을 반환합니다. 시간 배율과 일시 정지가 적용되지 않습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the real time interval between the previous and the current frame. Time scale and pause are not applied. </br>
+    /// 
+    #[inline]
+    pub fn delta_time_sec(&self) -> f32 {
+        self.delta_time_sec as f32
+    }
+
+    /// #### 한국어 </br>
+    /// 시간 배율과 일시 정지가 적용된 프레임 간격을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the frame interval with time scale and pause applied. </br>
+    /// 
+    #[inline]
+    pub fn scaled_delta_time_sec(&self) -> f32 {
+        self.scaled_delta_time_sec_f64() as f32
+    }
+
+    #[inline]
+    fn scaled_delta_time_sec_f64(&self) -> f64 {
+        match self.paused {
+            true => 0.0, 
+            false => self.delta_time_sec * self.time_scale, 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 최근 프레임 간격들의 이동 평균을 반환합니다. 화면에 표시하는 용도로 사용합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the moving average of recent frame intervals. Used for display purposes. </br>
+    /// 
     #[inline]
     pub fn elapsed_time_sec(&self) -> f32 {
         self.elapsed_time_sec as f32
     }
 
+    /// #### 한국어 </br>
+    /// 최근 프레임 간격들의 최소, 최대, 평균과 백분위수(p50/p95/p99)를 계산합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Computes the min, max, average and percentiles (p50/p95/p99) of recent frame intervals. </br>
+    /// 
+    pub fn frame_time_stats(&self) -> FrameTimeStats {
+        let mut samples = self.frame_times;
+        let samples = &mut samples[..self.cnt_frame_times];
+        if samples.is_empty() {
+            return FrameTimeStats::default();
+        }
+        samples.sort_unstable_by(|a, b| a.total_cmp(b));
+
+        FrameTimeStats {
+            min_sec: samples[0] as f32, 
+            max_sec: samples[samples.len() - 1] as f32, 
+            average_sec: self.elapsed_time_sec as f32, 
+            p50_sec: nearest_rank(samples, 50.0) as f32, 
+            p95_sec: nearest_rank(samples, 95.0) as f32, 
+            p99_sec: nearest_rank(samples, 99.0) as f32, 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 최근 프레임 간격들 중 `percentile`(0 ~ 100) 백분위수를 반환합니다. 기록이 없다면 0을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the `percentile` (0 to 100) of recent frame intervals. Returns zero if there are no records. </br>
+    /// 
+    pub fn frame_time_percentile_sec(&self, percentile: f32) -> f32 {
+        let mut samples = self.frame_times;
+        let samples = &mut samples[..self.cnt_frame_times];
+        samples.sort_unstable_by(|a, b| a.total_cmp(b));
+        nearest_rank(samples, percentile as f64) as f32
+    }
+
+    #[inline]
+    pub fn hitch_threshold_sec(&self) -> f32 {
+        self.hitch_threshold_sec as f32
+    }
+
+    /// #### 한국어 </br>
+    /// 끊김으로 간주할 프레임 간격(초)을 설정합니다. 음수는 0으로 설정됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the frame interval (in seconds) regarded as a hitch. Negative values are set to zero. </br>
+    /// 
+    #[inline]
+    pub fn set_hitch_threshold_sec(&mut self, threshold_sec: f32) {
+        self.hitch_threshold_sec = threshold_sec.max(0.0) as f64;
+    }
+
+    /// #### 한국어 </br>
+    /// 타이머가 생성되거나 통계가 지워진 이후 끊김이 발생한 횟수를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the number of hitches since the timer was created or the statistics were cleared. </br>
+    /// 
+    #[inline]
+    pub fn hitch_count(&self) -> u64 {
+        self.hitch_count
+    }
+
+    #[inline]
+    pub fn histogram(&self) -> &FrameTimeHistogram {
+        &self.histogram
+    }
+
+    #[inline]
+    pub fn fixed_time_step_sec(&self) -> f32 {
+        self.fixed_time_step_sec as f32
+    }
+
+    #[inline]
+    pub fn fixed_update_rate(&self) -> f64 {
+        1.0 / self.fixed_time_step_sec
+    }
+
+    /// #### 한국어 </br>
+    /// 고정 갱신의 빈도(Hz)를 설정합니다. 1Hz 보다 작은 값은 1Hz로 설정됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the rate (Hz) of the fixed updates. Values less than 1Hz are set to 1Hz. </br>
+    /// 
+    #[inline]
+    pub fn set_fixed_update_rate(&mut self, rate: f64) {
+        self.fixed_time_step_sec = 1.0 / rate.max(1.0);
+    }
+
+    #[inline]
+    pub fn max_fixed_steps(&self) -> u32 {
+        self.max_fixed_steps
+    }
+
+    #[inline]
+    pub fn set_max_fixed_steps(&mut self, max_fixed_steps: u32) {
+        self.max_fixed_steps = max_fixed_steps.max(1);
+    }
+
+    #[inline]
+    pub fn time_scale(&self) -> f32 {
+        self.time_scale as f32
+    }
+
+    /// #### 한국어 </br>
+    /// 시간 배율을 설정합니다. 음수는 0으로 설정됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the time scale. Negative values are set to zero. </br>
+    /// 
+    #[inline]
+    pub fn set_time_scale(&mut self, time_scale: f32) {
+        self.time_scale = time_scale.max(0.0) as f64;
+    }
+
+    #[inline]
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    #[inline]
+    pub fn set_paused(&mut self, paused: bool) {
+        self.paused = paused;
+    }
+
     #[inline]
     pub fn frame_rate(&self) -> u32 {
         self.frame_rate as u32
     }
 }
+
+
+
+/// #### 한국어 </br>
+/// 정렬된 표본들에서 `percentile`(0 ~ 100) 백분위수를 최근접 순위(nearest-rank) 방식으로 구합니다. 표본이 없다면 0을 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Finds the `percentile` (0 to 100) of sorted samples with the nearest-rank method. Returns zero if there are no samples. </br>
+/// 
+fn nearest_rank(sorted_samples: &[f64], percentile: f64) -> f64 {
+    if sorted_samples.is_empty() {
+        return 0.0;
+    }
+    let rank = (percentile.clamp(0.0, 100.0) / 100.0 * sorted_samples.len() as f64).ceil() as usize;
+    sorted_samples[rank.clamp(1, sorted_samples.len()) - 1]
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type TestTimer = GameTimer<10, ManualClock>;
+
+    fn assert_near(actual: f32, expected: f32) {
+        assert!((actual - expected).abs() < 1.0e-5, "expected {}, found {}", expected, actual);
+    }
+
+    fn timer_with_frames(frame_times_sec: &[f64]) -> TestTimer {
+        let mut timer = TestTimer::with_clock(ManualClock::new());
+        for &frame_time_sec in frame_times_sec {
+            timer.clock().advance_sec(frame_time_sec);
+            timer.tick();
+        }
+        timer
+    }
+
+    #[test]
+    fn tick_measures_delta_with_pause_and_time_scale() {
+        let mut timer = timer_with_frames(&[0.02]);
+        assert_near(timer.delta_time_sec(), 0.02);
+        assert_near(timer.scaled_delta_time_sec(), 0.02);
+
+        timer.set_time_scale(0.5);
+        timer.clock().advance_sec(0.04);
+        timer.tick();
+        assert_near(timer.delta_time_sec(), 0.04);
+        assert_near(timer.scaled_delta_time_sec(), 0.02);
+
+        timer.set_paused(true);
+        timer.clock().advance_sec(0.01);
+        timer.tick();
+        assert_near(timer.delta_time_sec(), 0.01);
+        assert_near(timer.scaled_delta_time_sec(), 0.0);
+
+        timer.set_time_scale(-1.0);
+        assert_near(timer.time_scale(), 0.0);
+    }
+
+    #[test]
+    fn reset_discards_idle_time() {
+        let mut timer = timer_with_frames(&[0.01]);
+        timer.clock().advance_sec(5.0);
+        timer.reset();
+        timer.clock().advance_sec(0.01);
+        timer.tick();
+        assert_near(timer.delta_time_sec(), 0.01);
+        assert_near(timer.interpolation_alpha(), 0.6);
+    }
+
+    #[test]
+    fn hitches_are_counted_above_threshold() {
+        let mut timer = timer_with_frames(&[0.016, 0.051, 0.05, 0.2]);
+        assert_eq!(timer.hitch_count(), 2);
+
+        timer.set_hitch_threshold_sec(0.01);
+        timer.clock().advance_sec(0.016);
+        timer.tick();
+        assert_eq!(timer.hitch_count(), 3);
+
+        timer.reset_statistics();
+        assert_eq!(timer.hitch_count(), 0);
+        assert_eq!(timer.histogram().total(), 0);
+    }
+
+    #[test]
+    fn histogram_buckets_frame_times() {
+        let timer = timer_with_frames(&[0.001, 0.005, 0.016, 0.017, 0.1, 0.5]);
+        let counts = timer.histogram().counts();
+        assert_eq!(counts, &[1, 1, 0, 1, 1, 0, 0, 0, 2]);
+        assert_eq!(timer.histogram().total(), 6);
+
+        assert_eq!(FrameTimeHistogram::bucket_range_ms(0), (0.0, Some(4.0)));
+        assert_eq!(FrameTimeHistogram::bucket_range_ms(3), (12.0, Some(16.7)));
+        assert_eq!(FrameTimeHistogram::bucket_range_ms(NUM_HISTOGRAM_BUCKETS - 1), (100.0, None));
+    }
+
+    #[test]
+    fn fixed_steps_accumulate_with_interpolation_alpha() {
+        let mut timer = TestTimer::with_clock(ManualClock::new());
+        timer.set_fixed_update_rate(100.0);
+
+        timer.clock().advance_sec(0.025);
+        timer.tick();
+        assert!(timer.consume_fixed_step());
+        assert!(timer.consume_fixed_step());
+        assert!(!timer.consume_fixed_step());
+        assert_near(timer.interpolation_alpha(), 0.5);
+
+        // (한국어) 남은 시간은 다음 프레임으로 이어집니다.
+        // (English Translation) The remaining time carries over to the next frame.
+        timer.clock().advance_sec(0.005);
+        timer.tick();
+        assert!(timer.consume_fixed_step());
+        assert!(!timer.consume_fixed_step());
+        assert_near(timer.interpolation_alpha(), 0.0);
+
+        // (한국어) 일시 정지 중에는 시간이 누적되지 않습니다.
+        // (English Translation) No time is accumulated while paused.
+        timer.set_paused(true);
+        timer.clock().advance_sec(0.05);
+        timer.tick();
+        assert!(!timer.consume_fixed_step());
+    }
+
+    #[test]
+    fn fixed_steps_are_limited_after_a_long_frame() {
+        let mut timer = TestTimer::with_clock(ManualClock::new());
+        timer.set_fixed_update_rate(100.0);
+        timer.set_max_fixed_steps(4);
+
+        timer.clock().advance_sec(1.0);
+        timer.tick();
+        let mut steps = 0;
+        while timer.consume_fixed_step() {
+            steps += 1;
+        }
+        assert_eq!(steps, 4);
+    }
+
+    #[test]
+    fn percentiles_use_nearest_rank() {
+        // (한국어) 1ms 부터 10ms 까지의 프레임을 순서를 섞어 기록합니다.
+        // (English Translation) Records frames from 1ms to 10ms in shuffled order.
+        let frame_times_ms = [7.0, 3.0, 10.0, 1.0, 5.0, 9.0, 2.0, 8.0, 4.0, 6.0];
+        let frame_times_sec: Vec<f64> = frame_times_ms.iter().map(|ms| ms / 1000.0).collect();
+        let timer = timer_with_frames(&frame_times_sec);
+
+        let stats = timer.frame_time_stats();
+        assert_near(stats.min_sec, 0.001);
+        assert_near(stats.max_sec, 0.010);
+        assert_near(stats.average_sec, 0.0055);
+        assert_near(stats.p50_sec, 0.005);
+        assert_near(stats.p95_sec, 0.010);
+        assert_near(stats.p99_sec, 0.010);
+
+        assert_near(timer.frame_time_percentile_sec(0.0), 0.001);
+        assert_near(timer.frame_time_percentile_sec(10.0), 0.001);
+        assert_near(timer.frame_time_percentile_sec(90.0), 0.009);
+        assert_near(timer.frame_time_percentile_sec(200.0), 0.010);
+    }
+
+    #[test]
+    fn percentiles_keep_only_the_recent_window() {
+        let mut frame_times_sec = vec![1.0; 5];
+        frame_times_sec.extend([0.002; 10]);
+        let timer = timer_with_frames(&frame_times_sec);
+        assert_near(timer.frame_time_stats().max_sec, 0.002);
+
+        let empty = TestTimer::with_clock(ManualClock::new());
+        assert_eq!(empty.frame_time_stats(), FrameTimeStats::default());
+        assert_near(empty.frame_time_percentile_sec(50.0), 0.0);
+    }
+}
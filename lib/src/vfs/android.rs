@@ -0,0 +1,70 @@
+use std::io;
+use std::io::Read;
+use std::fmt;
+use std::ffi::CString;
+use winit::platform::android::activity::AndroidApp;
+
+use crate::vfs::FileSystem;
+
+
+
+/// #### 한국어 </br>
+/// `APK`의 `assets` 디렉토리를 루트로 사용하는 읽기 전용 백엔드입니다. </br>
+/// `AndroidApp`을 통해 얻은 NDK의 `AAssetManager`로 파일을 읽습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A read-only backend that uses the `assets` directory of the `APK` as its root. </br>
+/// Files are read with the NDK `AAssetManager` obtained through `AndroidApp`. </br>
+/// 
+#[derive(Clone)]
+pub struct AndroidAssetFs {
+    app: AndroidApp, 
+}
+
+#[allow(dead_code)]
+impl AndroidAssetFs {
+    #[inline]
+    pub fn new(app: AndroidApp) -> Self {
+        Self { app }
+    }
+}
+
+impl fmt::Debug for AndroidAssetFs {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AndroidAssetFs").finish_non_exhaustive()
+    }
+}
+
+impl FileSystem for AndroidAssetFs {
+    fn read(&self, path: &str) -> io::Result<Vec<u8>> {
+        let name = to_c_string(path)?;
+        let mut asset = self.app.asset_manager()
+            .open(&name)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, format!("{} was not found in the APK", path)))?;
+
+        let mut bytes = Vec::with_capacity(asset.length());
+        asset.read_to_end(&mut bytes)?;
+        return Ok(bytes);
+    }
+
+    fn exists(&self, path: &str) -> bool {
+        to_c_string(path).ok()
+            .and_then(|name| self.app.asset_manager().open(&name))
+            .is_some()
+    }
+
+    fn read_dir(&self, path: &str) -> io::Result<Vec<String>> {
+        // (한국어) `AAssetDir`은 하위 디렉토리를 제외한 파일들의 이름만 반환합니다.
+        // (English Translation) `AAssetDir` returns only the names of files, excluding subdirectories.
+        let name = to_c_string(path)?;
+        let dir = self.app.asset_manager()
+            .open_dir(&name)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, format!("{} was not found in the APK", path)))?;
+        return Ok(dir.map(|name| name.to_string_lossy().into_owned()).collect());
+    }
+}
+
+#[inline]
+fn to_c_string(path: &str) -> io::Result<CString> {
+    CString::new(path).map_err(|err| io::Error::new(io::ErrorKind::InvalidInput, err))
+}
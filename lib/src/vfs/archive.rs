@@ -0,0 +1,769 @@
+use std::io;
+use std::fs;
+use std::sync::Arc;
+use std::path::Path;
+use std::collections::BTreeMap;
+
+use crate::vfs::FileSystem;
+use crate::vfs::memory::list_children;
+
+
+
+const END_OF_CENTRAL_DIRECTORY_SIGNATURE: u32 = 0x06054b50;
+const CENTRAL_DIRECTORY_SIGNATURE: u32 = 0x02014b50;
+const LOCAL_FILE_HEADER_SIGNATURE: u32 = 0x04034b50;
+
+const METHOD_STORED: u16 = 0;
+const METHOD_DEFLATED: u16 = 8;
+
+
+
+#[derive(Debug, Clone, Copy)]
+struct ArchiveEntry {
+    header_offset: usize, 
+    method: u16, 
+    crc32: u32, 
+    compressed_size: usize, 
+    uncompressed_size: usize, 
+}
+
+/// #### 한국어 </br>
+/// `zip` 형식의 아카이브(`.zip`, `.pak`)를 루트로 사용하는 읽기 전용 백엔드입니다. </br>
+/// 압축하지 않은 항목과 `deflate`로 압축된 항목을 지원하며, `zip64`와 암호화는 지원하지 않습니다. </br>
+/// 아카이브 전체를 메모리에 두고, 파일을 읽을 때 압축을 해제하고 `CRC-32`를 검사합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A read-only backend that uses an archive in `zip` format (`.zip`, `.pak`) as its root. </br>
+/// Supports stored entries and entries compressed with `deflate`; `zip64` and encryption are not supported. </br>
+/// The whole archive is kept in memory, and files are decompressed and checked against `CRC-32` when read. </br>
+/// 
+#[derive(Debug, Clone)]
+pub struct ArchiveFs {
+    bytes: Arc<[u8]>, 
+    entries: Arc<BTreeMap<String, ArchiveEntry>>, 
+}
+
+#[allow(dead_code)]
+impl ArchiveFs {
+    /// #### 한국어 </br>
+    /// 운영체제 파일 시스템의 아카이브 파일을 엽니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Opens an archive file of the operating system file system. </br>
+    /// 
+    pub fn open<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Self::from_bytes(fs::read(path)?)
+    }
+
+    /// #### 한국어 </br>
+    /// 메모리에 있는 아카이브를 엽니다. 다른 가상 파일 시스템에서 읽은 아카이브를 마운트할 때 사용됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Opens an archive in memory. Used to mount an archive read from another virtual file system. </br>
+    /// 
+    pub fn from_bytes<B: Into<Arc<[u8]>>>(bytes: B) -> io::Result<Self> {
+        let bytes: Arc<[u8]> = bytes.into();
+        let entries = read_central_directory(&bytes)?;
+        Ok(Self { bytes, entries: Arc::new(entries) })
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// #### 한국어 </br>
+    /// 아카이브에 있는 파일들의 경로를 정렬된 순서로 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the paths of the files in the archive in sorted order. </br>
+    /// 
+    pub fn file_names(&self) -> impl Iterator<Item = &str> {
+        self.entries.keys().map(|name| name.as_str())
+    }
+}
+
+impl FileSystem for ArchiveFs {
+    fn read(&self, path: &str) -> io::Result<Vec<u8>> {
+        let entry = self.entries.get(path)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, format!("{} was not found in the archive", path)))?;
+
+        let header = self.bytes.get(entry.header_offset..entry.header_offset + 30)
+            .filter(|header| read_u32(header, 0) == LOCAL_FILE_HEADER_SIGNATURE)
+            .ok_or_else(|| invalid_data("invalid local file header"))?;
+        let data_offset = entry.header_offset + 30 + read_u16(header, 26) as usize + read_u16(header, 28) as usize;
+        let data = self.bytes.get(data_offset..data_offset + entry.compressed_size)
+            .ok_or_else(|| invalid_data("file data is out of range"))?;
+
+        let bytes = match entry.method {
+            METHOD_STORED => data.to_vec(), 
+            METHOD_DEFLATED => inflate(data, entry.uncompressed_size)?, 
+            method => return Err(io::Error::new(
+                io::ErrorKind::Unsupported, 
+                format!("{} uses unsupported compression method {}", path, method)
+            )), 
+        };
+
+        if bytes.len() != entry.uncompressed_size || crc32(&bytes) != entry.crc32 {
+            return Err(invalid_data(&format!("{} is corrupted", path)));
+        }
+        Ok(bytes)
+    }
+
+    fn exists(&self, path: &str) -> bool {
+        self.entries.contains_key(path)
+    }
+
+    fn read_dir(&self, path: &str) -> io::Result<Vec<String>> {
+        list_children(self.file_names(), path)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, format!("{} was not found in the archive", path)))
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 아카이브 끝의 중앙 디렉토리를 읽어 파일 항목들을 찾습니다. 디렉토리 항목은 제외됩니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Reads the central directory at the end of the archive to find the file entries. Directory entries are excluded. </br>
+/// 
+fn read_central_directory(bytes: &[u8]) -> io::Result<BTreeMap<String, ArchiveEntry>> {
+    // (한국어) 중앙 디렉토리의 끝 레코드는 최대 65535 바이트의 주석 앞에 있습니다.
+    // (English Translation) The end of central directory record is located before a comment of at most 65535 bytes.
+    let search_start = bytes.len().saturating_sub(22 + u16::MAX as usize);
+    let end = (search_start..=bytes.len().saturating_sub(22))
+        .rev()
+        .find(|&offset| bytes.len() >= 22 && read_u32(bytes, offset) == END_OF_CENTRAL_DIRECTORY_SIGNATURE)
+        .ok_or_else(|| invalid_data("end of central directory record was not found"))?;
+
+    let num_entries = read_u16(bytes, end + 10) as usize;
+    let directory_size = read_u32(bytes, end + 12) as usize;
+    let directory_offset = read_u32(bytes, end + 16) as usize;
+    if directory_offset == u32::MAX as usize || directory_offset + directory_size > end {
+        return Err(invalid_data("zip64 archives are not supported"));
+    }
+
+    let mut entries = BTreeMap::new();
+    let mut offset = directory_offset;
+    for _ in 0..num_entries {
+        let header = bytes.get(offset..offset + 46)
+            .filter(|header| read_u32(header, 0) == CENTRAL_DIRECTORY_SIGNATURE)
+            .ok_or_else(|| invalid_data("invalid central directory header"))?;
+        let name_len = read_u16(header, 28) as usize;
+        let extra_len = read_u16(header, 30) as usize;
+        let comment_len = read_u16(header, 32) as usize;
+        let name = bytes.get(offset + 46..offset + 46 + name_len)
+            .ok_or_else(|| invalid_data("file name is out of range"))?;
+        let name = String::from_utf8_lossy(name).replace('\\', "/");
+
+        if !name.ends_with('/') {
+            if read_u16(header, 8) & 0x1 != 0 {
+                return Err(io::Error::new(io::ErrorKind::Unsupported, format!("{} is encrypted", name)));
+            }
+            entries.insert(name, ArchiveEntry {
+                header_offset: read_u32(header, 42) as usize, 
+                method: read_u16(header, 10), 
+                crc32: read_u32(header, 16), 
+                compressed_size: read_u32(header, 20) as usize, 
+                uncompressed_size: read_u32(header, 24) as usize, 
+            });
+        }
+        offset += 46 + name_len + extra_len + comment_len;
+    }
+    Ok(entries)
+}
+
+#[inline]
+fn read_u16(bytes: &[u8], offset: usize) -> u16 {
+    u16::from_le_bytes([bytes[offset], bytes[offset + 1]])
+}
+
+#[inline]
+fn read_u32(bytes: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes([bytes[offset], bytes[offset + 1], bytes[offset + 2], bytes[offset + 3]])
+}
+
+#[inline]
+fn invalid_data(msg: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg.to_string())
+}
+
+
+
+const CRC32_TABLE: [u32; 256] = {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = match crc & 1 {
+                1 => 0xEDB88320 ^ (crc >> 1), 
+                _ => crc >> 1, 
+            };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+};
+
+/// #### 한국어 </br>
+/// `zip`에서 사용하는 `CRC-32` 검사값을 계산합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Computes the `CRC-32` checksum used by `zip`. </br>
+/// 
+pub fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in bytes {
+        crc = CRC32_TABLE[((crc ^ byte as u32) & 0xFF) as usize] ^ (crc >> 8);
+    }
+    !crc
+}
+
+
+
+const LENGTH_BASE: [u16; 29] = [3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 15, 17, 19, 23, 27, 31, 35, 43, 51, 59, 67, 83, 99, 115, 131, 163, 195, 227, 258];
+const LENGTH_EXTRA: [u8; 29] = [0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 0];
+const DISTANCE_BASE: [u16; 30] = [1, 2, 3, 4, 5, 7, 9, 13, 17, 25, 33, 49, 65, 97, 129, 193, 257, 385, 513, 769, 1025, 1537, 2049, 3073, 4097, 6145, 8193, 12289, 16385, 24577];
+const DISTANCE_EXTRA: [u8; 30] = [0, 0, 0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8, 8, 9, 9, 10, 10, 11, 11, 12, 12, 13, 13];
+const CODE_LENGTH_ORDER: [usize; 19] = [16, 17, 18, 0, 8, 7, 9, 6, 10, 5, 11, 4, 12, 3, 13, 2, 14, 1, 15];
+const MAX_CODE_BITS: usize = 15;
+
+/// #### 한국어 </br>
+/// `deflate` 블록의 비트들을 최하위 비트부터 읽습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Reads the bits of `deflate` blocks starting from the least significant bit. </br>
+/// 
+struct BitReader<'a> {
+    bytes: &'a [u8], 
+    position: usize, 
+    buffer: u32, 
+    num_bits: u32, 
+}
+
+impl<'a> BitReader<'a> {
+    fn bits(&mut self, count: u32) -> io::Result<u32> {
+        while self.num_bits < count {
+            let byte = *self.bytes.get(self.position)
+                .ok_or_else(|| invalid_data("deflate stream ended unexpectedly"))?;
+            self.buffer |= (byte as u32) << self.num_bits;
+            self.num_bits += 8;
+            self.position += 1;
+        }
+        let value = self.buffer & ((1u32 << count) - 1);
+        self.buffer >>= count;
+        self.num_bits -= count;
+        Ok(value)
+    }
+
+    fn align_to_byte(&mut self) {
+        self.buffer = 0;
+        self.num_bits = 0;
+    }
+}
+
+/// #### 한국어 </br>
+/// 정규 허프만 코드의 길이별 코드 수와 길이 순으로 정렬된 심볼들입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The number of codes per length and the symbols sorted by length of a canonical Huffman code. </br>
+/// 
+struct Huffman {
+    counts: [u16; MAX_CODE_BITS + 1], 
+    symbols: Vec<u16>, 
+}
+
+impl Huffman {
+    fn new(lengths: &[u8]) -> io::Result<Self> {
+        let mut counts = [0u16; MAX_CODE_BITS + 1];
+        for &length in lengths {
+            counts[length as usize] += 1;
+        }
+
+        // (한국어) 코드가 초과 할당되지 않았는지 검사합니다. 불완전한 코드는 허용됩니다.
+        // (English Translation) Checks that the code is not over-subscribed. Incomplete codes are allowed.
+        let mut left = 1i32;
+        for &count in counts.iter().skip(1) {
+            left = (left << 1) - count as i32;
+            if left < 0 {
+                return Err(invalid_data("over-subscribed huffman code"));
+            }
+        }
+
+        let mut offsets = [0u16; MAX_CODE_BITS + 2];
+        for length in 1..=MAX_CODE_BITS {
+            offsets[length + 1] = offsets[length] + counts[length];
+        }
+        let mut symbols = vec![0u16; lengths.len()];
+        for (symbol, &length) in lengths.iter().enumerate() {
+            if length != 0 {
+                symbols[offsets[length as usize] as usize] = symbol as u16;
+                offsets[length as usize] += 1;
+            }
+        }
+        counts[0] = 0;
+        Ok(Self { counts, symbols })
+    }
+
+    fn decode(&self, reader: &mut BitReader) -> io::Result<u16> {
+        let mut code = 0i32;
+        let mut first = 0i32;
+        let mut index = 0i32;
+        for length in 1..=MAX_CODE_BITS {
+            code |= reader.bits(1)? as i32;
+            let count = self.counts[length] as i32;
+            if code - first < count {
+                return Ok(self.symbols[(index + code - first) as usize]);
+            }
+            index += count;
+            first = (first + count) << 1;
+            code <<= 1;
+        }
+        Err(invalid_data("invalid huffman code"))
+    }
+}
+
+/// #### 한국어 </br>
+/// `deflate`(RFC 1951)로 압축된 데이터의 압축을 해제합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Decompresses data compressed with `deflate` (RFC 1951). </br>
+/// 
+pub fn inflate(bytes: &[u8], size_hint: usize) -> io::Result<Vec<u8>> {
+    let mut output = Vec::with_capacity(size_hint);
+    let mut reader = BitReader { bytes, position: 0, buffer: 0, num_bits: 0 };
+    loop {
+        let is_last = reader.bits(1)? == 1;
+        match reader.bits(2)? {
+            0 => {
+                reader.align_to_byte();
+                let header = bytes.get(reader.position..reader.position + 4)
+                    .ok_or_else(|| invalid_data("deflate stream ended unexpectedly"))?;
+                let len = read_u16(header, 0);
+                if len != !read_u16(header, 2) {
+                    return Err(invalid_data("invalid stored block length"));
+                }
+                let start = reader.position + 4;
+                let data = bytes.get(start..start + len as usize)
+                    .ok_or_else(|| invalid_data("deflate stream ended unexpectedly"))?;
+                output.extend_from_slice(data);
+                reader.position = start + len as usize;
+            }, 
+            1 => {
+                let mut lengths = [0u8; 288];
+                lengths[0..144].fill(8);
+                lengths[144..256].fill(9);
+                lengths[256..280].fill(7);
+                lengths[280..288].fill(8);
+                let literals = Huffman::new(&lengths)?;
+                let distances = Huffman::new(&[5u8; 30])?;
+                inflate_block(&mut reader, &mut output, &literals, &distances)?;
+            }, 
+            2 => {
+                let (literals, distances) = read_dynamic_codes(&mut reader)?;
+                inflate_block(&mut reader, &mut output, &literals, &distances)?;
+            }, 
+            _ => return Err(invalid_data("invalid deflate block type")), 
+        };
+
+        if is_last {
+            return Ok(output);
+        }
+    }
+}
+
+fn read_dynamic_codes(reader: &mut BitReader) -> io::Result<(Huffman, Huffman)> {
+    let num_literals = reader.bits(5)? as usize + 257;
+    let num_distances = reader.bits(5)? as usize + 1;
+    let num_code_lengths = reader.bits(4)? as usize + 4;
+    if num_literals > 286 || num_distances > 30 {
+        return Err(invalid_data("too many huffman codes"));
+    }
+
+    let mut code_lengths = [0u8; 19];
+    for &index in CODE_LENGTH_ORDER.iter().take(num_code_lengths) {
+        code_lengths[index] = reader.bits(3)? as u8;
+    }
+    let code_length_code = Huffman::new(&code_lengths)?;
+
+    let mut lengths = vec![0u8; num_literals + num_distances];
+    let mut index = 0;
+    while index < lengths.len() {
+        let symbol = code_length_code.decode(reader)?;
+        let (value, repeat) = match symbol {
+            0..=15 => (symbol as u8, 1), 
+            16 => match index {
+                0 => return Err(invalid_data("repeat with no previous length")), 
+                _ => (lengths[index - 1], 3 + reader.bits(2)? as usize), 
+            }, 
+            17 => (0, 3 + reader.bits(3)? as usize), 
+            _ => (0, 11 + reader.bits(7)? as usize), 
+        };
+        if index + repeat > lengths.len() {
+            return Err(invalid_data("too many code lengths"));
+        }
+        lengths[index..index + repeat].fill(value);
+        index += repeat;
+    }
+
+    if lengths[256] == 0 {
+        return Err(invalid_data("missing end-of-block code"));
+    }
+    let literals = Huffman::new(&lengths[..num_literals])?;
+    let distances = Huffman::new(&lengths[num_literals..])?;
+    Ok((literals, distances))
+}
+
+fn inflate_block(reader: &mut BitReader, output: &mut Vec<u8>, literals: &Huffman, distances: &Huffman) -> io::Result<()> {
+    loop {
+        let symbol = literals.decode(reader)? as usize;
+        match symbol {
+            0..=255 => output.push(symbol as u8), 
+            256 => return Ok(()), 
+            _ => {
+                let index = symbol - 257;
+                if index >= LENGTH_BASE.len() {
+                    return Err(invalid_data("invalid length code"));
+                }
+                let length = LENGTH_BASE[index] as usize + reader.bits(LENGTH_EXTRA[index] as u32)? as usize;
+
+                let index = distances.decode(reader)? as usize;
+                if index >= DISTANCE_BASE.len() {
+                    return Err(invalid_data("invalid distance code"));
+                }
+                let distance = DISTANCE_BASE[index] as usize + reader.bits(DISTANCE_EXTRA[index] as u32)? as usize;
+                if distance > output.len() {
+                    return Err(invalid_data("distance is too far back"));
+                }
+
+                // (한국어) 복사 구간이 자기 자신과 겹칠 수 있으므로 한 바이트씩 복사합니다.
+                // (English Translation) Copies byte by byte since the copied range may overlap itself.
+                let start = output.len() - distance;
+                for i in 0..length {
+                    output.push(output[start + i]);
+                }
+            }, 
+        };
+    }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// #### 한국어 </br>
+    /// `(이름, 압축 방식, 저장된 데이터, 원본 데이터)` 항목들로 `zip` 아카이브를 만듭니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Builds a `zip` archive from `(name, method, stored data, original data)` entries. </br>
+    /// 
+    fn build_archive(entries: &[(&str, u16, &[u8], &[u8])]) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        let mut directory = Vec::new();
+        for &(name, method, data, original) in entries {
+            let header_offset = bytes.len() as u32;
+            bytes.extend_from_slice(&LOCAL_FILE_HEADER_SIGNATURE.to_le_bytes());
+            bytes.extend_from_slice(&[20, 0, 0, 0]);
+            bytes.extend_from_slice(&method.to_le_bytes());
+            bytes.extend_from_slice(&[0; 4]);
+            bytes.extend_from_slice(&crc32(original).to_le_bytes());
+            bytes.extend_from_slice(&(data.len() as u32).to_le_bytes());
+            bytes.extend_from_slice(&(original.len() as u32).to_le_bytes());
+            bytes.extend_from_slice(&(name.len() as u16).to_le_bytes());
+            bytes.extend_from_slice(&[0; 2]);
+            bytes.extend_from_slice(name.as_bytes());
+            bytes.extend_from_slice(data);
+
+            directory.extend_from_slice(&CENTRAL_DIRECTORY_SIGNATURE.to_le_bytes());
+            directory.extend_from_slice(&[20, 0, 20, 0, 0, 0]);
+            directory.extend_from_slice(&method.to_le_bytes());
+            directory.extend_from_slice(&[0; 4]);
+            directory.extend_from_slice(&crc32(original).to_le_bytes());
+            directory.extend_from_slice(&(data.len() as u32).to_le_bytes());
+            directory.extend_from_slice(&(original.len() as u32).to_le_bytes());
+            directory.extend_from_slice(&(name.len() as u16).to_le_bytes());
+            directory.extend_from_slice(&[0; 12]);
+            directory.extend_from_slice(&header_offset.to_le_bytes());
+            directory.extend_from_slice(name.as_bytes());
+        }
+
+        let directory_offset = bytes.len() as u32;
+        bytes.extend_from_slice(&directory);
+        bytes.extend_from_slice(&END_OF_CENTRAL_DIRECTORY_SIGNATURE.to_le_bytes());
+        bytes.extend_from_slice(&[0; 4]);
+        bytes.extend_from_slice(&(entries.len() as u16).to_le_bytes());
+        bytes.extend_from_slice(&(entries.len() as u16).to_le_bytes());
+        bytes.extend_from_slice(&(directory.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(&directory_offset.to_le_bytes());
+        bytes.extend_from_slice(&[0; 2]);
+        bytes
+    }
+
+    /// #### 한국어 </br>
+    /// 데이터를 압축하지 않는 하나의 `deflate` 블록으로 감쌉니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Wraps data in a single uncompressed `deflate` block. </br>
+    /// 
+    fn deflate_stored(data: &[u8]) -> Vec<u8> {
+        let len = data.len() as u16;
+        let mut bytes = vec![0x01];
+        bytes.extend_from_slice(&len.to_le_bytes());
+        bytes.extend_from_slice(&(!len).to_le_bytes());
+        bytes.extend_from_slice(data);
+        bytes
+    }
+
+    /// #### 한국어 </br>
+    /// `deflate` 스트림을 비트 단위로 작성합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Writes a `deflate` stream bit by bit. </br>
+    /// 
+    #[derive(Default)]
+    struct BitWriter {
+        bytes: Vec<u8>, 
+        num_bits: u32, 
+    }
+
+    impl BitWriter {
+        /// #### 한국어 </br>
+        /// 값의 비트들을 최하위 비트부터 씁니다. </br>
+        /// 
+        /// #### English (Translation) </br>
+        /// Writes the bits of a value starting from the least significant bit. </br>
+        /// 
+        fn bits(mut self, value: u32, count: u32) -> Self {
+            for bit in 0..count {
+                if self.num_bits % 8 == 0 {
+                    self.bytes.push(0);
+                }
+                let last = self.bytes.len() - 1;
+                self.bytes[last] |= (((value >> bit) & 1) as u8) << (self.num_bits % 8);
+                self.num_bits += 1;
+            }
+            self
+        }
+
+        /// #### 한국어 </br>
+        /// 허프만 코드를 최상위 비트부터 씁니다. </br>
+        /// 
+        /// #### English (Translation) </br>
+        /// Writes a Huffman code starting from the most significant bit. </br>
+        /// 
+        fn code(self, code: u32, length: u32) -> Self {
+            let reversed = (0..length).fold(0, |acc, bit| (acc << 1) | ((code >> bit) & 1));
+            self.bits(reversed, length)
+        }
+
+        /// #### 한국어 </br>
+        /// 고정 허프만 코드 블록의 리터럴 또는 길이 심볼을 씁니다. </br>
+        /// 
+        /// #### English (Translation) </br>
+        /// Writes a literal or length symbol of a fixed Huffman code block. </br>
+        /// 
+        fn fixed_symbol(self, symbol: u32) -> Self {
+            match symbol {
+                0..=143 => self.code(0x30 + symbol, 8), 
+                144..=255 => self.code(0x190 + symbol - 144, 9), 
+                256..=279 => self.code(symbol - 256, 7), 
+                _ => self.code(0xC0 + symbol - 280, 8), 
+            }
+        }
+    }
+
+    #[test]
+    fn inflate_decodes_fixed_huffman_blocks() {
+        // (한국어) "abc" 다음에 거리 3, 길이 6의 겹치는 복사가 이어집니다.
+        // (English Translation) "abc" followed by an overlapping copy of length 6 at distance 3.
+        let bytes = BitWriter::default()
+            .bits(1, 1)
+            .bits(1, 2)
+            .fixed_symbol(b'a' as u32)
+            .fixed_symbol(b'b' as u32)
+            .fixed_symbol(b'c' as u32)
+            .fixed_symbol(260)
+            .code(2, 5)
+            .fixed_symbol(256)
+            .bytes;
+        assert_eq!(inflate(&bytes, 0).unwrap(), b"abcabcabc");
+    }
+
+    #[test]
+    fn inflate_rejects_truncated_streams() {
+        assert_eq!(inflate(&[], 0).unwrap_err().kind(), io::ErrorKind::InvalidData);
+
+        let stored = deflate_stored(b"hello");
+        for len in 1..stored.len() {
+            assert_eq!(inflate(&stored[..len], 0).unwrap_err().kind(), io::ErrorKind::InvalidData, "{}", len);
+        }
+
+        // (한국어) 블록 끝 심볼이 없는 고정 허프만 블록입니다.
+        // (English Translation) A fixed Huffman block without the end-of-block symbol.
+        let bytes = BitWriter::default()
+            .bits(1, 1)
+            .bits(1, 2)
+            .fixed_symbol(b'a' as u32)
+            .bytes;
+        assert_eq!(inflate(&bytes, 0).unwrap_err().kind(), io::ErrorKind::InvalidData);
+
+        // (한국어) 마지막 블록이 아닌 블록 뒤에 스트림이 끝납니다.
+        // (English Translation) The stream ends after a block that is not the last one.
+        let mut bytes = deflate_stored(b"hello");
+        bytes[0] = 0x00;
+        assert_eq!(inflate(&bytes, 0).unwrap_err().kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn inflate_rejects_invalid_blocks() {
+        // (한국어) 블록 종류 3은 예약되어 있습니다.
+        // (English Translation) Block type 3 is reserved.
+        let bytes = BitWriter::default().bits(1, 1).bits(3, 2).bytes;
+        assert_eq!(inflate(&bytes, 0).unwrap_err().kind(), io::ErrorKind::InvalidData);
+
+        let mut bytes = deflate_stored(b"hello");
+        bytes[3] ^= 0x01;
+        assert_eq!(inflate(&bytes, 0).unwrap_err().kind(), io::ErrorKind::InvalidData);
+
+        // (한국어) 리터럴 코드가 286개를 넘는 동적 허프만 블록입니다.
+        // (English Translation) A dynamic Huffman block with more than 286 literal codes.
+        let bytes = BitWriter::default()
+            .bits(1, 1)
+            .bits(2, 2)
+            .bits(31, 5)
+            .bits(0, 5)
+            .bits(0, 4)
+            .bytes;
+        assert_eq!(inflate(&bytes, 0).unwrap_err().kind(), io::ErrorKind::InvalidData);
+
+        // (한국어) 모든 코드 길이 코드가 1비트인 초과 할당된 동적 허프만 블록입니다.
+        // (English Translation) An over-subscribed dynamic Huffman block whose code length codes are all 1 bit long.
+        let mut writer = BitWriter::default()
+            .bits(1, 1)
+            .bits(2, 2)
+            .bits(0, 5)
+            .bits(0, 5)
+            .bits(15, 4);
+        for _ in 0..19 {
+            writer = writer.bits(1, 3);
+        }
+        assert_eq!(inflate(&writer.bytes, 0).unwrap_err().kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn inflate_rejects_distances_beyond_the_output() {
+        // (한국어) 한 바이트만 출력된 상태에서 거리 2의 복사를 요청합니다.
+        // (English Translation) Requests a copy at distance 2 when only one byte has been written.
+        let bytes = BitWriter::default()
+            .bits(1, 1)
+            .bits(1, 2)
+            .fixed_symbol(b'a' as u32)
+            .fixed_symbol(257)
+            .code(1, 5)
+            .fixed_symbol(256)
+            .bytes;
+        assert_eq!(inflate(&bytes, 0).unwrap_err().kind(), io::ErrorKind::InvalidData);
+
+        let bytes = BitWriter::default()
+            .bits(1, 1)
+            .bits(1, 2)
+            .fixed_symbol(257)
+            .code(0, 5)
+            .fixed_symbol(256)
+            .bytes;
+        assert_eq!(inflate(&bytes, 0).unwrap_err().kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn read_rejects_deflated_entries_with_mismatched_checksums() {
+        let deflated = deflate_stored(b"fn main() {}");
+        let truncated = &deflated[..deflated.len() - 1];
+        let bytes = build_archive(&[
+            ("checksum.wgsl", METHOD_DEFLATED, &deflated, b"fn main() {)"), 
+            ("size.wgsl", METHOD_DEFLATED, &deflated, b"fn main() {} "), 
+            ("truncated.wgsl", METHOD_DEFLATED, truncated, b"fn main() {}"), 
+        ]);
+        let archive = ArchiveFs::from_bytes(bytes).unwrap();
+
+        assert_eq!(archive.read("checksum.wgsl").unwrap_err().kind(), io::ErrorKind::InvalidData);
+        assert_eq!(archive.read("size.wgsl").unwrap_err().kind(), io::ErrorKind::InvalidData);
+        assert_eq!(archive.read("truncated.wgsl").unwrap_err().kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn crc32_matches_reference_value() {
+        assert_eq!(crc32(b"123456789"), 0xCBF43926);
+        assert_eq!(crc32(b""), 0);
+    }
+
+    #[test]
+    fn index_lists_files_and_skips_directories() {
+        let deflated = deflate_stored(b"fn main() {}");
+        let bytes = build_archive(&[
+            ("shaders/", METHOD_STORED, b"", b""), 
+            ("shaders/colored.wgsl", METHOD_DEFLATED, &deflated, b"fn main() {}"), 
+            ("shaders\\debug.wgsl", METHOD_STORED, b"debug", b"debug"), 
+            ("readme.txt", METHOD_STORED, b"hello", b"hello"), 
+        ]);
+        let archive = ArchiveFs::from_bytes(bytes).unwrap();
+
+        assert_eq!(archive.len(), 3);
+        assert_eq!(
+            archive.file_names().collect::<Vec<_>>(), 
+            ["readme.txt", "shaders/colored.wgsl", "shaders/debug.wgsl"]
+        );
+        assert!(archive.exists("shaders/debug.wgsl"));
+        assert!(!archive.exists("shaders"));
+        assert_eq!(archive.read_dir("").unwrap(), ["readme.txt", "shaders"]);
+        assert_eq!(archive.read_dir("shaders").unwrap(), ["colored.wgsl", "debug.wgsl"]);
+        assert_eq!(archive.read_dir("missing").unwrap_err().kind(), io::ErrorKind::NotFound);
+    }
+
+    #[test]
+    fn read_decompresses_and_checks_entries() {
+        let deflated = deflate_stored(b"fn main() {}");
+        let bytes = build_archive(&[
+            ("stored.txt", METHOD_STORED, b"hello", b"hello"), 
+            ("deflated.wgsl", METHOD_DEFLATED, &deflated, b"fn main() {}"), 
+            ("corrupted.txt", METHOD_STORED, b"hellO", b"hello"), 
+            ("unknown.bin", 99, b"data", b"data"), 
+        ]);
+        let archive = ArchiveFs::from_bytes(bytes).unwrap();
+
+        assert_eq!(archive.read("stored.txt").unwrap(), b"hello");
+        assert_eq!(archive.read("deflated.wgsl").unwrap(), b"fn main() {}");
+        assert_eq!(archive.read("corrupted.txt").unwrap_err().kind(), io::ErrorKind::InvalidData);
+        assert_eq!(archive.read("unknown.bin").unwrap_err().kind(), io::ErrorKind::Unsupported);
+        assert_eq!(archive.read("missing.txt").unwrap_err().kind(), io::ErrorKind::NotFound);
+    }
+
+    #[test]
+    fn rejects_invalid_archives() {
+        assert_eq!(ArchiveFs::from_bytes(Vec::new()).unwrap_err().kind(), io::ErrorKind::InvalidData);
+        assert_eq!(ArchiveFs::from_bytes(b"not an archive".to_vec()).unwrap_err().kind(), io::ErrorKind::InvalidData);
+
+        let mut bytes = build_archive(&[("file.txt", METHOD_STORED, b"hello", b"hello")]);
+        let directory_offset = bytes.len() - 6;
+        bytes[directory_offset..directory_offset + 4].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert_eq!(ArchiveFs::from_bytes(bytes).unwrap_err().kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn empty_archive_has_no_files() {
+        let archive = ArchiveFs::from_bytes(build_archive(&[])).unwrap();
+        assert!(archive.is_empty());
+        assert!(archive.read_dir("").unwrap().is_empty());
+    }
+}
@@ -0,0 +1,65 @@
+use std::io;
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+
+use crate::vfs::FileSystem;
+
+
+
+/// #### 한국어 </br>
+/// 운영체제 파일 시스템의 디렉토리를 루트로 사용하는 백엔드입니다. 데스크톱에서 사용됩니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A backend that uses a directory of the operating system file system as its root. Used on desktop. </br>
+/// 
+#[derive(Debug, Clone)]
+pub struct DirectoryFs {
+    root: PathBuf, 
+}
+
+#[allow(dead_code)]
+impl DirectoryFs {
+    #[inline]
+    pub fn new<P: Into<PathBuf>>(root: P) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// #### 한국어 </br>
+    /// 현재 실행 파일이 있는 디렉토리 안의 `name` 디렉토리를 루트로 사용합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Uses the `name` directory inside the directory of the current executable as the root. </br>
+    /// 
+    pub fn next_to_executable<P: AsRef<Path>>(name: P) -> io::Result<Self> {
+        let executable = std::env::current_exe()?;
+        let dir = executable.parent()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "The executable has no parent directory"))?;
+        Ok(Self::new(dir.join(name)))
+    }
+
+    #[inline]
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+}
+
+impl FileSystem for DirectoryFs {
+    fn read(&self, path: &str) -> io::Result<Vec<u8>> {
+        fs::read(self.root.join(path))
+    }
+
+    fn exists(&self, path: &str) -> bool {
+        self.root.join(path).is_file()
+    }
+
+    fn read_dir(&self, path: &str) -> io::Result<Vec<String>> {
+        let mut names = Vec::new();
+        for entry in fs::read_dir(self.root.join(path))? {
+            if let Some(name) = entry?.file_name().to_str() {
+                names.push(name.to_string());
+            }
+        }
+        Ok(names)
+    }
+}
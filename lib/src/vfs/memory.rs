@@ -0,0 +1,161 @@
+use std::io;
+use std::sync::Arc;
+use std::sync::RwLock;
+use std::path::Path;
+use std::collections::BTreeMap;
+
+use crate::vfs::FileSystem;
+use crate::vfs::normalize_path;
+
+
+
+/// #### 한국어 </br>
+/// 파일들을 메모리에 보관하는 백엔드입니다. 테스트나 런타임에 생성된 데이터에 사용됩니다. </br>
+/// 복제본들은 같은 파일들을 공유하므로, 마운트한 후에도 복제본으로 파일을 추가하거나 제거할 수 있습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A backend that keeps files in memory. Used for tests or data generated at runtime. </br>
+/// Clones share the same files, so files can be added or removed through a clone even after mounting. </br>
+/// 
+#[derive(Debug, Clone, Default)]
+pub struct MemoryFs {
+    files: Arc<RwLock<BTreeMap<String, Arc<[u8]>>>>, 
+}
+
+#[allow(dead_code)]
+impl MemoryFs {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// #### 한국어 </br>
+    /// 파일을 추가하거나 교체합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Adds or replaces a file. </br>
+    /// 
+    pub fn insert<P: AsRef<Path>, B: Into<Arc<[u8]>>>(&self, path: P, bytes: B) -> io::Result<()> {
+        let path = normalize_path(path.as_ref())?;
+        if let Ok(mut files) = self.files.write() {
+            files.insert(path, bytes.into());
+        }
+        Ok(())
+    }
+
+    #[inline]
+    pub fn with_file<P: AsRef<Path>, B: Into<Arc<[u8]>>>(self, path: P, bytes: B) -> io::Result<Self> {
+        self.insert(path, bytes)?;
+        Ok(self)
+    }
+
+    /// #### 한국어 </br>
+    /// 파일을 제거하고, 파일이 있었는지 여부를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Removes a file, and returns whether the file existed. </br>
+    /// 
+    pub fn remove<P: AsRef<Path>>(&self, path: P) -> bool {
+        let Ok(path) = normalize_path(path.as_ref()) else {
+            return false;
+        };
+        self.files.write()
+            .map(|mut files| files.remove(&path).is_some())
+            .unwrap_or(false)
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.files.read().map(|files| files.len()).unwrap_or(0)
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl FileSystem for MemoryFs {
+    fn read(&self, path: &str) -> io::Result<Vec<u8>> {
+        self.files.read().ok()
+            .and_then(|files| files.get(path).map(|bytes| bytes.to_vec()))
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, format!("{} was not found in memory", path)))
+    }
+
+    fn exists(&self, path: &str) -> bool {
+        self.files.read()
+            .map(|files| files.contains_key(path))
+            .unwrap_or(false)
+    }
+
+    fn read_dir(&self, path: &str) -> io::Result<Vec<String>> {
+        let files = self.files.read()
+            .map_err(|_| io::Error::other("The file table is poisoned"))?;
+        list_children(files.keys().map(|key| key.as_str()), path)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, format!("{} was not found in memory", path)))
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 정렬된 파일 경로들에서 `dir` 디렉토리의 바로 아래 항목들의 이름을 찾습니다. 디렉토리가 없다면 `None`을 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Finds the names of the entries directly under the `dir` directory from sorted file paths. Returns `None` if the directory does not exist. </br>
+/// 
+pub(crate) fn list_children<'a, I: Iterator<Item = &'a str>>(paths: I, dir: &str) -> Option<Vec<String>> {
+    let mut found = dir.is_empty();
+    let mut names: Vec<String> = Vec::new();
+    for path in paths {
+        let rest = match dir.is_empty() {
+            true => Some(path), 
+            false => path.strip_prefix(dir).and_then(|rest| rest.strip_prefix('/')), 
+        };
+        if let Some(name) = rest.and_then(|rest| rest.split('/').next()) {
+            found = true;
+            if !name.is_empty() && names.last().map(|last| last.as_str()) != Some(name) {
+                names.push(name.to_string());
+            }
+        }
+    }
+    found.then_some(names)
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert_normalizes_paths_and_clones_share_files() {
+        let fs = MemoryFs::new();
+        let clone = fs.clone();
+        clone.insert("./shaders/../shaders/colored.wgsl", b"colored".to_vec()).unwrap();
+
+        assert_eq!(fs.len(), 1);
+        assert!(fs.exists("shaders/colored.wgsl"));
+        assert_eq!(fs.read("shaders/colored.wgsl").unwrap(), b"colored");
+        assert!(fs.insert("../outside.txt", b"".to_vec()).is_err());
+
+        assert!(fs.remove("shaders//colored.wgsl"));
+        assert!(!fs.remove("shaders/colored.wgsl"));
+        assert!(clone.is_empty());
+        assert_eq!(fs.read("shaders/colored.wgsl").unwrap_err().kind(), io::ErrorKind::NotFound);
+    }
+
+    #[test]
+    fn read_dir_lists_direct_children() {
+        let fs = MemoryFs::new()
+            .with_file("a.txt", b"a".to_vec()).unwrap()
+            .with_file("models/box.obj", b"box".to_vec()).unwrap()
+            .with_file("models/tree/leaf.obj", b"leaf".to_vec()).unwrap()
+            .with_file("models_old/box.obj", b"old".to_vec()).unwrap();
+
+        assert_eq!(fs.read_dir("").unwrap(), ["a.txt", "models", "models_old"]);
+        assert_eq!(fs.read_dir("models").unwrap(), ["box.obj", "tree"]);
+        assert_eq!(fs.read_dir("models/tree").unwrap(), ["leaf.obj"]);
+        assert_eq!(fs.read_dir("mod").unwrap_err().kind(), io::ErrorKind::NotFound);
+    }
+}
@@ -0,0 +1,387 @@
+#[cfg(target_os = "android")]
+pub mod android;
+pub mod archive;
+pub mod directory;
+pub mod memory;
+
+use std::io;
+use std::fmt;
+use std::sync::Arc;
+use std::path::Path;
+use std::path::Component;
+
+#[cfg(target_os = "android")]
+pub use self::android::AndroidAssetFs;
+pub use self::archive::ArchiveFs;
+pub use self::directory::DirectoryFs;
+pub use self::memory::MemoryFs;
+
+
+
+/// #### 한국어 </br>
+/// 가상 파일 시스템에 마운트할 수 있는 파일 시스템 백엔드의 trait입니다. </br>
+/// 경로는 백엔드의 루트를 기준으로 한 `/`로 구분된 상대 경로입니다. </br>
+/// 작업 스레드에서도 파일을 읽을 수 있도록 `Send`와 `Sync`를 구현해야 합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The trait of file system backends that can be mounted on the virtual file system. </br>
+/// Paths are `/`-separated relative paths based on the root of the backend. </br>
+/// It must implement `Send` and `Sync` so that files can also be read on worker threads. </br>
+/// 
+pub trait FileSystem: fmt::Debug + Send + Sync {
+    /// #### 한국어 </br>
+    /// 파일의 모든 내용을 읽습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Reads the entire contents of a file. </br>
+    /// 
+    fn read(&self, path: &str) -> io::Result<Vec<u8>>;
+
+    /// #### 한국어 </br>
+    /// 파일이 존재하는지 여부를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns whether the file exists. </br>
+    /// 
+    fn exists(&self, path: &str) -> bool;
+
+    /// #### 한국어 </br>
+    /// 디렉토리에 있는 항목들의 이름을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the names of the entries in a directory. </br>
+    /// 
+    fn read_dir(&self, path: &str) -> io::Result<Vec<String>>;
+}
+
+
+
+#[derive(Debug, Clone)]
+struct Mount {
+    prefix: String, 
+    fs: Arc<dyn FileSystem>, 
+}
+
+/// #### 한국어 </br>
+/// 여러 파일 시스템 백엔드를 마운트 지점에 연결하는 가상 파일 시스템입니다. </br>
+/// 파일을 읽을 때 경로와 일치하는 마운트 지점들을 나중에 마운트된 순서대로 찾으며,
+/// 파일이 없는 백엔드는 건너뜁니다. 따라서 나중에 마운트된 백엔드의 파일이 우선합니다. </br>
+/// 복제본들은 같은 백엔드들을 공유합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A virtual file system that connects several file system backends to mount points. </br>
+/// When reading a file, the mount points matching the path are searched in reverse mount order,
+/// and backends without the file are skipped. Therefore, files of backends mounted later take precedence. </br>
+/// Clones share the same backends. </br>
+/// 
+#[derive(Debug, Clone, Default)]
+pub struct Vfs {
+    mounts: Vec<Mount>, 
+}
+
+#[allow(dead_code)]
+impl Vfs {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// #### 한국어 </br>
+    /// 데스크톱의 기본 가상 파일 시스템을 생성합니다. </br>
+    /// 크레이트의 `assets` 디렉토리를 루트에 마운트하고,
+    /// 실행 파일 옆에 `assets` 디렉토리가 있다면 그 위에 마운트하여 우선하도록 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates the default virtual file system for desktop. </br>
+    /// Mounts the `assets` directory of the crate at the root,
+    /// and if there is an `assets` directory next to the executable, mounts it over that so that it takes precedence. </br>
+    /// 
+    #[cfg(not(target_os = "android"))]
+    pub fn desktop() -> Self {
+        let mut vfs = Self::new()
+            .with_mount("", DirectoryFs::new(concat!(env!("CARGO_MANIFEST_DIR"), "/assets")));
+        match DirectoryFs::next_to_executable("assets") {
+            Ok(fs) if fs.root().is_dir() => vfs.mount("", fs), 
+            Ok(_) => { /* empty */ }, 
+            Err(err) => log::warn!("Could not find the executable directory: {}", err), 
+        };
+        vfs
+    }
+
+    /// #### 한국어 </br>
+    /// Android의 기본 가상 파일 시스템을 생성합니다. `APK`의 에셋들을 루트에 마운트합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates the default virtual file system for Android. Mounts the assets of the `APK` at the root. </br>
+    /// 
+    #[cfg(target_os = "android")]
+    pub fn android(app: winit::platform::android::activity::AndroidApp) -> Self {
+        Self::new().with_mount("", AndroidAssetFs::new(app))
+    }
+
+    /// #### 한국어 </br>
+    /// 파일 시스템 백엔드를 `prefix` 마운트 지점에 마운트합니다. 빈 문자열은 루트를 의미합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Mounts a file system backend at the `prefix` mount point. An empty string means the root. </br>
+    /// 
+    pub fn mount<P: AsRef<Path>, F: FileSystem + 'static>(&mut self, prefix: P, fs: F) {
+        self.mount_shared(prefix, Arc::new(fs));
+    }
+
+    /// #### 한국어 </br>
+    /// 이미 공유된 파일 시스템 백엔드를 `prefix` 마운트 지점에 마운트합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Mounts an already shared file system backend at the `prefix` mount point. </br>
+    /// 
+    pub fn mount_shared<P: AsRef<Path>>(&mut self, prefix: P, fs: Arc<dyn FileSystem>) {
+        match normalize_path(prefix.as_ref()) {
+            Ok(prefix) => self.mounts.push(Mount { prefix, fs }), 
+            Err(err) => log::error!("Could not mount {}: {}", prefix.as_ref().display(), err), 
+        };
+    }
+
+    #[inline]
+    pub fn with_mount<P: AsRef<Path>, F: FileSystem + 'static>(mut self, prefix: P, fs: F) -> Self {
+        self.mount(prefix, fs);
+        self
+    }
+
+    /// #### 한국어 </br>
+    /// `prefix` 마운트 지점에 마운트된 모든 백엔드를 제거하고, 제거된 백엔드의 수를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Removes all backends mounted at the `prefix` mount point, and returns the number of removed backends. </br>
+    /// 
+    pub fn unmount<P: AsRef<Path>>(&mut self, prefix: P) -> usize {
+        let Ok(prefix) = normalize_path(prefix.as_ref()) else {
+            return 0;
+        };
+        let num_mounts = self.mounts.len();
+        self.mounts.retain(|mount| mount.prefix != prefix);
+        num_mounts - self.mounts.len()
+    }
+
+    /// #### 한국어 </br>
+    /// 마운트 지점들을 마운트된 순서대로 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the mount points in mount order. </br>
+    /// 
+    pub fn mount_points(&self) -> impl Iterator<Item = &str> {
+        self.mounts.iter().map(|mount| mount.prefix.as_str())
+    }
+
+    /// #### 한국어 </br>
+    /// 파일의 모든 내용을 읽습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Reads the entire contents of a file. </br>
+    /// 
+    pub fn read<P: AsRef<Path>>(&self, path: P) -> io::Result<Vec<u8>> {
+        let path = normalize_path(path.as_ref())?;
+        for (fs, relative) in self.resolve(&path) {
+            match fs.read(relative) {
+                Err(err) if err.kind() == io::ErrorKind::NotFound => continue, 
+                result => return result, 
+            };
+        }
+        Err(not_found(&path))
+    }
+
+    /// #### 한국어 </br>
+    /// `UTF-8` 텍스트 파일의 모든 내용을 읽습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Reads the entire contents of a `UTF-8` text file. </br>
+    /// 
+    pub fn read_to_string<P: AsRef<Path>>(&self, path: P) -> io::Result<String> {
+        let bytes = self.read(path)?;
+        String::from_utf8(bytes)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+    }
+
+    /// #### 한국어 </br>
+    /// 마운트된 백엔드 중 하나에 파일이 존재하는지 여부를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns whether the file exists in one of the mounted backends. </br>
+    /// 
+    pub fn exists<P: AsRef<Path>>(&self, path: P) -> bool {
+        let Ok(path) = normalize_path(path.as_ref()) else {
+            return false;
+        };
+        return self.resolve(&path).any(|(fs, relative)| fs.exists(relative));
+    }
+
+    /// #### 한국어 </br>
+    /// 디렉토리에 있는 항목들의 이름을 모든 백엔드에서 모아 정렬된 순서로 반환합니다. </br>
+    /// 경로 안에 있는 마운트 지점의 이름도 항목으로 포함됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gathers the names of the entries in a directory from all backends and returns them in sorted order. </br>
+    /// The names of mount points inside the path are also included as entries. </br>
+    /// 
+    pub fn read_dir<P: AsRef<Path>>(&self, path: P) -> io::Result<Vec<String>> {
+        let path = normalize_path(path.as_ref())?;
+        let mut found = false;
+        let mut names = Vec::new();
+        for (fs, relative) in self.resolve(&path) {
+            match fs.read_dir(relative) {
+                Ok(entries) => {
+                    found = true;
+                    names.extend(entries);
+                }, 
+                Err(err) if err.kind() == io::ErrorKind::NotFound => continue, 
+                Err(err) => return Err(err), 
+            };
+        }
+
+        for mount in self.mounts.iter() {
+            if let Some(name) = strip_prefix(&mount.prefix, &path).and_then(|rest| rest.split('/').next()) {
+                if !name.is_empty() {
+                    found = true;
+                    names.push(name.to_string());
+                }
+            }
+        }
+
+        if !found {
+            return Err(not_found(&path));
+        }
+        names.sort();
+        names.dedup();
+        Ok(names)
+    }
+
+    /// #### 한국어 </br>
+    /// 경로와 일치하는 백엔드들과 백엔드 기준의 상대 경로를 나중에 마운트된 순서대로 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the backends matching the path and the paths relative to them, in reverse mount order. </br>
+    /// 
+    fn resolve<'a>(&'a self, path: &'a str) -> impl Iterator<Item = (&'a dyn FileSystem, &'a str)> {
+        self.mounts.iter()
+            .rev()
+            .filter_map(move |mount| {
+                strip_prefix(path, &mount.prefix).map(|relative| (mount.fs.as_ref(), relative))
+            })
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 경로를 `/`로 구분된 상대 경로로 정규화합니다. `.`은 제거되며, 루트 밖을 가리키는 `..`는 오류입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Normalizes a path into a `/`-separated relative path. `.` is removed, and `..` pointing outside the root is an error. </br>
+/// 
+pub fn normalize_path(path: &Path) -> io::Result<String> {
+    let mut parts: Vec<&str> = Vec::new();
+    for component in path.components() {
+        match component {
+            Component::Normal(part) => parts.push(part.to_str().ok_or_else(|| {
+                io::Error::new(io::ErrorKind::InvalidInput, format!("{} is not valid UTF-8", path.display()))
+            })?), 
+            Component::ParentDir => if parts.pop().is_none() {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput, 
+                    format!("{} points outside the root", path.display())
+                ));
+            }, 
+            Component::CurDir | Component::RootDir | Component::Prefix(_) => { /* empty */ }, 
+        };
+    }
+    Ok(parts.join("/"))
+}
+
+/// #### 한국어 </br>
+/// `path`가 `prefix` 디렉토리 안에 있다면 `prefix` 기준의 상대 경로를 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns the path relative to `prefix` if `path` is inside the `prefix` directory. </br>
+/// 
+fn strip_prefix<'a>(path: &'a str, prefix: &str) -> Option<&'a str> {
+    if prefix.is_empty() {
+        return Some(path);
+    }
+    let rest = path.strip_prefix(prefix)?;
+    if rest.is_empty() {
+        return Some(rest);
+    }
+    rest.strip_prefix('/')
+}
+
+#[inline]
+fn not_found(path: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::NotFound, format!("{} was not found in any mounted file system", path))
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn memory_fs(files: &[(&str, &str)]) -> MemoryFs {
+        let fs = MemoryFs::new();
+        for &(path, text) in files {
+            fs.insert(path, text.as_bytes().to_vec()).unwrap();
+        }
+        fs
+    }
+
+    #[test]
+    fn normalize_path_removes_dots_and_separators() {
+        assert_eq!(normalize_path(Path::new("shaders/colored.wgsl")).unwrap(), "shaders/colored.wgsl");
+        assert_eq!(normalize_path(Path::new("/shaders/./colored.wgsl")).unwrap(), "shaders/colored.wgsl");
+        assert_eq!(normalize_path(Path::new("shaders//debug/../colored.wgsl")).unwrap(), "shaders/colored.wgsl");
+        assert_eq!(normalize_path(Path::new("")).unwrap(), "");
+        assert_eq!(normalize_path(Path::new(".")).unwrap(), "");
+        assert_eq!(normalize_path(Path::new("../colored.wgsl")).unwrap_err().kind(), io::ErrorKind::InvalidInput);
+        assert_eq!(normalize_path(Path::new("shaders/../../colored.wgsl")).unwrap_err().kind(), io::ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn later_mounts_take_precedence() {
+        let vfs = Vfs::new()
+            .with_mount("", memory_fs(&[("config.toml", "base"), ("base.txt", "base")]))
+            .with_mount("", memory_fs(&[("config.toml", "override")]));
+
+        assert_eq!(vfs.read_to_string("config.toml").unwrap(), "override");
+        assert_eq!(vfs.read_to_string("base.txt").unwrap(), "base");
+        assert_eq!(vfs.read("missing.txt").unwrap_err().kind(), io::ErrorKind::NotFound);
+    }
+
+    #[test]
+    fn mount_prefixes_match_whole_components() {
+        let mut vfs = Vfs::new()
+            .with_mount("", memory_fs(&[("shaders/colored.wgsl", "root"), ("shaders_old/colored.wgsl", "old")]))
+            .with_mount("./shaders/", memory_fs(&[("colored.wgsl", "mounted")]));
+
+        assert_eq!(vfs.read_to_string("shaders/colored.wgsl").unwrap(), "mounted");
+        assert_eq!(vfs.read_to_string("/shaders/../shaders/colored.wgsl").unwrap(), "mounted");
+        assert_eq!(vfs.read_to_string("shaders_old/colored.wgsl").unwrap(), "old");
+        assert!(vfs.exists("shaders/colored.wgsl"));
+        assert!(vfs.read("../shaders/colored.wgsl").is_err());
+
+        assert_eq!(vfs.unmount("shaders"), 1);
+        assert_eq!(vfs.read_to_string("shaders/colored.wgsl").unwrap(), "root");
+    }
+
+    #[test]
+    fn read_dir_merges_backends_and_mount_points() {
+        let vfs = Vfs::new()
+            .with_mount("", memory_fs(&[("a.txt", "a"), ("models/box.obj", "box")]))
+            .with_mount("", memory_fs(&[("b.txt", "b"), ("models/box.obj", "box")]))
+            .with_mount("packs/base", memory_fs(&[("c.txt", "c")]));
+
+        assert_eq!(vfs.read_dir("").unwrap(), ["a.txt", "b.txt", "models", "packs"]);
+        assert_eq!(vfs.read_dir("models").unwrap(), ["box.obj"]);
+        assert_eq!(vfs.read_dir("packs").unwrap(), ["base"]);
+        assert_eq!(vfs.read_dir("packs/base").unwrap(), ["c.txt"]);
+        assert_eq!(vfs.read_dir("missing").unwrap_err().kind(), io::ErrorKind::NotFound);
+    }
+}